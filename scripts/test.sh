@@ -2,3 +2,6 @@
 
 cargo test
 cargo test --features postgresql --test feature_flag_postgresql
+cargo test --features sqlite --test feature_flag_sqlite
+cargo test --features mysql --test feature_flag_mysql
+cargo test --features mssql --test feature_flag_mssql
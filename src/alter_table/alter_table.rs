@@ -0,0 +1,372 @@
+use crate::{
+  behavior::{push_unique, trim, Concat, TransactionQuery},
+  fmt,
+  structure::{AlterTable, AlterTableAction, AlterTableClause, Dialect, Error},
+};
+use std::borrow::Cow;
+
+impl<'a> AlterTable<'a> {
+  /// The add column action, the name of the column followed by its type and constraints
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("login varchar(40) not null")
+  ///   .add_column("age int")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "ALTER TABLE users ADD COLUMN login varchar(40) not null, ADD COLUMN age int");
+  /// ```
+  pub fn add_column(mut self, definition: &str) -> Self {
+    let action = AlterTableAction::AddColumn(definition.trim().to_owned());
+    push_unique(&mut self._actions, action);
+    self
+  }
+
+  /// The add constraint action, the name of the constraint followed by its definition
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_constraint("users_login_key unique (login)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "ALTER TABLE users ADD CONSTRAINT users_login_key unique (login)");
+  /// ```
+  pub fn add_constraint(mut self, definition: &str) -> Self {
+    let action = AlterTableAction::AddConstraint(definition.trim().to_owned());
+    push_unique(&mut self._actions, action);
+    self
+  }
+
+  /// The alter column action that removes the default value of the column
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .alter_column_drop_default("active")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "ALTER TABLE users ALTER COLUMN active DROP DEFAULT");
+  /// ```
+  pub fn alter_column_drop_default(mut self, column_name: &str) -> Self {
+    let action = AlterTableAction::AlterColumnDropDefault(column_name.trim().to_owned());
+    push_unique(&mut self._actions, action);
+    self
+  }
+
+  /// The alter column action that changes the default value of the column
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .alter_column_set_default("active", "true")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "ALTER TABLE users ALTER COLUMN active SET DEFAULT true");
+  /// ```
+  pub fn alter_column_set_default(mut self, column_name: &str, expression: &str) -> Self {
+    let action = AlterTableAction::AlterColumnSetDefault(column_name.trim().to_owned(), expression.trim().to_owned());
+    push_unique(&mut self._actions, action);
+    self
+  }
+
+  /// The alter column action that changes the type of the column, the `TYPE` form is PostgreSQL only
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .alter_column_type("login", "varchar(80)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "ALTER TABLE users ALTER COLUMN login TYPE varchar(80)");
+  /// ```
+  pub fn alter_column_type(mut self, column_name: &str, data_type: &str) -> Self {
+    let action = AlterTableAction::AlterColumnType(column_name.trim().to_owned(), data_type.trim().to_owned());
+    push_unique(&mut self._actions, action);
+    self
+  }
+
+  /// The alter table clause. This method overrides the previous value
+  ///
+  /// The actions are rendered in the call order separated by comma
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users");
+  ///
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table(sql::quote_ident("Users", sql::Dialect::Postgres));
+  /// ```
+  pub fn alter_table(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._alter_table = trim(table_name.into());
+    self
+  }
+
+  /// Gets the current state of the [AlterTable] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .drop_column("birthday")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN age int, DROP COLUMN birthday
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](AlterTable::as_string) method checking the clauses in use against the dialect.
+  /// A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .alter_column_type("login", "varchar(80)");
+  ///
+  /// assert_eq!(
+  ///   alter_table.as_string_for(sql::Dialect::Postgres),
+  ///   Ok("ALTER TABLE users ALTER COLUMN login TYPE varchar(80)".to_owned())
+  /// );
+  /// assert!(alter_table.as_string_for(sql::Dialect::Sqlite).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// Prints the current state of the [AlterTable] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("age int")
+  ///   .alter_column_set_default("active", "true")
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users
+  ///   ADD COLUMN age int,
+  ///   ALTER COLUMN active SET DEFAULT true
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// The drop column action
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_column("birthday")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "ALTER TABLE users DROP COLUMN birthday");
+  /// ```
+  pub fn drop_column(mut self, column_name: &str) -> Self {
+    let action = AlterTableAction::DropColumn(column_name.trim().to_owned());
+    push_unique(&mut self._actions, action);
+    self
+  }
+
+  /// The drop constraint action
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .drop_constraint("users_login_key")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "ALTER TABLE users DROP CONSTRAINT users_login_key");
+  /// ```
+  pub fn drop_constraint(mut self, constraint_name: &str) -> Self {
+    let action = AlterTableAction::DropConstraint(constraint_name.trim().to_owned());
+    push_unique(&mut self._actions, action);
+    self
+  }
+
+  /// Create AlterTable's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [AlterTable] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "alter table users";
+  /// let alter_table = sql::AlterTable::new()
+  ///   .raw(raw_query)
+  ///   .add_column("age int")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// alter table users ADD COLUMN age int
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "default 'guest'";
+  /// let alter_table = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .add_column("login varchar(40)")
+  ///   .raw_after(sql::AlterTableClause::AddColumn, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// ALTER TABLE users ADD COLUMN login varchar(40) default 'guest'
+  /// ```
+  pub fn raw_after(mut self, clause: AlterTableClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* the users table */";
+  /// let alter_table = sql::AlterTable::new()
+  ///   .raw_before(sql::AlterTableClause::AlterTable, raw)
+  ///   .alter_table("users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* the users table */ ALTER TABLE users
+  /// ```
+  pub fn raw_before(mut self, clause: AlterTableClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// The rename column action. PostgreSQL doesn't accept a rename together with other actions
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .rename_column("login", "username")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "ALTER TABLE users RENAME COLUMN login TO username");
+  /// ```
+  pub fn rename_column(mut self, column_name: &str, new_column_name: &str) -> Self {
+    let action = AlterTableAction::RenameColumn(column_name.trim().to_owned(), new_column_name.trim().to_owned());
+    push_unique(&mut self._actions, action);
+    self
+  }
+
+  /// The rename table action. This method overrides the previous value.
+  /// PostgreSQL doesn't accept a rename together with other actions
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::AlterTable::new()
+  ///   .alter_table("users")
+  ///   .rename_to("accounts")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "ALTER TABLE users RENAME TO accounts");
+  /// ```
+  pub fn rename_to(mut self, new_table_name: &'a str) -> Self {
+    self
+      ._actions
+      .retain(|action| matches!(action, AlterTableAction::RenameTo(_)) == false);
+    self
+      ._actions
+      .push(AlterTableAction::RenameTo(new_table_name.trim().to_owned()));
+    self
+  }
+}
+
+impl TransactionQuery for AlterTable<'_> {}
+
+impl std::fmt::Display for AlterTable<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for AlterTable<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
@@ -0,0 +1,193 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt,
+  structure::{AlterTable, AlterTableAction, AlterTableClause, Dialect, Error},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, AlterTableClause> for AlterTable<'_> {}
+
+impl Concat for AlterTable<'_> {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_alter_table(query, fmts);
+    query = self.concat_actions(query, fmts);
+
+    query.trim_end().to_owned()
+  }
+
+  fn params(&self) -> Vec<Value> {
+    vec![]
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use AlterTableAction::*;
+    use Dialect::*;
+
+    let has_rename = self
+      ._actions
+      .iter()
+      .any(|action| matches!(action, RenameColumn(..) | RenameTo(_)));
+
+    check(
+      dialect,
+      "MULTIPLE ACTIONS",
+      self._actions.len() > 1,
+      &[Postgres, MySql, MsSql],
+    )?;
+    check(
+      dialect,
+      "RENAME WITH OTHER ACTIONS",
+      has_rename && self._actions.len() > 1,
+      &[MySql],
+    )?;
+
+    for action in self._actions.iter() {
+      let (clause, dialects): (&'static str, &[Dialect]) = match action {
+        AddColumn(_) => ("ADD COLUMN", &[Postgres, Sqlite, MySql]),
+        AddConstraint(_) => ("ADD CONSTRAINT", &[Postgres, MySql, MsSql]),
+        AlterColumnDropDefault(_) => ("ALTER COLUMN DROP DEFAULT", &[Postgres, MySql]),
+        AlterColumnSetDefault(..) => ("ALTER COLUMN SET DEFAULT", &[Postgres, MySql]),
+        AlterColumnType(..) => ("ALTER COLUMN TYPE", &[Postgres]),
+        DropColumn(_) => ("DROP COLUMN", &[Postgres, Sqlite, MySql, MsSql]),
+        DropConstraint(_) => ("DROP CONSTRAINT", &[Postgres, MySql, MsSql]),
+        RenameColumn(..) => ("RENAME COLUMN", &[Postgres, Sqlite, MySql]),
+        RenameTo(_) => ("RENAME TO", &[Postgres, Sqlite, MySql]),
+      };
+      check(dialect, clause, true, dialects)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl AlterTable<'_> {
+  fn concat_alter_table(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._alter_table.is_empty() == false {
+      let table_name = &self._alter_table;
+      format!("ALTER TABLE{space}{table_name}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      AlterTableClause::AlterTable,
+      sql,
+    )
+  }
+
+  /// All the actions of the statement separated by comma in the call order, the raw SQL of a clause
+  /// is rendered before the first and after the last action of that clause
+  fn concat_actions(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter {
+      comma,
+      lb,
+      indent,
+      space,
+      ..
+    } = fmts;
+
+    let mut items = vec![];
+    for (index, action) in self._actions.iter().enumerate() {
+      let sql = action.concat(fmts);
+      if sql.is_empty() {
+        continue;
+      }
+
+      let clause = action.clause();
+      let is_first = self._actions[..index].iter().all(|item| item.clause() != clause);
+      let is_last = self._actions[index + 1..].iter().all(|item| item.clause() != clause);
+      let raw_before = if is_first { &self._raw_before[..] } else { &[] };
+      let raw_after = if is_last { &self._raw_after[..] } else { &[] };
+
+      let item = concat_raw_before_after(raw_before, raw_after, "".to_owned(), fmts, clause, sql);
+      items.push(item.trim_end().to_owned());
+    }
+
+    let clauses_without_actions = [
+      AlterTableClause::AddColumn,
+      AlterTableClause::AddConstraint,
+      AlterTableClause::AlterColumn,
+      AlterTableClause::DropColumn,
+      AlterTableClause::DropConstraint,
+      AlterTableClause::RenameColumn,
+      AlterTableClause::RenameTo,
+    ]
+    .into_iter()
+    .filter(|clause| self._actions.iter().all(|action| action.clause() != *clause));
+
+    for clause in clauses_without_actions {
+      let item = concat_raw_before_after(
+        &self._raw_before,
+        &self._raw_after,
+        "".to_owned(),
+        fmts,
+        clause,
+        "".to_owned(),
+      );
+      if item.is_empty() == false {
+        items.push(item.trim_end().to_owned());
+      }
+    }
+
+    if items.is_empty() {
+      return query;
+    }
+
+    let body = items.join(&format!("{comma}{lb}{indent}"));
+    format!("{query}{indent}{body}{space}{lb}")
+  }
+}
+
+impl AlterTableAction {
+  fn clause(&self) -> AlterTableClause {
+    match self {
+      Self::AddColumn(_) => AlterTableClause::AddColumn,
+      Self::AddConstraint(_) => AlterTableClause::AddConstraint,
+      Self::AlterColumnDropDefault(_) | Self::AlterColumnSetDefault(..) | Self::AlterColumnType(..) => {
+        AlterTableClause::AlterColumn
+      }
+      Self::DropColumn(_) => AlterTableClause::DropColumn,
+      Self::DropConstraint(_) => AlterTableClause::DropConstraint,
+      Self::RenameColumn(..) => AlterTableClause::RenameColumn,
+      Self::RenameTo(_) => AlterTableClause::RenameTo,
+    }
+  }
+
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { space, .. } = fmts;
+    let (name, sql) = match self {
+      Self::AddColumn(definition) => (definition, format!("ADD COLUMN{space}{definition}")),
+      Self::AddConstraint(definition) => (definition, format!("ADD CONSTRAINT{space}{definition}")),
+      Self::AlterColumnDropDefault(column) => (column, format!("ALTER COLUMN{space}{column}{space}DROP DEFAULT")),
+      Self::AlterColumnSetDefault(column, expression) => (
+        column,
+        format!("ALTER COLUMN{space}{column}{space}SET DEFAULT{space}{expression}"),
+      ),
+      Self::AlterColumnType(column, data_type) => (
+        column,
+        format!("ALTER COLUMN{space}{column}{space}TYPE{space}{data_type}"),
+      ),
+      Self::DropColumn(column) => (column, format!("DROP COLUMN{space}{column}")),
+      Self::DropConstraint(constraint) => (constraint, format!("DROP CONSTRAINT{space}{constraint}")),
+      Self::RenameColumn(column, new_column) => (
+        column,
+        format!("RENAME COLUMN{space}{column}{space}TO{space}{new_column}"),
+      ),
+      Self::RenameTo(table) => (table, format!("RENAME TO{space}{table}")),
+    };
+
+    if name.is_empty() {
+      return "".to_owned();
+    }
+    format!("{sql}{space}")
+  }
+}
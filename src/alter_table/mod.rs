@@ -0,0 +1,2 @@
+mod alter_table;
+mod alter_table_internal;
@@ -1,5 +1,17 @@
-use crate::fmt;
-use std::cmp::PartialEq;
+use crate::{
+  fmt, placeholder,
+  structure::{Dialect, Error},
+  value::Value,
+};
+use std::{borrow::Cow, cmp::PartialEq};
+
+/// Shifts the placeholders of the SQL past the values already bound and stores the new values,
+/// the placeholders written by hand in the methods without values are not taken into account
+pub fn bind(params: &mut Vec<Value>, sql: &str, values: impl IntoIterator<Item = Value>) -> String {
+  let sql = placeholder::shift(sql.trim(), params.len());
+  params.extend(values);
+  sql
+}
 
 pub fn push_unique<T: Eq>(list: &mut Vec<T>, value: T) {
   let prev_item = list.iter().find(|&item| *item == value);
@@ -8,7 +20,14 @@ pub fn push_unique<T: Eq>(list: &mut Vec<T>, value: T) {
   }
 }
 
-pub fn raw_queries<'a, Clause: PartialEq>(raw_list: &'a Vec<(Clause, String)>, clause: &'a Clause) -> Vec<String> {
+pub fn trim(value: Cow<'_, str>) -> Cow<'_, str> {
+  match value {
+    Cow::Borrowed(value) => Cow::Borrowed(value.trim()),
+    Cow::Owned(value) => Cow::Owned(value.trim().to_owned()),
+  }
+}
+
+pub fn raw_queries<'a, Clause: PartialEq>(raw_list: &'a [(Clause, String)], clause: &'a Clause) -> Vec<String> {
   raw_list
     .iter()
     .filter(|item| item.0 == *clause)
@@ -16,16 +35,37 @@ pub fn raw_queries<'a, Clause: PartialEq>(raw_list: &'a Vec<(Clause, String)>, c
     .collect::<Vec<_>>()
 }
 
+/// Represents all statements that can be used in the explain method
+pub trait ExplainQuery: Concat {}
+
 /// Represents all statements that can be used in the with method
+#[allow(dead_code)]
 pub trait WithQuery: Concat {}
 
+/// Represents all statements that can be used in the statement method of the transaction
+pub trait TransactionQuery: Concat {
+  /// The statement can't run inside a transaction block, like `CREATE INDEX CONCURRENTLY`
+  fn is_transaction_unsafe(&self) -> bool {
+    false
+  }
+}
+
+/// Represents all statements that can be used as the source of the using method of the merge
+pub trait UsingQuery: Concat {}
+
 pub trait Concat {
   fn concat(&self, fmts: &fmt::Formatter) -> String;
+
+  /// The bound values of the builder followed by the values of the nested builders in the order they're rendered
+  fn params(&self) -> Vec<Value>;
+
+  /// Returns an error when the builder or one of the nested builders uses a clause the dialect doesn't support
+  fn validate(&self, dialect: Dialect) -> Result<(), Error>;
 }
 
 pub fn concat_raw_before_after<Clause: PartialEq>(
-  items_before: &Vec<(Clause, String)>,
-  items_after: &Vec<(Clause, String)>,
+  items_before: &[(Clause, String)],
+  items_after: &[(Clause, String)],
   query: String,
   fmts: &fmt::Formatter,
   clause: Clause,
@@ -43,12 +83,12 @@ pub fn concat_raw_before_after<Clause: PartialEq>(
 pub trait ConcatMethods<'a, Clause: PartialEq> {
   fn concat_from(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
+    items: &[String],
   ) -> String {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
     let sql = if items.is_empty() == false {
@@ -61,7 +101,67 @@ pub trait ConcatMethods<'a, Clause: PartialEq> {
     concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
   }
 
-  fn concat_raw(&self, query: String, fmts: &fmt::Formatter, items: &Vec<String>) -> String {
+  fn concat_limit(
+    &self,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: String,
+    fmts: &fmt::Formatter,
+    clause: Clause,
+    limit: &str,
+  ) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if limit.is_empty() == false {
+      format!("LIMIT{space}{limit}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+  }
+
+  fn concat_order_by(
+    &self,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: String,
+    fmts: &fmt::Formatter,
+    clause: Clause,
+    items: &[String],
+  ) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if items.is_empty() == false {
+      let columns = items.join(comma);
+      format!("ORDER BY{space}{columns}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+  }
+
+  #[cfg(feature = "mssql")]
+  fn concat_output(
+    &self,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: String,
+    fmts: &fmt::Formatter,
+    clause: Clause,
+    items: &[String],
+  ) -> String {
+    let fmt::Formatter { lb, space, comma, .. } = fmts;
+    let sql = if items.is_empty() == false {
+      let output_names = items.join(comma);
+      format!("OUTPUT{space}{output_names}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+  }
+
+  fn concat_raw(&self, query: String, fmts: &fmt::Formatter, items: &[String]) -> String {
     if items.is_empty() {
       return query;
     }
@@ -71,15 +171,15 @@ pub trait ConcatMethods<'a, Clause: PartialEq> {
     format!("{query}{raw_sql}{space}{lb}")
   }
 
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   fn concat_returning(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
+    items: &[String],
   ) -> String {
     let fmt::Formatter { lb, space, comma, .. } = fmts;
     let sql = if items.is_empty() == false {
@@ -92,14 +192,35 @@ pub trait ConcatMethods<'a, Clause: PartialEq> {
     concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
   }
 
+  #[cfg(feature = "mssql")]
+  fn concat_table_hint(
+    &self,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
+    query: String,
+    fmts: &fmt::Formatter,
+    clause: Clause,
+    items: &[String],
+  ) -> String {
+    let fmt::Formatter { lb, space, comma, .. } = fmts;
+    let sql = if items.is_empty() == false {
+      let hints = items.join(comma);
+      format!("WITH{space}({hints}){space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
+  }
+
   fn concat_values(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
+    items: &[String],
   ) -> String {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
     let sql = if items.is_empty() == false {
@@ -115,12 +236,12 @@ pub trait ConcatMethods<'a, Clause: PartialEq> {
 
   fn concat_where(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<String>,
+    items: &[String],
   ) -> String {
     let fmt::Formatter { lb, space, indent, .. } = fmts;
     let sql = if items.is_empty() == false {
@@ -133,15 +254,15 @@ pub trait ConcatMethods<'a, Clause: PartialEq> {
     concat_raw_before_after(items_raw_before, items_raw_after, query, fmts, clause, sql)
   }
 
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   fn concat_with(
     &self,
-    items_raw_before: &Vec<(Clause, String)>,
-    items_raw_after: &Vec<(Clause, String)>,
+    items_raw_before: &[(Clause, String)],
+    items_raw_after: &[(Clause, String)],
     query: String,
     fmts: &fmt::Formatter,
     clause: Clause,
-    items: &Vec<(&'a str, std::sync::Arc<dyn WithQuery>)>,
+    items: &Vec<(crate::structure::Cte<'a>, std::sync::Arc<dyn WithQuery>)>,
   ) -> String {
     let fmt::Formatter {
       comma,
@@ -152,7 +273,8 @@ pub trait ConcatMethods<'a, Clause: PartialEq> {
     } = fmts;
     let sql = if items.is_empty() == false {
       let with = items.iter().fold("".to_owned(), |acc, item| {
-        let (name, query) = item;
+        let (cte, query) = item;
+        let name = cte.concat_name(fmts);
         let inner_lb = format!("{lb}{indent}");
         let inner_fmts = fmt::Formatter {
           comma,
@@ -161,13 +283,18 @@ pub trait ConcatMethods<'a, Clause: PartialEq> {
           space,
           ..*fmts
         };
-        let query_string = query.concat(&inner_fmts);
+        let query_string = placeholder::nested(&query.concat(&inner_fmts));
 
-        format!("{acc}{name}{space}AS{space}({lb}{indent}{query_string}{lb}){comma}{lb}")
+        format!("{acc}{name}{space}({lb}{indent}{query_string}{lb}){comma}{lb}")
       });
       let with = &with[..with.len() - comma.len() - lb.len()];
+      let recursive = if items.iter().any(|(cte, _)| cte._recursive) {
+        format!("{space}RECURSIVE")
+      } else {
+        "".to_owned()
+      };
 
-      format!("WITH{space}{lb}{with}{space}{lb}")
+      format!("WITH{recursive}{space}{lb}{with}{space}{lb}")
     } else {
       "".to_owned()
     };
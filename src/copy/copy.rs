@@ -0,0 +1,381 @@
+use crate::{
+  behavior::{push_unique, trim, Concat, TransactionQuery},
+  fmt,
+  quote::quote_literal,
+  structure::{Copy, CopyClause, CopyFormat, Dialect, Error, Select},
+};
+use std::borrow::Cow;
+
+impl<'a> Copy<'a> {
+  /// Gets the current state of the [Copy](struct@crate::Copy) and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .copy("users (login, name)")
+  ///   .from_stdin()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// COPY users (login, name) FROM STDIN
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](Copy::as_string) method checking the clauses in use, the builder and the nested builder,
+  /// against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let copy = sql::Copy::new()
+  ///   .copy("users")
+  ///   .to_stdout();
+  ///
+  /// assert_eq!(
+  ///   copy.as_string_for(sql::Dialect::Postgres),
+  ///   Ok("COPY users TO STDOUT".to_owned())
+  /// );
+  /// assert!(copy.as_string_for(sql::Dialect::MySql).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The copy clause, the table and optionally the list of columns. This method overrides the previous value
+  /// and the previous [select](Copy::select)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let copy = sql::Copy::new()
+  ///   .copy("users (login, name)");
+  ///
+  /// let copy = sql::Copy::new()
+  ///   .copy(sql::quote_ident("Users", sql::Dialect::Postgres));
+  /// ```
+  pub fn copy(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._copy = trim(table_name.into());
+    self._select = None;
+    self
+  }
+
+  /// Prints the current state of the [Copy](struct@crate::Copy) into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let copy = sql::Copy::new()
+  ///   .select(sql::Select::new().select("login, name").from("users"))
+  ///   .to_stdout()
+  ///   .format(sql::CopyFormat::Csv)
+  ///   .header()
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// COPY (
+  ///   SELECT login, name
+  ///   FROM users
+  /// )
+  /// TO STDOUT
+  /// WITH (FORMAT csv, HEADER)
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// The `DELIMITER` option, the character that separates the columns, the value is quoted by the builder.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .copy("users")
+  ///   .from_stdin()
+  ///   .format(sql::CopyFormat::Csv)
+  ///   .delimiter(";")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "COPY users FROM STDIN WITH (FORMAT csv, DELIMITER ';')");
+  /// ```
+  pub fn delimiter(mut self, delimiter: &str) -> Self {
+    self._delimiter = Some(quote_literal(delimiter, Dialect::Postgres));
+    self
+  }
+
+  /// The `FORMAT` option. This method overrides the previous value. The binary format combined with the
+  /// header, delimiter or null options is returned as an error by the [as_string_for](Copy::as_string_for) method
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .copy("users")
+  ///   .from_stdin()
+  ///   .format(sql::CopyFormat::Csv)
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "COPY users FROM STDIN WITH (FORMAT csv)");
+  /// ```
+  pub fn format(mut self, format: CopyFormat) -> Self {
+    self._format = Some(format);
+    self
+  }
+
+  /// The from clause, the file read by the server, the file name is quoted by the builder.
+  /// This method overrides the previous value and the previous [to](Copy::to)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .copy("users")
+  ///   .from("/tmp/users.csv")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "COPY users FROM '/tmp/users.csv'");
+  /// ```
+  pub fn from(mut self, file_name: &str) -> Self {
+    self._from = quote_literal(file_name, Dialect::Postgres);
+    self._to = "".to_owned();
+    self
+  }
+
+  /// The from clause reading the rows sent by the client.
+  /// This method overrides the previous value and the previous [to](Copy::to)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .copy("users (login, name)")
+  ///   .from_stdin()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "COPY users (login, name) FROM STDIN");
+  /// ```
+  pub fn from_stdin(mut self) -> Self {
+    self._from = "STDIN".to_owned();
+    self._to = "".to_owned();
+    self
+  }
+
+  /// The `HEADER` option, the first line of the file has the names of the columns
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .copy("users")
+  ///   .to_stdout()
+  ///   .format(sql::CopyFormat::Csv)
+  ///   .header()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "COPY users TO STDOUT WITH (FORMAT csv, HEADER)");
+  /// ```
+  pub fn header(mut self) -> Self {
+    self._header = true;
+    self
+  }
+
+  /// Create Copy's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The `NULL` option, the string that represents a null value, the value is quoted by the builder.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .copy("users")
+  ///   .from_stdin()
+  ///   .null("")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "COPY users FROM STDIN WITH (NULL '')");
+  /// ```
+  pub fn null(mut self, null_string: &str) -> Self {
+    self._null = Some(quote_literal(null_string, Dialect::Postgres));
+    self
+  }
+
+  /// Prints the current state of the [Copy](struct@crate::Copy) into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "copy users";
+  /// let copy = sql::Copy::new()
+  ///   .raw(raw_query)
+  ///   .from_stdin()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// copy users FROM STDIN
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "where active = true";
+  /// let copy = sql::Copy::new()
+  ///   .copy("users")
+  ///   .from_stdin()
+  ///   .raw_after(sql::CopyClause::From, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// COPY users FROM STDIN where active = true
+  /// ```
+  pub fn raw_after(mut self, clause: CopyClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "copy users";
+  /// let copy = sql::Copy::new()
+  ///   .raw_before(sql::CopyClause::To, raw)
+  ///   .to_stdout()
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// copy users TO STDOUT
+  /// ```
+  pub fn raw_before(mut self, clause: CopyClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// The query form of the copy clause, the rows of the select are copied. This method overrides the previous value.
+  /// A query with bound values is returned as an error by the [as_string_for](Copy::as_string_for) method
+  /// and the previous [copy](Copy::copy)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .select(sql::Select::new().select("login, name").from("users").where_clause("active = true"))
+  ///   .to_stdout()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "COPY (SELECT login, name FROM users WHERE active = true) TO STDOUT");
+  /// ```
+  pub fn select(mut self, select: Select<'a>) -> Self {
+    self._select = Some(select);
+    self._copy = Cow::default();
+    self
+  }
+
+  /// The to clause, the file written by the server, the file name is quoted by the builder.
+  /// This method overrides the previous value and the previous [from](Copy::from)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .copy("users")
+  ///   .to("/tmp/users.csv")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "COPY users TO '/tmp/users.csv'");
+  /// ```
+  pub fn to(mut self, file_name: &str) -> Self {
+    self._to = quote_literal(file_name, Dialect::Postgres);
+    self._from = "".to_owned();
+    self
+  }
+
+  /// The to clause sending the rows to the client.
+  /// This method overrides the previous value and the previous [from](Copy::from)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Copy::new()
+  ///   .copy("users")
+  ///   .to_stdout()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "COPY users TO STDOUT");
+  /// ```
+  pub fn to_stdout(mut self) -> Self {
+    self._to = "STDOUT".to_owned();
+    self._from = "".to_owned();
+    self
+  }
+}
+
+impl TransactionQuery for Copy<'_> {}
+
+impl std::fmt::Display for Copy<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Copy<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
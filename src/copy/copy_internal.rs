@@ -0,0 +1,140 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt, placeholder,
+  structure::{Copy, CopyClause, CopyFormat, Dialect, Error},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, CopyClause> for Copy<'_> {}
+
+impl Concat for Copy<'_> {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_copy(query, fmts);
+    query = self.concat_from(query, fmts);
+    query = self.concat_to(query, fmts);
+    query = self.concat_with(query, fmts);
+
+    placeholder::resolve_nested(query.trim_end())
+  }
+
+  fn params(&self) -> Vec<Value> {
+    self._select.iter().flat_map(|select| select.params()).collect()
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(
+      dialect,
+      "COPY",
+      self._copy.is_empty() == false || self._select.is_some(),
+      &[Postgres],
+    )?;
+    check(
+      dialect,
+      "BINARY FORMAT WITH TEXT OPTIONS",
+      self._format == Some(CopyFormat::Binary) && (self._header || self._delimiter.is_some() || self._null.is_some()),
+      &[],
+    )?;
+    if let Some(select) = &self._select {
+      check(
+        dialect,
+        "SELECT WITH BOUND VALUES",
+        select.params().is_empty() == false,
+        &[],
+      )?;
+      select.validate(dialect)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Copy<'_> {
+  fn concat_copy(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter {
+      comma,
+      lb,
+      indent,
+      space,
+      ..
+    } = fmts;
+    let sql = if let Some(select) = &self._select {
+      let inner_lb = format!("{lb}{indent}");
+      let inner_fmts = fmt::Formatter {
+        comma,
+        lb: inner_lb.as_str(),
+        indent,
+        space,
+        ..*fmts
+      };
+      let select_string = placeholder::nested(&select.concat(&inner_fmts));
+      format!("COPY{space}({lb}{indent}{select_string}{lb}){space}{lb}")
+    } else if self._copy.is_empty() == false {
+      let table_name = &self._copy;
+      format!("COPY{space}{table_name}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, CopyClause::Copy, sql)
+  }
+
+  fn concat_from(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._from.is_empty() == false {
+      let source = &self._from;
+      format!("FROM{space}{source}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, CopyClause::From, sql)
+  }
+
+  fn concat_to(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._to.is_empty() == false {
+      let target = &self._to;
+      format!("TO{space}{target}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, CopyClause::To, sql)
+  }
+
+  fn concat_with(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let mut options = vec![];
+    if let Some(format) = &self._format {
+      let format = match format {
+        CopyFormat::Binary => "binary",
+        CopyFormat::Csv => "csv",
+        CopyFormat::Text => "text",
+      };
+      options.push(format!("FORMAT{space}{format}"));
+    }
+    if self._header {
+      options.push("HEADER".to_owned());
+    }
+    if let Some(delimiter) = &self._delimiter {
+      options.push(format!("DELIMITER{space}{delimiter}"));
+    }
+    if let Some(null) = &self._null {
+      options.push(format!("NULL{space}{null}"));
+    }
+    let sql = if options.is_empty() == false {
+      let options = options.join(comma);
+      format!("WITH{space}({options}){space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, CopyClause::With, sql)
+  }
+}
@@ -0,0 +1,2 @@
+mod copy;
+mod copy_internal;
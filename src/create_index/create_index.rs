@@ -0,0 +1,370 @@
+use crate::{
+  behavior::{push_unique, trim, Concat, TransactionQuery},
+  fmt,
+  structure::{CreateIndex, CreateIndexClause, Dialect, Error},
+};
+use std::borrow::Cow;
+
+impl<'a> CreateIndex<'a> {
+  /// Gets the current state of the [CreateIndex] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login)
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](CreateIndex::as_string) method checking the clauses in use against the dialect.
+  /// A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .where_clause("active = true");
+  ///
+  /// assert_eq!(
+  ///   create_index.as_string_for(sql::Dialect::Sqlite),
+  ///   Ok("CREATE INDEX users_login_idx ON users (login) WHERE active = true".to_owned())
+  /// );
+  /// assert!(create_index.as_string_for(sql::Dialect::MySql).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The indexed column or expression, optionally followed by the sort order like `ASC`, `DESC`,
+  /// `NULLS FIRST` or `NULLS LAST`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("orders_user_created_idx")
+  ///   .on("orders")
+  ///   .column("user_id")
+  ///   .column("created_at DESC NULLS LAST")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "CREATE INDEX orders_user_created_idx ON orders (user_id, created_at DESC NULLS LAST)"
+  /// );
+  /// ```
+  pub fn column(mut self, column_name: &str) -> Self {
+    push_unique(&mut self._column, column_name.trim().to_owned());
+    self
+  }
+
+  /// Adds the `CONCURRENTLY` option, the index is built without locking out the writes on the table.
+  /// The option can't run inside a [Transaction](crate::Transaction), this method can be used enabling the feature flag `postgresql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .concurrently()
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE INDEX CONCURRENTLY users_login_idx ON users (login)");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn concurrently(mut self) -> Self {
+    self._concurrently = true;
+    self
+  }
+
+  /// The create index clause. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx");
+  ///
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index(sql::quote_ident("UsersLogin", sql::Dialect::Postgres));
+  /// ```
+  pub fn create_index(mut self, index_name: impl Into<Cow<'a, str>>) -> Self {
+    self._create_index = trim(index_name.into());
+    self._if_not_exists = false;
+    self
+  }
+
+  /// The create index clause with the `IF NOT EXISTS` option. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index_if_not_exists("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE INDEX IF NOT EXISTS users_login_idx ON users (login)");
+  /// ```
+  pub fn create_index_if_not_exists(mut self, index_name: impl Into<Cow<'a, str>>) -> Self {
+    self._create_index = trim(index_name.into());
+    self._if_not_exists = true;
+    self
+  }
+
+  /// Prints the current state of the [CreateIndex] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .where_clause("active = true")
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_login_idx
+  /// ON users
+  /// (login)
+  /// WHERE active = true
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// The include clause, the columns stored in the index without being part of the key
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .include("name")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE INDEX users_login_idx ON users (login) INCLUDE (name)");
+  /// ```
+  pub fn include(mut self, column_name: &str) -> Self {
+    push_unique(&mut self._include, column_name.trim().to_owned());
+    self
+  }
+
+  /// Create CreateIndex's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The on clause, the table of the index. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users");
+  /// ```
+  pub fn on(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._on = trim(table_name.into());
+    self
+  }
+
+  /// Prints the current state of the [CreateIndex] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "create index users_login_idx";
+  /// let create_index = sql::CreateIndex::new()
+  ///   .raw(raw_query)
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// create index users_login_idx ON users (login)
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "with (fillfactor = 70)";
+  /// let create_index = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .raw_after(sql::CreateIndexClause::Column, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE INDEX users_login_idx ON users (login) with (fillfactor = 70)
+  /// ```
+  pub fn raw_after(mut self, clause: CreateIndexClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* the login index */";
+  /// let create_index = sql::CreateIndex::new()
+  ///   .raw_before(sql::CreateIndexClause::CreateIndex, raw)
+  ///   .create_index("users_login_idx")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* the login index */ CREATE INDEX users_login_idx
+  /// ```
+  pub fn raw_before(mut self, clause: CreateIndexClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds the `UNIQUE` option, the index doesn't allow duplicated values
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_key")
+  ///   .unique()
+  ///   .on("users")
+  ///   .column("login")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE UNIQUE INDEX users_login_key ON users (login)");
+  /// ```
+  pub fn unique(mut self) -> Self {
+    self._unique = true;
+    self
+  }
+
+  /// The using clause, the index method like `btree` or `gin`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("documents_tags_idx")
+  ///   .on("documents")
+  ///   .using("gin")
+  ///   .column("tags")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE INDEX documents_tags_idx ON documents USING gin (tags)");
+  /// ```
+  pub fn using(mut self, index_method: &'a str) -> Self {
+    self._using = index_method.trim();
+    self
+  }
+
+  /// The where clause of a partial index, the conditions of consecutive calls are joined by `AND`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateIndex::new()
+  ///   .create_index("users_login_idx")
+  ///   .on("users")
+  ///   .column("login")
+  ///   .where_clause("active = true")
+  ///   .where_clause("deleted_at is null")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "CREATE INDEX users_login_idx ON users (login) WHERE active = true AND deleted_at is null"
+  /// );
+  /// ```
+  pub fn where_clause(mut self, condition: &str) -> Self {
+    push_unique(&mut self._where, condition.trim().to_owned());
+    self
+  }
+}
+
+impl TransactionQuery for CreateIndex<'_> {
+  #[cfg(feature = "postgresql")]
+  fn is_transaction_unsafe(&self) -> bool {
+    self._concurrently
+  }
+}
+
+impl std::fmt::Display for CreateIndex<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for CreateIndex<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
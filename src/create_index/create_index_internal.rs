@@ -0,0 +1,174 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt,
+  structure::{CreateIndex, CreateIndexClause, Dialect, Error},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, CreateIndexClause> for CreateIndex<'_> {}
+
+impl Concat for CreateIndex<'_> {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_create_index(query, fmts);
+    query = self.concat_on(query, fmts);
+    query = self.concat_using(query, fmts);
+    query = self.concat_column(query, fmts);
+    query = self.concat_include(query, fmts);
+    query = self.concat_where(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::Where,
+      &self._where,
+    );
+
+    query.trim_end().to_owned()
+  }
+
+  fn params(&self) -> Vec<Value> {
+    vec![]
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(dialect, "IF NOT EXISTS", self._if_not_exists, &[Postgres, Sqlite])?;
+    check(dialect, "USING", self._using.is_empty() == false, &[Postgres])?;
+    check(
+      dialect,
+      "INCLUDE",
+      self._include.is_empty() == false,
+      &[Postgres, MsSql],
+    )?;
+    check(
+      dialect,
+      "WHERE",
+      self._where.is_empty() == false,
+      &[Postgres, Sqlite, MsSql],
+    )?;
+    #[cfg(feature = "postgresql")]
+    check(dialect, "CONCURRENTLY", self._concurrently, &[Postgres])?;
+
+    Ok(())
+  }
+}
+
+impl CreateIndex<'_> {
+  fn concat_column(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if self._column.is_empty() == false {
+      let columns = self._column.join(comma);
+      format!("({columns}){space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::Column,
+      sql,
+    )
+  }
+
+  fn concat_create_index(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._create_index.is_empty() == false {
+      let index_name = &self._create_index;
+      let unique = if self._unique {
+        format!("UNIQUE{space}")
+      } else {
+        "".to_owned()
+      };
+      #[cfg(feature = "postgresql")]
+      let concurrently = if self._concurrently {
+        format!("CONCURRENTLY{space}")
+      } else {
+        "".to_owned()
+      };
+      #[cfg(not(feature = "postgresql"))]
+      let concurrently = "";
+      let if_not_exists = if self._if_not_exists {
+        format!("IF NOT EXISTS{space}")
+      } else {
+        "".to_owned()
+      };
+      format!("CREATE{space}{unique}INDEX{space}{concurrently}{if_not_exists}{index_name}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::CreateIndex,
+      sql,
+    )
+  }
+
+  fn concat_include(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if self._include.is_empty() == false {
+      let columns = self._include.join(comma);
+      format!("INCLUDE{space}({columns}){space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::Include,
+      sql,
+    )
+  }
+
+  fn concat_on(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._on.is_empty() == false {
+      let table_name = &self._on;
+      format!("ON{space}{table_name}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::On,
+      sql,
+    )
+  }
+
+  fn concat_using(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._using.is_empty() == false {
+      let method = self._using;
+      format!("USING{space}{method}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateIndexClause::Using,
+      sql,
+    )
+  }
+}
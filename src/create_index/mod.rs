@@ -0,0 +1,2 @@
+mod create_index;
+mod create_index_internal;
@@ -0,0 +1,393 @@
+use crate::{
+  behavior::{push_unique, trim, Concat, TransactionQuery},
+  fmt,
+  structure::{CreateTable, CreateTableClause, Dialect, Error},
+};
+use std::borrow::Cow;
+
+impl<'a> CreateTable<'a> {
+  /// Gets the current state of the [CreateTable] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .column("login varchar(40) not null")
+  ///   .primary_key("(id)")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE TABLE users (id serial, login varchar(40) not null, PRIMARY KEY(id))
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](CreateTable::as_string) method checking the clauses in use against the dialect.
+  /// A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table_if_not_exists("users")
+  ///   .column("id int");
+  ///
+  /// assert_eq!(
+  ///   create_table.as_string_for(sql::Dialect::Sqlite),
+  ///   Ok("CREATE TABLE IF NOT EXISTS users (id int)".to_owned())
+  /// );
+  /// assert!(create_table.as_string_for(sql::Dialect::MsSql).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The check constraint of the table, the argument is the condition with its parentheses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("age int")
+  ///   .check("(age >= 18)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE TABLE users (age int, CHECK(age >= 18))");
+  /// ```
+  pub fn check(mut self, condition: &str) -> Self {
+    push_unique(&mut self._check, condition.trim().to_owned());
+    self
+  }
+
+  /// The column definition, the name of the column followed by its type and constraints
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial primary key")
+  ///   .column("login varchar(40) not null");
+  /// ```
+  pub fn column(mut self, definition: &str) -> Self {
+    push_unique(&mut self._column, definition.trim().to_owned());
+    self
+  }
+
+  /// The named constraint of the table
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("login varchar(40)")
+  ///   .constraint("users_login_key unique (login)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE TABLE users (login varchar(40), CONSTRAINT users_login_key unique (login))");
+  /// ```
+  pub fn constraint(mut self, definition: &str) -> Self {
+    push_unique(&mut self._constraint, definition.trim().to_owned());
+    self
+  }
+
+  /// The create table clause. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users");
+  ///
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table(sql::quote_ident("Users", sql::Dialect::Postgres));
+  /// ```
+  pub fn create_table(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._create_table = trim(table_name.into());
+    self._if_not_exists = false;
+    self
+  }
+
+  /// The create table clause with the `IF NOT EXISTS` option. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table_if_not_exists("users")
+  ///   .column("id serial")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE TABLE IF NOT EXISTS users (id serial)");
+  /// ```
+  pub fn create_table_if_not_exists(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._create_table = trim(table_name.into());
+    self._if_not_exists = true;
+    self
+  }
+
+  /// Prints the current state of the [CreateTable] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .column("login varchar(40) not null")
+  ///   .primary_key("(id)")
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (
+  ///   id serial,
+  ///   login varchar(40) not null,
+  ///   PRIMARY KEY(id)
+  /// )
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// The foreign key constraint of the table, the argument is the columns with its parentheses followed by the references
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("orders")
+  ///   .column("user_id int")
+  ///   .foreign_key("(user_id) references users (id) on delete cascade")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "CREATE TABLE orders (user_id int, FOREIGN KEY(user_id) references users (id) on delete cascade)"
+  /// );
+  /// ```
+  pub fn foreign_key(mut self, definition: &str) -> Self {
+    push_unique(&mut self._foreign_key, definition.trim().to_owned());
+    self
+  }
+
+  /// The inherits clause, this method can be used enabling the feature flag `postgresql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("admins")
+  ///   .column("level int")
+  ///   .inherits("users")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE TABLE admins (level int) INHERITS (users)");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn inherits(mut self, table_name: &str) -> Self {
+    push_unique(&mut self._inherits, table_name.trim().to_owned());
+    self
+  }
+
+  /// Create CreateTable's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The partition by clause, this method can be used enabling the feature flag `postgresql`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("logs")
+  ///   .column("created_at date not null")
+  ///   .partition_by("range (created_at)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE TABLE logs (created_at date not null) PARTITION BY range (created_at)");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn partition_by(mut self, strategy: &'a str) -> Self {
+    self._partition_by = strategy.trim();
+    self
+  }
+
+  /// The primary key constraint of the table, the argument is the columns with its parentheses.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users_groups")
+  ///   .column("user_id int")
+  ///   .column("group_id int")
+  ///   .primary_key("(user_id, group_id)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE TABLE users_groups (user_id int, group_id int, PRIMARY KEY(user_id, group_id))");
+  /// ```
+  pub fn primary_key(mut self, columns: &'a str) -> Self {
+    self._primary_key = columns.trim();
+    self
+  }
+
+  /// Prints the current state of the [CreateTable] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "create table users";
+  /// let create_table = sql::CreateTable::new()
+  ///   .raw(raw_query)
+  ///   .column("id serial")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// create table users (id serial)
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* the primary key */";
+  /// let create_table = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .raw_after(sql::CreateTableClause::Column, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE TABLE users (id serial /* the primary key */)
+  /// ```
+  pub fn raw_after(mut self, clause: CreateTableClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* the users table */";
+  /// let create_table = sql::CreateTable::new()
+  ///   .raw_before(sql::CreateTableClause::CreateTable, raw)
+  ///   .create_table("users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* the users table */ CREATE TABLE users
+  /// ```
+  pub fn raw_before(mut self, clause: CreateTableClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// The tablespace clause, this method can be used enabling the feature flag `postgresql`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("id serial")
+  ///   .tablespace("fast_storage")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE TABLE users (id serial) TABLESPACE fast_storage");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn tablespace(mut self, tablespace_name: &'a str) -> Self {
+    self._tablespace = tablespace_name.trim();
+    self
+  }
+
+  /// The unique constraint of the table, the argument is the columns with its parentheses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("login varchar(40)")
+  ///   .unique("(login)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE TABLE users (login varchar(40), UNIQUE(login))");
+  /// ```
+  pub fn unique(mut self, columns: &str) -> Self {
+    push_unique(&mut self._unique, columns.trim().to_owned());
+    self
+  }
+}
+
+impl TransactionQuery for CreateTable<'_> {}
+
+impl std::fmt::Display for CreateTable<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for CreateTable<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
@@ -0,0 +1,215 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt,
+  structure::{CreateTable, CreateTableClause, Dialect, Error},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, CreateTableClause> for CreateTable<'_> {}
+
+impl Concat for CreateTable<'_> {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_create_table(query, fmts);
+    query = self.concat_definitions(query, fmts);
+
+    #[cfg(feature = "postgresql")]
+    {
+      query = self.concat_inherits(query, fmts);
+      query = self.concat_partition_by(query, fmts);
+      query = self.concat_tablespace(query, fmts);
+    }
+
+    query.trim_end().to_owned()
+  }
+
+  fn params(&self) -> Vec<Value> {
+    vec![]
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(
+      dialect,
+      "IF NOT EXISTS",
+      self._if_not_exists,
+      &[Postgres, Sqlite, MySql],
+    )?;
+    #[cfg(feature = "postgresql")]
+    {
+      check(dialect, "INHERITS", self._inherits.is_empty() == false, &[Postgres])?;
+      check(
+        dialect,
+        "PARTITION BY",
+        self._partition_by.is_empty() == false,
+        &[Postgres],
+      )?;
+      check(dialect, "TABLESPACE", self._tablespace.is_empty() == false, &[Postgres])?;
+    }
+
+    Ok(())
+  }
+}
+
+impl CreateTable<'_> {
+  fn concat_create_table(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._create_table.is_empty() == false {
+      let table_name = &self._create_table;
+      let if_not_exists = if self._if_not_exists {
+        format!("IF NOT EXISTS{space}")
+      } else {
+        "".to_owned()
+      };
+      format!("CREATE TABLE{space}{if_not_exists}{table_name}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateTableClause::CreateTable,
+      sql,
+    )
+  }
+
+  /// The columns and the table constraints, rendered inside the parentheses and separated by comma
+  fn concat_definitions(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, indent, .. } = fmts;
+
+    let primary_key = [self._primary_key.to_owned()];
+    let constraint = format!("CONSTRAINT{space}");
+    let definitions = [
+      (CreateTableClause::Column, "", &self._column[..]),
+      (CreateTableClause::PrimaryKey, "PRIMARY KEY", &primary_key[..]),
+      (CreateTableClause::Unique, "UNIQUE", &self._unique[..]),
+      (CreateTableClause::Check, "CHECK", &self._check[..]),
+      (CreateTableClause::ForeignKey, "FOREIGN KEY", &self._foreign_key[..]),
+      (CreateTableClause::Constraint, &constraint, &self._constraint[..]),
+    ];
+
+    let body = definitions
+      .into_iter()
+      .fold("".to_owned(), |body, (clause, prefix, items)| {
+        self.concat_definition(body, fmts, clause, prefix, items)
+      });
+
+    if body.is_empty() {
+      return query;
+    }
+
+    let body = body.trim_end();
+    format!("{query}({lb}{indent}{body}{lb}){space}{lb}")
+  }
+
+  fn concat_definition(
+    &self,
+    body: String,
+    fmts: &fmt::Formatter,
+    clause: CreateTableClause,
+    prefix: &str,
+    items: &[String],
+  ) -> String {
+    let fmt::Formatter {
+      comma,
+      lb,
+      indent,
+      space,
+      ..
+    } = fmts;
+    let separator = format!("{comma}{lb}{indent}");
+    let items = items
+      .iter()
+      .filter(|item| item.is_empty() == false)
+      .map(|item| format!("{prefix}{item}"))
+      .collect::<Vec<_>>();
+
+    let sql = if items.is_empty() == false {
+      let definitions = items.join(&separator);
+      format!("{definitions}{space}")
+    } else {
+      "".to_owned()
+    };
+    let has_definitions = items.is_empty() == false
+      || self._raw_before.iter().any(|(item, _)| *item == clause)
+      || self._raw_after.iter().any(|(item, _)| *item == clause);
+
+    if has_definitions == false {
+      return body;
+    }
+
+    let body = if body.is_empty() {
+      body
+    } else {
+      format!("{}{separator}", body.trim_end())
+    };
+    concat_raw_before_after(&self._raw_before, &self._raw_after, body, fmts, clause, sql)
+  }
+
+  #[cfg(feature = "postgresql")]
+  fn concat_inherits(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if self._inherits.is_empty() == false {
+      let tables = self._inherits.join(comma);
+      format!("INHERITS{space}({tables}){space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateTableClause::Inherits,
+      sql,
+    )
+  }
+
+  #[cfg(feature = "postgresql")]
+  fn concat_partition_by(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._partition_by.is_empty() == false {
+      let partition_by = self._partition_by;
+      format!("PARTITION BY{space}{partition_by}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateTableClause::PartitionBy,
+      sql,
+    )
+  }
+
+  #[cfg(feature = "postgresql")]
+  fn concat_tablespace(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._tablespace.is_empty() == false {
+      let tablespace = self._tablespace;
+      format!("TABLESPACE{space}{tablespace}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateTableClause::Tablespace,
+      sql,
+    )
+  }
+}
@@ -0,0 +1,2 @@
+mod create_table;
+mod create_table_internal;
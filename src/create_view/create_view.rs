@@ -0,0 +1,354 @@
+use crate::{
+  behavior::{push_unique, trim, Concat, TransactionQuery},
+  fmt,
+  structure::{CreateView, CreateViewClause, Dialect, Error, Select},
+};
+use std::borrow::Cow;
+
+impl<'a> CreateView<'a> {
+  /// Gets the current state of the [CreateView] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .select(sql::Select::new().select("*").from("users").where_clause("active = true"))
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// CREATE VIEW active_users AS SELECT * FROM users WHERE active = true
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](CreateView::as_string) method checking the clauses in use, the builder and the nested builder,
+  /// against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_view = sql::CreateView::new()
+  ///   .create_or_replace_view("active_users")
+  ///   .select(sql::Select::new().select("*").from("users"));
+  ///
+  /// assert_eq!(
+  ///   create_view.as_string_for(sql::Dialect::MySql),
+  ///   Ok("CREATE OR REPLACE VIEW active_users AS SELECT * FROM users".to_owned())
+  /// );
+  /// assert!(create_view.as_string_for(sql::Dialect::Sqlite).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The column aliases of the view, rendered in parentheses after the view name
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("user_logins")
+  ///   .column("user_login")
+  ///   .column("user_name")
+  ///   .select(sql::Select::new().select("login, name").from("users"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "CREATE VIEW user_logins (user_login, user_name) AS SELECT login, name FROM users"
+  /// );
+  /// ```
+  pub fn column(mut self, column_name: &str) -> Self {
+    push_unique(&mut self._column, column_name.trim().to_owned());
+    self
+  }
+
+  /// The create view clause with the `OR REPLACE` option. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_or_replace_view("active_users")
+  ///   .select(sql::Select::new().select("*").from("users"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE OR REPLACE VIEW active_users AS SELECT * FROM users");
+  /// ```
+  pub fn create_or_replace_view(mut self, view_name: impl Into<Cow<'a, str>>) -> Self {
+    self._create_view = trim(view_name.into());
+    self._or_replace = true;
+    self
+  }
+
+  /// The create view clause. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("active_users");
+  ///
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view(sql::quote_ident("ActiveUsers", sql::Dialect::Postgres));
+  /// ```
+  pub fn create_view(mut self, view_name: impl Into<Cow<'a, str>>) -> Self {
+    self._create_view = trim(view_name.into());
+    self._or_replace = false;
+    self
+  }
+
+  /// Prints the current state of the [CreateView] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .select(sql::Select::new().select("*").from("users").where_clause("active = true"))
+  ///   .with_check_option()
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users
+  /// AS
+  /// SELECT *
+  /// FROM users
+  /// WHERE active = true
+  /// WITH CHECK OPTION
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds the `MATERIALIZED` option, the result of the query is stored and updated by the
+  /// `RefreshMaterializedView` builder, this method can be used enabling the feature flag `postgresql`.
+  /// A materialized view doesn't accept the `OR REPLACE` and the `WITH CHECK OPTION` clauses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("sales_summary")
+  ///   .materialized()
+  ///   .select(sql::Select::new().select("seller_id, sum(amount)").from("sales").group_by("seller_id"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "CREATE MATERIALIZED VIEW sales_summary AS SELECT seller_id, sum(amount) FROM sales GROUP BY seller_id"
+  /// );
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn materialized(mut self) -> Self {
+    self._materialized = true;
+    self
+  }
+
+  /// Create CreateView's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [CreateView] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "create view active_users";
+  /// let create_view = sql::CreateView::new()
+  ///   .raw(raw_query)
+  ///   .select(sql::Select::new().select("*").from("users"))
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// create view active_users AS SELECT * FROM users
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "with (security_barrier)";
+  /// let create_view = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .raw_after(sql::CreateViewClause::CreateView, raw)
+  ///   .select(sql::Select::new().select("*").from("users"))
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// CREATE VIEW active_users with (security_barrier) AS SELECT * FROM users
+  /// ```
+  pub fn raw_after(mut self, clause: CreateViewClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* the active users */";
+  /// let create_view = sql::CreateView::new()
+  ///   .raw_before(sql::CreateViewClause::CreateView, raw)
+  ///   .create_view("active_users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* the active users */ CREATE VIEW active_users
+  /// ```
+  pub fn raw_before(mut self, clause: CreateViewClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// The query of the view, rendered after the `AS` keyword. This method overrides the previous value.
+  /// A query with bound values is returned as an error by the [as_string_for](CreateView::as_string_for) method,
+  /// the definition of a view can't have parameters
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let active_users = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_clause("active = true");
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .select(active_users)
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE VIEW active_users AS SELECT * FROM users WHERE active = true");
+  /// ```
+  pub fn select(mut self, select: Select<'a>) -> Self {
+    self._select = Some(select);
+    self
+  }
+
+  /// Adds the `WITH CHECK OPTION`, the rows inserted or updated through the view must satisfy the view's conditions
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("active_users")
+  ///   .select(sql::Select::new().select("*").from("users").where_clause("active = true"))
+  ///   .with_check_option()
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "CREATE VIEW active_users AS SELECT * FROM users WHERE active = true WITH CHECK OPTION"
+  /// );
+  /// ```
+  pub fn with_check_option(mut self) -> Self {
+    self._with_check_option = true;
+    self
+  }
+
+  /// Adds the `WITH DATA` option of a materialized view, the view is populated when created,
+  /// this method can be used enabling the feature flag `postgresql`. This method overrides the [with_no_data](CreateView::with_no_data) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("sales_summary")
+  ///   .materialized()
+  ///   .select(sql::Select::new().select("*").from("sales"))
+  ///   .with_data()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE MATERIALIZED VIEW sales_summary AS SELECT * FROM sales WITH DATA");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn with_data(mut self) -> Self {
+    self._with_data = Some(true);
+    self
+  }
+
+  /// Adds the `WITH NO DATA` option of a materialized view, the view is populated by the next refresh,
+  /// this method can be used enabling the feature flag `postgresql`. This method overrides the [with_data](CreateView::with_data) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::CreateView::new()
+  ///   .create_view("sales_summary")
+  ///   .materialized()
+  ///   .select(sql::Select::new().select("*").from("sales"))
+  ///   .with_no_data()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "CREATE MATERIALIZED VIEW sales_summary AS SELECT * FROM sales WITH NO DATA");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn with_no_data(mut self) -> Self {
+    self._with_data = Some(false);
+    self
+  }
+}
+
+impl TransactionQuery for CreateView<'_> {}
+
+impl std::fmt::Display for CreateView<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for CreateView<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
@@ -0,0 +1,186 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt, placeholder,
+  structure::{CreateView, CreateViewClause, Dialect, Error},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, CreateViewClause> for CreateView<'_> {}
+
+impl Concat for CreateView<'_> {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_create_view(query, fmts);
+    query = self.concat_column(query, fmts);
+    query = self.concat_select(query, fmts);
+    query = self.concat_with_check_option(query, fmts);
+    #[cfg(feature = "postgresql")]
+    {
+      query = self.concat_with_data(query, fmts);
+    }
+
+    placeholder::resolve_nested(query.trim_end())
+  }
+
+  fn params(&self) -> Vec<Value> {
+    self._select.iter().flat_map(|select| select.params()).collect()
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(dialect, "OR REPLACE", self._or_replace, &[Postgres, MySql])?;
+    check(
+      dialect,
+      "WITH CHECK OPTION",
+      self._with_check_option,
+      &[Postgres, MySql, MsSql],
+    )?;
+    #[cfg(feature = "postgresql")]
+    {
+      check(dialect, "MATERIALIZED", self._materialized, &[Postgres])?;
+      check(dialect, "WITH DATA", self._with_data.is_some(), &[Postgres])?;
+      check(
+        dialect,
+        "MATERIALIZED OR REPLACE",
+        self._materialized && self._or_replace,
+        &[],
+      )?;
+      check(
+        dialect,
+        "MATERIALIZED WITH CHECK OPTION",
+        self._materialized && self._with_check_option,
+        &[],
+      )?;
+      check(
+        dialect,
+        "WITH DATA WITHOUT MATERIALIZED",
+        self._with_data.is_some() && self._materialized == false,
+        &[],
+      )?;
+    }
+    if let Some(select) = &self._select {
+      check(
+        dialect,
+        "SELECT WITH BOUND VALUES",
+        select.params().is_empty() == false,
+        &[],
+      )?;
+      select.validate(dialect)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl CreateView<'_> {
+  fn concat_column(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if self._column.is_empty() == false {
+      let columns = self._column.join(comma);
+      format!("({columns}){space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateViewClause::Column,
+      sql,
+    )
+  }
+
+  fn concat_create_view(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._create_view.is_empty() == false {
+      let view_name = &self._create_view;
+      let or_replace = if self._or_replace {
+        format!("OR REPLACE{space}")
+      } else {
+        "".to_owned()
+      };
+      #[cfg(feature = "postgresql")]
+      let materialized = if self._materialized {
+        format!("MATERIALIZED{space}")
+      } else {
+        "".to_owned()
+      };
+      #[cfg(not(feature = "postgresql"))]
+      let materialized = "";
+      format!("CREATE{space}{or_replace}{materialized}VIEW{space}{view_name}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateViewClause::CreateView,
+      sql,
+    )
+  }
+
+  fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if let Some(select) = &self._select {
+      let select_string = placeholder::nested(&select.concat(fmts));
+      format!("AS{space}{lb}{select_string}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateViewClause::Select,
+      sql,
+    )
+  }
+
+  fn concat_with_check_option(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._with_check_option {
+      format!("WITH CHECK OPTION{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateViewClause::WithCheckOption,
+      sql,
+    )
+  }
+
+  #[cfg(feature = "postgresql")]
+  fn concat_with_data(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = match self._with_data {
+      Some(true) => format!("WITH DATA{space}{lb}"),
+      Some(false) => format!("WITH NO DATA{space}{lb}"),
+      None => "".to_owned(),
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      CreateViewClause::WithData,
+      sql,
+    )
+  }
+}
@@ -0,0 +1,2 @@
+mod create_view;
+mod create_view_internal;
@@ -0,0 +1,117 @@
+use crate::{behavior::push_unique, structure::Cte};
+
+impl<'a> Cte<'a> {
+  /// Adds a column to the column list of the expression, the columns are accumulated on consecutive calls
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .with(
+  ///     sql::Cte::new("totals").column("user_id").column("total"),
+  ///     sql::Select::new().select("user_id, sum(amount)").from("orders").group_by("user_id"),
+  ///   )
+  ///   .select("*")
+  ///   .from("totals")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "WITH totals(user_id, total) AS (SELECT user_id, sum(amount) FROM orders GROUP BY user_id) SELECT * FROM totals"
+  /// );
+  /// ```
+  pub fn column(mut self, column_name: &str) -> Self {
+    push_unique(&mut self._column, column_name.trim().to_owned());
+    self
+  }
+
+  /// Adds the `MATERIALIZED` hint, the query of the expression is computed once,
+  /// this method can be used enabling the feature flag `postgresql`. This method overrides the [not_materialized](Cte::not_materialized) hint
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .with(
+  ///     sql::Cte::new("active_users").materialized(),
+  ///     sql::Select::new().select("*").from("users").where_clause("active = true"),
+  ///   )
+  ///   .select("*")
+  ///   .from("active_users")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "WITH active_users AS MATERIALIZED (SELECT * FROM users WHERE active = true) SELECT * FROM active_users"
+  /// );
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn materialized(mut self) -> Self {
+    self._materialized = Some(true);
+    self
+  }
+
+  /// Create Cte's instance with the name of the expression
+  pub fn new(name: &'a str) -> Self {
+    Self {
+      _name: name.trim(),
+      ..Default::default()
+    }
+  }
+
+  /// Adds the `NOT MATERIALIZED` hint, the query of the expression can be inlined in the main query,
+  /// this method can be used enabling the feature flag `postgresql`. This method overrides the [materialized](Cte::materialized) hint
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .with(
+  ///     sql::Cte::new("active_users").not_materialized(),
+  ///     sql::Select::new().select("*").from("users").where_clause("active = true"),
+  ///   )
+  ///   .select("*")
+  ///   .from("active_users")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "WITH active_users AS NOT MATERIALIZED (SELECT * FROM users WHERE active = true) SELECT * FROM active_users"
+  /// );
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn not_materialized(mut self) -> Self {
+    self._materialized = Some(false);
+    self
+  }
+
+  /// Marks the expression as recursive, the query of the expression can reference the expression itself.
+  /// The with clause is rendered as `WITH RECURSIVE` when one of the expressions is recursive
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let numbers = sql::Select::new()
+  ///   .select("1")
+  ///   .union(sql::Select::new().select("n + 1").from("numbers").where_clause("n < 10"));
+  ///
+  /// let query = sql::Select::new()
+  ///   .with(sql::Cte::new("numbers").column("n").recursive(), numbers)
+  ///   .select("n")
+  ///   .from("numbers")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "WITH RECURSIVE numbers(n) AS ((SELECT 1) UNION (SELECT n + 1 FROM numbers WHERE n < 10)) SELECT n FROM numbers"
+  /// );
+  /// ```
+  pub fn recursive(mut self) -> Self {
+    self._recursive = true;
+    self
+  }
+}
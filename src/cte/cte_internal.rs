@@ -0,0 +1,51 @@
+use crate::{
+  fmt,
+  structure::{Cte, Dialect, Error},
+};
+
+impl Cte<'_> {
+  /// The name of the expression followed by the column list and the materialization hint,
+  /// rendered before the query of the expression in the with clause
+  pub(crate) fn concat_name(&self, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, space, .. } = fmts;
+    let name = self._name;
+    let columns = if self._column.is_empty() == false {
+      let columns = self._column.join(comma);
+      format!("({columns})")
+    } else {
+      "".to_owned()
+    };
+    #[cfg(feature = "postgresql")]
+    let materialized = match self._materialized {
+      Some(true) => format!("{space}MATERIALIZED"),
+      Some(false) => format!("{space}NOT{space}MATERIALIZED"),
+      None => "".to_owned(),
+    };
+    #[cfg(not(feature = "postgresql"))]
+    let materialized = "";
+
+    format!("{name}{columns}{space}AS{materialized}")
+  }
+
+  pub(crate) fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(dialect, "WITH RECURSIVE", self._recursive, &[Postgres, Sqlite, MySql])?;
+    #[cfg(feature = "postgresql")]
+    check(
+      dialect,
+      "MATERIALIZED",
+      self._materialized.is_some(),
+      &[Postgres, Sqlite],
+    )?;
+
+    Ok(())
+  }
+}
+
+impl<'a> From<&'a str> for Cte<'a> {
+  fn from(name: &'a str) -> Self {
+    Cte::new(name)
+  }
+}
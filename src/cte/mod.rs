@@ -0,0 +1,2 @@
+mod cte;
+mod cte_internal;
@@ -1,8 +1,12 @@
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+use crate::structure::Cte;
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
-  structure::{Delete, DeleteClause},
+  behavior::{bind, push_unique, trim, Concat, ExplainQuery, TransactionQuery, WithQuery},
+  fmt, placeholder,
+  structure::{Delete, DeleteClause, Dialect, Error, Placeholder},
+  value::Value,
 };
+use std::{borrow::Cow, collections::HashMap};
 
 impl<'a> Delete<'a> {
   /// The same as [where_clause](Delete::where_clause) method, useful to write more idiomatic SQL query
@@ -21,6 +25,21 @@ impl<'a> Delete<'a> {
     self
   }
 
+  /// The same as [where_bind](Delete::where_bind) method, useful to write more idiomatic SQL query
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let delete = sql::Delete::new()
+  ///   .where_bind("login = $1", "foo")
+  ///   .and_bind("active = $1", true);
+  /// ```
+  pub fn and_bind(mut self, condition: &str, value: impl Into<Value>) -> Self {
+    self = self.where_bind(condition, value);
+    self
+  }
+
   /// Gets the current state of the [Delete] and returns it as string
   ///
   /// # Examples
@@ -42,6 +61,134 @@ impl<'a> Delete<'a> {
     self.concat(&fmts)
   }
 
+  /// Gets the current state of the [Delete] and returns it as string together with the bound values,
+  /// the value at index `n` of the list is the value of the placeholder `$n+1`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_bind("id = $1", 42)
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "DELETE FROM users WHERE id = $1");
+  /// assert_eq!(params, vec![sql::Value::from(42)]);
+  /// ```
+  pub fn as_query(&self) -> (String, Vec<Value>) {
+    (self.as_string(), self.params())
+  }
+
+  /// The same as [as_query](Delete::as_query) method rendering the placeholders in the placeholder style,
+  /// using [Placeholder::QuestionMark] the values are repeated and reordered to follow the placeholders.
+  /// A positional placeholder without bound value is returned as [Error::UnboundPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_bind("id = $1", 42)
+  ///   .as_query_with(sql::Placeholder::QuestionMark)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "DELETE FROM users WHERE id = ?");
+  /// assert_eq!(params, vec![sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_with(&self, style: Placeholder) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(self.as_query(), &style)
+  }
+
+  /// The same as [as_query_with](Delete::as_query_with) method resolving the named placeholders like `:login`,
+  /// each name is replaced by a positional placeholder numbered after the bound values
+  /// in the order they appear, a repeated name reuses the same position. A name not found in the map is returned
+  /// as [Error::UnknownPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use std::collections::HashMap;
+  /// use sql_query_builder as sql;
+  ///
+  /// let named = HashMap::from([("login", sql::Value::from("foo")), ("tenant", sql::Value::from(42))]);
+  /// let (query, params) = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("login = :login")
+  ///   .and("tenant_id = :tenant")
+  ///   .as_query_named(&named, sql::Placeholder::Dollar)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "DELETE FROM users WHERE login = $1 AND tenant_id = $2");
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_named(
+    &self,
+    named: &HashMap<&str, Value>,
+    style: Placeholder,
+  ) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(placeholder::resolve_named(self.as_query(), named)?, &style)
+  }
+
+  /// The same as [as_query](Delete::as_query) method checking the clauses in use against the dialect like
+  /// [as_string_for](Delete::as_string_for), the placeholders are rendered in the style of the dialect, `$1` for Postgres,
+  /// `?` for SQLite and MySQL and `@p1` for SQL Server, and the values follow the placeholders
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_bind("id = $1", 42)
+  ///   .as_query_for(sql::Dialect::MsSql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "DELETE FROM users WHERE id = @p1");
+  /// assert_eq!(params, vec![sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_for(&self, dialect: Dialect) -> Result<(String, Vec<Value>), Error> {
+    crate::dialect::render_query(self, dialect)
+  }
+
+  /// The same as [as_string](Delete::as_string) method checking the clauses in use, the builder and the nested builders,
+  /// against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause].
+  /// The placeholders are rendered in the style of the dialect, see [as_query_for](Delete::as_query_for)
+  /// The dialect specific clauses are still enabled by the feature flags, so one binary can target many dialects
+  /// enabling all the feature flags it needs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = $1")
+  ///   .as_string_for(sql::Dialect::MsSql);
+  ///
+  /// assert_eq!(query, Ok("DELETE FROM users WHERE id = @p1".to_owned()));
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The same as [as_string](Delete::as_string) method rendering the placeholders in the placeholder style
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_clause("id = $1")
+  ///   .as_string_with(sql::Placeholder::At);
+  ///
+  /// assert_eq!(query, "DELETE FROM users WHERE id = @p1");
+  /// ```
+  pub fn as_string_with(&self, style: Placeholder) -> String {
+    placeholder::convert(&self.as_string(), &style)
+  }
+
   /// Prints the current state of the [Delete] into console output in a more ease to read version.
   /// This method is useful to debug complex queries or just to print the generated SQL while you type
   ///
@@ -81,9 +228,33 @@ impl<'a> Delete<'a> {
   /// let delete = sql::Delete::new()
   ///   .delete_from("address")
   ///   .delete_from("orders");
+  ///
+  /// let delete = sql::Delete::new()
+  ///   .delete_from(sql::quote_qualified(&["sales", "orders"], sql::Dialect::Postgres));
   /// ```
-  pub fn delete_from(mut self, table_name: &'a str) -> Self {
-    self._delete_from = table_name.trim();
+  pub fn delete_from(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._delete_from = trim(table_name.into());
+    self
+  }
+
+  /// The limit clause, this method can be used enabling the feature flag `mysql`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("sessions")
+  ///   .where_clause("expired = true")
+  ///   .order_by("created_at")
+  ///   .limit("1000")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DELETE FROM sessions WHERE expired = true ORDER BY created_at LIMIT 1000");
+  /// ```
+  #[cfg(any(doc, feature = "mysql"))]
+  pub fn limit(mut self, num: &'a str) -> Self {
+    self._limit = num.trim();
     self
   }
 
@@ -92,6 +263,33 @@ impl<'a> Delete<'a> {
     Self::default()
   }
 
+  /// The order by clause, this method can be used enabling the feature flag `mysql`
+  #[cfg(any(doc, feature = "mysql"))]
+  pub fn order_by(mut self, column: &str) -> Self {
+    push_unique(&mut self._order_by, column.trim().to_owned());
+    self
+  }
+
+  /// The output clause, this method can be used enabling the feature flag `mssql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .output("deleted.id")
+  ///   .where_clause("active = false")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DELETE FROM users OUTPUT deleted.id WHERE active = false");
+  /// ```
+  #[cfg(any(doc, feature = "mssql"))]
+  pub fn output(mut self, output_name: &str) -> Self {
+    push_unique(&mut self._output, output_name.trim().to_owned());
+    self
+  }
+
   /// Prints the current state of the [Delete] into console output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
@@ -172,13 +370,33 @@ impl<'a> Delete<'a> {
     self
   }
 
-  /// The returning clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(any(doc, feature = "postgresql"))]
+  /// The returning clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
   pub fn returning(mut self, output_name: &str) -> Self {
     push_unique(&mut self._returning, output_name.trim().to_owned());
     self
   }
 
+  /// The table hint clause, this method can be used enabling the feature flag `mssql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .table_hint("ROWLOCK")
+  ///   .where_clause("active = false")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DELETE FROM users WITH (ROWLOCK) WHERE active = false");
+  /// ```
+  #[cfg(any(doc, feature = "mssql"))]
+  pub fn table_hint(mut self, hint: &str) -> Self {
+    push_unique(&mut self._table_hint, hint.trim().to_owned());
+    self
+  }
+
   /// The where clause
   ///
   /// # Examples
@@ -194,7 +412,24 @@ impl<'a> Delete<'a> {
     self
   }
 
-  /// The with clause, this method can be used enabling the feature flag `postgresql`
+  /// The where clause with a bound value, the placeholder `$1` of the condition refers to the value
+  /// and is renumbered after the values already bound
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let delete = sql::Delete::new()
+  ///   .delete_from("users")
+  ///   .where_bind("login = $1", "foo");
+  /// ```
+  pub fn where_bind(mut self, condition: &str, value: impl Into<Value>) -> Self {
+    let condition = bind(&mut self._params, condition, [value.into()]);
+    push_unique(&mut self._where, condition);
+    self
+  }
+
+  /// The with clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`
   ///
   /// # Examples
   /// ```
@@ -219,13 +454,17 @@ impl<'a> Delete<'a> {
   /// DELETE FROM users
   /// WHERE id in (select * from deactivated_users)
   /// ```
-  #[cfg(any(doc, feature = "postgresql"))]
-  pub fn with(mut self, name: &'a str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim(), std::sync::Arc::new(query)));
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+  pub fn with(mut self, name: impl Into<Cte<'a>>, query: impl WithQuery + 'static) -> Self {
+    self._with.push((name.into(), std::sync::Arc::new(query)));
     self
   }
 }
 
+impl ExplainQuery for Delete<'_> {}
+
+impl TransactionQuery for Delete<'_> {}
+
 impl WithQuery for Delete<'_> {}
 
 impl std::fmt::Display for Delete<'_> {
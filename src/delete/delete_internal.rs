@@ -1,7 +1,8 @@
 use crate::{
   behavior::{concat_raw_before_after, Concat, ConcatMethods},
-  fmt,
-  structure::{Delete, DeleteClause},
+  fmt, placeholder,
+  structure::{Delete, DeleteClause, Dialect, Error},
+  value::Value,
 };
 
 impl<'a> ConcatMethods<'a, DeleteClause> for Delete<'_> {}
@@ -10,40 +11,121 @@ impl Concat for Delete<'_> {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_owned();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    #[cfg(feature = "postgresql")]
+    query = self.concat_raw(query, fmts, &self._raw);
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_with(
         &self._raw_before,
         &self._raw_after,
         query,
-        &fmts,
+        fmts,
         DeleteClause::With,
         &self._with,
       );
     }
-    query = self.concat_delete_from(query, &fmts);
+    query = self.concat_delete_from(query, fmts);
+    #[cfg(feature = "mssql")]
+    {
+      query = self.concat_table_hint(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        DeleteClause::TableHint,
+        &self._table_hint,
+      );
+      query = self.concat_output(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        DeleteClause::Output,
+        &self._output,
+      );
+    }
     query = self.concat_where(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       DeleteClause::Where,
       &self._where,
     );
-    #[cfg(feature = "postgresql")]
+    #[cfg(feature = "mysql")]
+    {
+      query = self.concat_order_by(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        DeleteClause::OrderBy,
+        &self._order_by,
+      );
+      query = self.concat_limit(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        DeleteClause::Limit,
+        self._limit,
+      );
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_returning(
         &self._raw_before,
         &self._raw_after,
         query,
-        &fmts,
+        fmts,
         DeleteClause::Returning,
         &self._returning,
       );
     }
 
-    query.trim_end().to_owned()
+    placeholder::resolve_nested(query.trim_end())
+  }
+
+  fn params(&self) -> Vec<Value> {
+    let params = self._params.iter().cloned();
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    let params = params.chain(self._with.iter().flat_map(|(_, query)| query.params()));
+    params.collect()
+  }
+
+  #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql", feature = "mssql")))]
+  fn validate(&self, _dialect: Dialect) -> Result<(), Error> {
+    Ok(())
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql", feature = "mssql"))]
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use {crate::dialect::check, Dialect::*};
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      check(
+        dialect,
+        "RETURNING",
+        self._returning.is_empty() == false,
+        &[Postgres, Sqlite],
+      )?;
+      for (cte, query) in self._with.iter() {
+        cte.validate(dialect)?;
+        query.validate(dialect)?;
+      }
+    }
+    #[cfg(feature = "mysql")]
+    {
+      check(dialect, "LIMIT", self._limit.is_empty() == false, &[MySql])?;
+      check(dialect, "ORDER BY", self._order_by.is_empty() == false, &[MySql])?;
+    }
+    #[cfg(feature = "mssql")]
+    {
+      check(dialect, "OUTPUT", self._output.is_empty() == false, &[MsSql])?;
+      check(dialect, "TABLE HINT", self._table_hint.is_empty() == false, &[MsSql])?;
+    }
+
+    Ok(())
   }
 }
 
@@ -51,7 +133,7 @@ impl Delete<'_> {
   fn concat_delete_from(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._delete_from.is_empty() == false {
-      let table_name = self._delete_from;
+      let table_name = &self._delete_from;
       format!("DELETE FROM{space}{table_name}{space}{lb}")
     } else {
       "".to_owned()
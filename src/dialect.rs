@@ -0,0 +1,52 @@
+use crate::{
+  behavior::Concat,
+  fmt, placeholder,
+  structure::{Dialect, Error, Placeholder},
+  value::Value,
+};
+
+/// Returns an error when the clause is in use and the dialect is not in the list of dialects that support it
+pub fn check(dialect: Dialect, clause: &'static str, in_use: bool, supported_by: &[Dialect]) -> Result<(), Error> {
+  if in_use && supported_by.contains(&dialect) == false {
+    return Err(Error::UnsupportedClause { clause, dialect });
+  }
+  Ok(())
+}
+
+/// The placeholder style used by the dialect, `?` for SQLite and MySQL and `@p1` for SQL Server
+pub fn placeholder_style(dialect: Dialect) -> Placeholder {
+  match dialect {
+    Dialect::Postgres => Placeholder::Dollar,
+    Dialect::Sqlite | Dialect::MySql => Placeholder::QuestionMark,
+    Dialect::MsSql => Placeholder::At,
+  }
+}
+
+/// Checks the builder against the dialect and renders it in one line with the placeholders of the dialect
+pub fn render(query: &impl Concat, dialect: Dialect) -> Result<String, Error> {
+  query.validate(dialect)?;
+  let sql = query.concat(&fmt::one_line_for(dialect));
+  Ok(placeholder::convert(&sql, &placeholder_style(dialect)))
+}
+
+/// The same as [render] returning the bound values in the order of the placeholders of the dialect
+pub fn render_query(query: &impl Concat, dialect: Dialect) -> Result<(String, Vec<Value>), Error> {
+  query.validate(dialect)?;
+  let sql = query.concat(&fmt::one_line_for(dialect));
+  placeholder::convert_query((sql, query.params()), &placeholder_style(dialect))
+}
+
+impl std::fmt::Display for Error {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Error::UnsupportedClause { clause, dialect } => {
+        write!(f, "the clause {clause} is not supported by the dialect {dialect:?}")
+      }
+      Error::UnboundPlaceholder { position } => write!(f, "the placeholder ${position} has no bound value"),
+      Error::UnknownPlaceholder { name } => write!(f, "the placeholder :{name} has no value in the map"),
+      Error::MultipleStatementsWithParams => write!(f, "the placeholders belong to more than one statement"),
+    }
+  }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,279 @@
+use crate::{
+  behavior::{push_unique, Concat, TransactionQuery},
+  fmt,
+  structure::{Dialect, DropIndex, DropIndexClause, Error},
+};
+
+impl DropIndex {
+  /// Gets the current state of the [DropIndex] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP INDEX users_login_idx
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](DropIndex::as_string) method checking the clauses in use against the dialect.
+  /// A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .cascade();
+  ///
+  /// assert_eq!(
+  ///   drop_index.as_string_for(sql::Dialect::Postgres),
+  ///   Ok("DROP INDEX users_login_idx CASCADE".to_owned())
+  /// );
+  /// assert!(drop_index.as_string_for(sql::Dialect::Sqlite).is_err());
+  ///
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .on("users");
+  ///
+  /// assert_eq!(
+  ///   drop_index.as_string_for(sql::Dialect::MySql),
+  ///   Ok("DROP INDEX users_login_idx ON users".to_owned())
+  /// );
+  /// assert!(drop_index.as_string_for(sql::Dialect::Postgres).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// Adds the `CASCADE` option, the objects that depend on the index are dropped too.
+  /// This method overrides the [restrict](DropIndex::restrict) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .cascade()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP INDEX users_login_idx CASCADE");
+  /// ```
+  pub fn cascade(mut self) -> Self {
+    self._cascade = true;
+    self._restrict = false;
+    self
+  }
+
+  /// Prints the current state of the [DropIndex] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .drop_index("orders_user_id_idx")
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP INDEX users_login_idx, orders_user_id_idx
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// The drop index clause, the index names are accumulated and dropped in the same statement
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .drop_index("orders_user_id_idx")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP INDEX users_login_idx, orders_user_id_idx");
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index(&sql::quote_ident("UsersLogin", sql::Dialect::Postgres))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP INDEX \"UsersLogin\"");
+  /// ```
+  pub fn drop_index(mut self, index_name: &str) -> Self {
+    push_unique(&mut self._drop_index, index_name.trim().to_owned());
+    self
+  }
+
+  /// The drop index clause with the `IF EXISTS` option, the option applies to all indexes of the statement
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index_if_exists("users_login_idx")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP INDEX IF EXISTS users_login_idx");
+  /// ```
+  pub fn drop_index_if_exists(mut self, index_name: &str) -> Self {
+    push_unique(&mut self._drop_index, index_name.trim().to_owned());
+    self._if_exists = true;
+    self
+  }
+
+  /// Create DropIndex's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The table of the index, MySQL and SQL Server require it. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .on("users")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP INDEX users_login_idx ON users");
+  /// ```
+  pub fn on(mut self, table_name: &str) -> Self {
+    self._on = table_name.trim().to_owned();
+    self
+  }
+
+  /// Prints the current state of the [DropIndex] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* teardown */";
+  /// let drop_index = sql::DropIndex::new()
+  ///   .raw(raw_query)
+  ///   .drop_index("users_login_idx")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* teardown */ DROP INDEX users_login_idx
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* teardown */";
+  /// let drop_index = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .raw_after(sql::DropIndexClause::DropIndex, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP INDEX users_login_idx /* teardown */
+  /// ```
+  pub fn raw_after(mut self, clause: DropIndexClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* teardown */";
+  /// let drop_index = sql::DropIndex::new()
+  ///   .raw_before(sql::DropIndexClause::DropIndex, raw)
+  ///   .drop_index("users_login_idx")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* teardown */ DROP INDEX users_login_idx
+  /// ```
+  pub fn raw_before(mut self, clause: DropIndexClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds the `RESTRICT` option, the index is not dropped if any object depends on it.
+  /// This method overrides the [cascade](DropIndex::cascade) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropIndex::new()
+  ///   .drop_index("users_login_idx")
+  ///   .restrict()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP INDEX users_login_idx RESTRICT");
+  /// ```
+  pub fn restrict(mut self) -> Self {
+    self._restrict = true;
+    self._cascade = false;
+    self
+  }
+}
+
+impl TransactionQuery for DropIndex {}
+
+impl std::fmt::Display for DropIndex {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for DropIndex {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
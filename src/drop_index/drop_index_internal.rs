@@ -0,0 +1,80 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt,
+  structure::{Dialect, DropIndex, DropIndexClause, Error},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, DropIndexClause> for DropIndex {}
+
+impl Concat for DropIndex {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_drop_index(query, fmts);
+
+    query.trim_end().to_owned()
+  }
+
+  fn params(&self) -> Vec<Value> {
+    vec![]
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(dialect, "CASCADE", self._cascade, &[Postgres])?;
+    check(dialect, "RESTRICT", self._restrict, &[Postgres])?;
+    check(dialect, "IF EXISTS", self._if_exists, &[Postgres, Sqlite, MsSql])?;
+    check(dialect, "MULTIPLE INDEXES", self._drop_index.len() > 1, &[Postgres])?;
+    check(dialect, "ON", self._on.is_empty() == false, &[MySql, MsSql])?;
+    check(
+      dialect,
+      "DROP INDEX WITHOUT ON",
+      self._drop_index.is_empty() == false && self._on.is_empty(),
+      &[Postgres, Sqlite],
+    )?;
+
+    Ok(())
+  }
+}
+
+impl DropIndex {
+  fn concat_drop_index(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if self._drop_index.is_empty() == false {
+      let names = self._drop_index.join(comma);
+      let if_exists = if self._if_exists {
+        format!("IF EXISTS{space}")
+      } else {
+        "".to_owned()
+      };
+      let on = if self._on.is_empty() == false {
+        format!("ON{space}{}{space}", self._on)
+      } else {
+        "".to_owned()
+      };
+      let drop_behavior = if self._cascade {
+        format!("CASCADE{space}")
+      } else if self._restrict {
+        format!("RESTRICT{space}")
+      } else {
+        "".to_owned()
+      };
+      format!("DROP INDEX{space}{if_exists}{names}{space}{on}{drop_behavior}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DropIndexClause::DropIndex,
+      sql,
+    )
+  }
+}
@@ -0,0 +1,2 @@
+mod drop_index;
+mod drop_index_internal;
@@ -0,0 +1,252 @@
+use crate::{
+  behavior::{push_unique, Concat, TransactionQuery},
+  fmt,
+  structure::{Dialect, DropTable, DropTableClause, Error},
+};
+
+impl DropTable {
+  /// Gets the current state of the [DropTable] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP TABLE users
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](DropTable::as_string) method checking the clauses in use against the dialect.
+  /// A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let drop_table = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .cascade();
+  ///
+  /// assert_eq!(
+  ///   drop_table.as_string_for(sql::Dialect::Postgres),
+  ///   Ok("DROP TABLE users CASCADE".to_owned())
+  /// );
+  /// assert!(drop_table.as_string_for(sql::Dialect::Sqlite).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// Adds the `CASCADE` option, the objects that depend on the table are dropped too.
+  /// This method overrides the [restrict](DropTable::restrict) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .cascade()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP TABLE users CASCADE");
+  /// ```
+  pub fn cascade(mut self) -> Self {
+    self._cascade = true;
+    self._restrict = false;
+    self
+  }
+
+  /// Prints the current state of the [DropTable] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let drop_table = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .drop_table("orders")
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP TABLE users, orders
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// The drop table clause, the table names are accumulated and dropped in the same statement.
+  /// Dropping multiple tables is returned as an error by the [as_string_for](DropTable::as_string_for) method in SQLite
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .drop_table("orders")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP TABLE users, orders");
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table(&sql::quote_ident("Users", sql::Dialect::Postgres))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP TABLE \"Users\"");
+  /// ```
+  pub fn drop_table(mut self, table_name: &str) -> Self {
+    push_unique(&mut self._drop_table, table_name.trim().to_owned());
+    self
+  }
+
+  /// The drop table clause with the `IF EXISTS` option, the option applies to all tables of the statement
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table_if_exists("users")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP TABLE IF EXISTS users");
+  /// ```
+  pub fn drop_table_if_exists(mut self, table_name: &str) -> Self {
+    push_unique(&mut self._drop_table, table_name.trim().to_owned());
+    self._if_exists = true;
+    self
+  }
+
+  /// Create DropTable's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [DropTable] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* teardown */";
+  /// let drop_table = sql::DropTable::new()
+  ///   .raw(raw_query)
+  ///   .drop_table("users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* teardown */ DROP TABLE users
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* teardown */";
+  /// let drop_table = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .raw_after(sql::DropTableClause::DropTable, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP TABLE users /* teardown */
+  /// ```
+  pub fn raw_after(mut self, clause: DropTableClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* teardown */";
+  /// let drop_table = sql::DropTable::new()
+  ///   .raw_before(sql::DropTableClause::DropTable, raw)
+  ///   .drop_table("users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* teardown */ DROP TABLE users
+  /// ```
+  pub fn raw_before(mut self, clause: DropTableClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds the `RESTRICT` option, the table is not dropped if any object depends on it.
+  /// This method overrides the [cascade](DropTable::cascade) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropTable::new()
+  ///   .drop_table("users")
+  ///   .restrict()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP TABLE users RESTRICT");
+  /// ```
+  pub fn restrict(mut self) -> Self {
+    self._restrict = true;
+    self._cascade = false;
+    self
+  }
+}
+
+impl TransactionQuery for DropTable {}
+
+impl std::fmt::Display for DropTable {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for DropTable {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
@@ -0,0 +1,252 @@
+use crate::{
+  behavior::{push_unique, Concat, TransactionQuery},
+  fmt,
+  structure::{Dialect, DropView, DropViewClause, Error},
+};
+
+impl DropView {
+  /// Gets the current state of the [DropView] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// DROP VIEW active_users
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](DropView::as_string) method checking the clauses in use against the dialect.
+  /// A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let drop_view = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .cascade();
+  ///
+  /// assert_eq!(
+  ///   drop_view.as_string_for(sql::Dialect::Postgres),
+  ///   Ok("DROP VIEW active_users CASCADE".to_owned())
+  /// );
+  /// assert!(drop_view.as_string_for(sql::Dialect::Sqlite).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// Adds the `CASCADE` option, the objects that depend on the view are dropped too.
+  /// This method overrides the [restrict](DropView::restrict) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .cascade()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP VIEW active_users CASCADE");
+  /// ```
+  pub fn cascade(mut self) -> Self {
+    self._cascade = true;
+    self._restrict = false;
+    self
+  }
+
+  /// Prints the current state of the [DropView] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let drop_view = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .drop_view("open_orders")
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW active_users, open_orders
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// The drop view clause, the view names are accumulated and dropped in the same statement.
+  /// Dropping multiple views is returned as an error by the [as_string_for](DropView::as_string_for) method in SQLite
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .drop_view("open_orders")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP VIEW active_users, open_orders");
+  ///
+  /// let query = sql::DropView::new()
+  ///   .drop_view(&sql::quote_ident("ActiveUsers", sql::Dialect::Postgres))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP VIEW \"ActiveUsers\"");
+  /// ```
+  pub fn drop_view(mut self, view_name: &str) -> Self {
+    push_unique(&mut self._drop_view, view_name.trim().to_owned());
+    self
+  }
+
+  /// The drop view clause with the `IF EXISTS` option, the option applies to all views of the statement
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropView::new()
+  ///   .drop_view_if_exists("active_users")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP VIEW IF EXISTS active_users");
+  /// ```
+  pub fn drop_view_if_exists(mut self, view_name: &str) -> Self {
+    push_unique(&mut self._drop_view, view_name.trim().to_owned());
+    self._if_exists = true;
+    self
+  }
+
+  /// Create DropView's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [DropView] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* teardown */";
+  /// let drop_view = sql::DropView::new()
+  ///   .raw(raw_query)
+  ///   .drop_view("active_users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* teardown */ DROP VIEW active_users
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* teardown */";
+  /// let drop_view = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .raw_after(sql::DropViewClause::DropView, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// DROP VIEW active_users /* teardown */
+  /// ```
+  pub fn raw_after(mut self, clause: DropViewClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* teardown */";
+  /// let drop_view = sql::DropView::new()
+  ///   .raw_before(sql::DropViewClause::DropView, raw)
+  ///   .drop_view("active_users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* teardown */ DROP VIEW active_users
+  /// ```
+  pub fn raw_before(mut self, clause: DropViewClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds the `RESTRICT` option, the view is not dropped if any object depends on it.
+  /// This method overrides the [cascade](DropView::cascade) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::DropView::new()
+  ///   .drop_view("active_users")
+  ///   .restrict()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "DROP VIEW active_users RESTRICT");
+  /// ```
+  pub fn restrict(mut self) -> Self {
+    self._restrict = true;
+    self._cascade = false;
+    self
+  }
+}
+
+impl TransactionQuery for DropView {}
+
+impl std::fmt::Display for DropView {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for DropView {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
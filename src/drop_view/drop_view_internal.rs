@@ -0,0 +1,72 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt,
+  structure::{Dialect, DropView, DropViewClause, Error},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, DropViewClause> for DropView {}
+
+impl Concat for DropView {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_drop_view(query, fmts);
+
+    query.trim_end().to_owned()
+  }
+
+  fn params(&self) -> Vec<Value> {
+    vec![]
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(dialect, "CASCADE", self._cascade, &[Postgres, MySql])?;
+    check(dialect, "RESTRICT", self._restrict, &[Postgres, MySql])?;
+    check(
+      dialect,
+      "MULTIPLE VIEWS",
+      self._drop_view.len() > 1,
+      &[Postgres, MySql, MsSql],
+    )?;
+
+    Ok(())
+  }
+}
+
+impl DropView {
+  fn concat_drop_view(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if self._drop_view.is_empty() == false {
+      let names = self._drop_view.join(comma);
+      let if_exists = if self._if_exists {
+        format!("IF EXISTS{space}")
+      } else {
+        "".to_owned()
+      };
+      let drop_behavior = if self._cascade {
+        format!("CASCADE{space}")
+      } else if self._restrict {
+        format!("RESTRICT{space}")
+      } else {
+        "".to_owned()
+      };
+      format!("DROP VIEW{space}{if_exists}{names}{space}{drop_behavior}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      DropViewClause::DropView,
+      sql,
+    )
+  }
+}
@@ -0,0 +1,382 @@
+#[cfg(any(doc, feature = "postgresql", feature = "mysql"))]
+use crate::structure::ExplainFormat;
+#[cfg(any(doc, feature = "postgresql"))]
+use crate::structure::ExplainOption;
+use crate::{
+  behavior::{push_unique, Concat, ExplainQuery},
+  fmt, placeholder,
+  structure::{Dialect, Error, Explain, ExplainClause, Placeholder},
+  value::Value,
+};
+use std::collections::HashMap;
+
+impl Explain {
+  /// Adds the `ANALYZE` option, the statement is executed and the actual run times are shown.
+  /// Together with the [option](Explain::option) method the `ANALYZE` is rendered as the first option of the list
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Explain::new()
+  ///   .analyze()
+  ///   .explain(sql::Select::new().select("*").from("orders"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "EXPLAIN ANALYZE SELECT * FROM orders");
+  /// ```
+  pub fn analyze(mut self) -> Self {
+    self._analyze = true;
+    self
+  }
+
+  /// Gets the current state of the [Explain] and returns it as string together with the bound values
+  /// of the explained statement, the value at index `n` of the list is the value of the placeholder `$n+1`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Explain::new()
+  ///   .explain(sql::Select::new().select("*").from("orders").where_bind("user_id = $1", 42))
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "EXPLAIN SELECT * FROM orders WHERE user_id = $1");
+  /// assert_eq!(params, vec![sql::Value::from(42)]);
+  /// ```
+  pub fn as_query(&self) -> (String, Vec<Value>) {
+    (self.as_string(), self.params())
+  }
+
+  /// The same as [as_query_with](Explain::as_query_with) method resolving the named placeholders like `:login`,
+  /// each name is replaced by a positional placeholder numbered after the bound values
+  /// in the order they appear, a repeated name reuses the same position. A name not found in the map is returned
+  /// as [Error::UnknownPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use std::collections::HashMap;
+  /// use sql_query_builder as sql;
+  ///
+  /// let named = HashMap::from([("user_id", sql::Value::from(42))]);
+  /// let (query, params) = sql::Explain::new()
+  ///   .explain(sql::Select::new().select("*").from("orders").where_clause("user_id = :user_id"))
+  ///   .as_query_named(&named, sql::Placeholder::QuestionMark)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "EXPLAIN SELECT * FROM orders WHERE user_id = ?");
+  /// assert_eq!(params, vec![sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_named(
+    &self,
+    named: &HashMap<&str, Value>,
+    style: Placeholder,
+  ) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(placeholder::resolve_named(self.as_query(), named)?, &style)
+  }
+
+  /// The same as [as_query](Explain::as_query) method rendering the placeholders in the placeholder style,
+  /// using [Placeholder::QuestionMark] the values are repeated and reordered to follow the placeholders.
+  /// A positional placeholder without bound value is returned as [Error::UnboundPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Explain::new()
+  ///   .explain(sql::Select::new().select("*").from("orders").where_bind("user_id = $1", 42))
+  ///   .as_query_with(sql::Placeholder::QuestionMark)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "EXPLAIN SELECT * FROM orders WHERE user_id = ?");
+  /// assert_eq!(params, vec![sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_with(&self, style: Placeholder) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(self.as_query(), &style)
+  }
+
+  /// Gets the current state of the [Explain] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Explain::new()
+  ///   .explain(sql::Select::new().select("*").from("orders"))
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// EXPLAIN SELECT * FROM orders
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_query](Explain::as_query) method checking the clauses in use against the dialect like
+  /// [as_string_for](Explain::as_string_for), the placeholders are rendered in the style of the dialect, `$1` for Postgres,
+  /// `?` for SQLite and MySQL and `@p1` for SQL Server, and the values follow the placeholders
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Explain::new()
+  ///   .explain(sql::Select::new().select("*").from("orders").where_bind("user_id = $1", 42))
+  ///   .as_query_for(sql::Dialect::MySql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "EXPLAIN SELECT * FROM orders WHERE user_id = ?");
+  /// assert_eq!(params, vec![sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_for(&self, dialect: Dialect) -> Result<(String, Vec<Value>), Error> {
+    crate::dialect::render_query(self, dialect)
+  }
+
+  /// The same as [as_string](Explain::as_string) method checking the options in use and the explained statement
+  /// against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause].
+  /// The placeholders are rendered in the style of the dialect, see [as_query_for](Explain::as_query_for)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let explain = sql::Explain::new()
+  ///   .analyze()
+  ///   .explain(sql::Select::new().select("*").from("orders"));
+  ///
+  /// assert_eq!(
+  ///   explain.as_string_for(sql::Dialect::MySql),
+  ///   Ok("EXPLAIN ANALYZE SELECT * FROM orders".to_owned())
+  /// );
+  /// assert!(explain.as_string_for(sql::Dialect::Sqlite).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The same as [as_string](Explain::as_string) method rendering the placeholders in the placeholder style
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Explain::new()
+  ///   .explain(sql::Select::new().select("*").from("orders").where_clause("user_id = $1"))
+  ///   .as_string_with(sql::Placeholder::At);
+  ///
+  /// assert_eq!(query, "EXPLAIN SELECT * FROM orders WHERE user_id = @p1");
+  /// ```
+  pub fn as_string_with(&self, style: Placeholder) -> String {
+    placeholder::convert(&self.as_string(), &style)
+  }
+
+  /// Prints the current state of the [Explain] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let explain = sql::Explain::new()
+  ///   .analyze()
+  ///   .explain(sql::Select::new().select("*").from("orders").where_clause("user_id = 42"))
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN ANALYZE
+  /// SELECT *
+  /// FROM orders
+  /// WHERE user_id = 42
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// The statement to be explained, any builder of a query or a data modification can be used.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Explain::new()
+  ///   .explain(sql::Update::new().update("orders").set("status = 'paid'").where_clause("id = 42"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "EXPLAIN UPDATE orders SET status = 'paid' WHERE id = 42");
+  /// ```
+  pub fn explain(mut self, query: impl ExplainQuery + 'static) -> Self {
+    self._explain = Some(std::sync::Arc::new(query));
+    self
+  }
+
+  /// The `FORMAT` option, rendered as the last option in parentheses for PostgreSQL and as `FORMAT=X` for MySQL.
+  /// This method overrides the previous value, this method can be used enabling one of the feature flags `postgresql` or `mysql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let explain = sql::Explain::new()
+  ///   .format(sql::ExplainFormat::Json)
+  ///   .explain(sql::Select::new().select("*").from("orders"));
+  ///
+  /// assert_eq!(
+  ///   explain.as_string_for(sql::Dialect::Postgres),
+  ///   Ok("EXPLAIN (FORMAT JSON) SELECT * FROM orders".to_owned())
+  /// );
+  /// assert_eq!(
+  ///   explain.as_string_for(sql::Dialect::MySql),
+  ///   Ok("EXPLAIN FORMAT=JSON SELECT * FROM orders".to_owned())
+  /// );
+  /// ```
+  #[cfg(any(doc, feature = "postgresql", feature = "mysql"))]
+  pub fn format(mut self, format: ExplainFormat) -> Self {
+    self._format = Some(format);
+    self
+  }
+
+  /// Create Explain's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds an option of the PostgreSQL, the options are rendered in parentheses in the call order,
+  /// this method can be used enabling the feature flag `postgresql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Explain::new()
+  ///   .option(sql::ExplainOption::Analyze)
+  ///   .option(sql::ExplainOption::Buffers)
+  ///   .option(sql::ExplainOption::Verbose)
+  ///   .explain(sql::Select::new().select("*").from("orders"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "EXPLAIN (ANALYZE, BUFFERS, VERBOSE) SELECT * FROM orders");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn option(mut self, option: ExplainOption) -> Self {
+    push_unique(&mut self._option, option);
+    self
+  }
+
+  /// Prints the current state of the [Explain] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds the `QUERY PLAN` option of the SQLite, this method can be used enabling the feature flag `sqlite`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Explain::new()
+  ///   .query_plan()
+  ///   .explain(sql::Select::new().select("*").from("orders"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "EXPLAIN QUERY PLAN SELECT * FROM orders");
+  /// ```
+  #[cfg(any(doc, feature = "sqlite"))]
+  pub fn query_plan(mut self) -> Self {
+    self._query_plan = true;
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* the slow query */";
+  /// let explain = sql::Explain::new()
+  ///   .raw(raw_query)
+  ///   .explain(sql::Select::new().select("*").from("orders"))
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* the slow query */ EXPLAIN SELECT * FROM orders
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* the slow query */";
+  /// let explain = sql::Explain::new()
+  ///   .explain(sql::Select::new().select("*").from("orders"))
+  ///   .raw_after(sql::ExplainClause::Explain, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN /* the slow query */ SELECT * FROM orders
+  /// ```
+  pub fn raw_after(mut self, clause: ExplainClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "explain analyze";
+  /// let explain = sql::Explain::new()
+  ///   .raw_before(sql::ExplainClause::Statement, raw)
+  ///   .explain(sql::Select::new().select("*").from("orders"))
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// EXPLAIN explain analyze SELECT * FROM orders
+  /// ```
+  pub fn raw_before(mut self, clause: ExplainClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+}
+
+impl std::fmt::Display for Explain {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Explain {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
@@ -0,0 +1,191 @@
+#[cfg(any(feature = "postgresql", feature = "mysql"))]
+use crate::structure::ExplainFormat;
+#[cfg(feature = "postgresql")]
+use crate::structure::ExplainOption;
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt,
+  structure::{Dialect, Error, Explain, ExplainClause},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, ExplainClause> for Explain {}
+
+impl Concat for Explain {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_explain(query, fmts);
+    query = self.concat_statement(query, fmts);
+
+    query.trim_end().to_owned()
+  }
+
+  fn params(&self) -> Vec<Value> {
+    self._explain.iter().flat_map(|query| query.params()).collect()
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(dialect, "EXPLAIN", self._explain.is_some(), &[Postgres, Sqlite, MySql])?;
+    check(dialect, "ANALYZE", self._analyze, &[Postgres, MySql])?;
+    #[cfg(feature = "postgresql")]
+    {
+      check(
+        dialect,
+        "EXPLAIN OPTIONS",
+        self._option.is_empty() == false,
+        &[Postgres],
+      )?;
+    }
+    #[cfg(feature = "sqlite")]
+    check(dialect, "QUERY PLAN", self._query_plan, &[Sqlite])?;
+    #[cfg(any(feature = "postgresql", feature = "mysql"))]
+    if let Some(format) = &self._format {
+      check(dialect, format_name(format), true, format_dialects(format))?;
+    }
+    if let Some(query) = &self._explain {
+      query.validate(dialect)?;
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "mysql"))]
+fn format_dialects(format: &ExplainFormat) -> &'static [Dialect] {
+  use Dialect::*;
+
+  match format {
+    ExplainFormat::Json => &[Postgres, MySql],
+    ExplainFormat::Text | ExplainFormat::Xml | ExplainFormat::Yaml => &[Postgres],
+    ExplainFormat::Traditional | ExplainFormat::Tree => &[MySql],
+  }
+}
+
+#[cfg(any(feature = "postgresql", feature = "mysql"))]
+fn format_name(format: &ExplainFormat) -> &'static str {
+  match format {
+    ExplainFormat::Json => "FORMAT JSON",
+    ExplainFormat::Text => "FORMAT TEXT",
+    ExplainFormat::Traditional => "FORMAT TRADITIONAL",
+    ExplainFormat::Tree => "FORMAT TREE",
+    ExplainFormat::Xml => "FORMAT XML",
+    ExplainFormat::Yaml => "FORMAT YAML",
+  }
+}
+
+impl Explain {
+  /// The format is rendered in the options list of the PostgreSQL and as `FORMAT=X` in the MySQL,
+  /// without dialect the enabled feature flags and the options in use decide the syntax
+  #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  fn is_format_in_options(&self, dialect: Option<Dialect>) -> bool {
+    match dialect {
+      Some(dialect) => dialect != Dialect::MySql,
+      #[cfg(all(feature = "postgresql", feature = "mysql"))]
+      None => {
+        self._option.is_empty() == false
+          || matches!(
+            self._format,
+            Some(ExplainFormat::Text | ExplainFormat::Xml | ExplainFormat::Yaml)
+          )
+      }
+      #[cfg(not(feature = "mysql"))]
+      None => true,
+      #[cfg(not(feature = "postgresql"))]
+      None => false,
+    }
+  }
+
+  fn concat_explain(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._explain.is_some() {
+      let fmt::Formatter { comma, .. } = fmts;
+      #[cfg(feature = "postgresql")]
+      let mut options = self
+        ._option
+        .iter()
+        .map(|option| match option {
+          ExplainOption::Analyze => "ANALYZE".to_owned(),
+          ExplainOption::Buffers => "BUFFERS".to_owned(),
+          ExplainOption::Costs(true) => "COSTS".to_owned(),
+          ExplainOption::Costs(false) => format!("COSTS{space}FALSE"),
+          ExplainOption::Settings => "SETTINGS".to_owned(),
+          ExplainOption::Summary => "SUMMARY".to_owned(),
+          ExplainOption::Timing(true) => "TIMING".to_owned(),
+          ExplainOption::Timing(false) => format!("TIMING{space}FALSE"),
+          ExplainOption::Verbose => "VERBOSE".to_owned(),
+          ExplainOption::Wal => "WAL".to_owned(),
+        })
+        .collect::<Vec<_>>();
+      #[cfg(not(feature = "postgresql"))]
+      let mut options: Vec<String> = vec![];
+      #[cfg(any(feature = "postgresql", feature = "mysql"))]
+      let format = match &self._format {
+        Some(format) if self.is_format_in_options(fmts.dialect) => {
+          options.push(format_name(format).replace(' ', space));
+          "".to_owned()
+        }
+        Some(format) => format!("{space}{}", format_name(format).replace(' ', "=")),
+        None => "".to_owned(),
+      };
+      #[cfg(not(any(feature = "postgresql", feature = "mysql")))]
+      let format = "";
+      let analyze = if self._analyze && options.is_empty() {
+        format!("{space}ANALYZE")
+      } else {
+        "".to_owned()
+      };
+      if self._analyze && options.is_empty() == false && options.iter().any(|option| option == "ANALYZE") == false {
+        options.insert(0, "ANALYZE".to_owned());
+      }
+      let options = if options.is_empty() == false {
+        format!("{space}({})", options.join(comma))
+      } else {
+        "".to_owned()
+      };
+      #[cfg(feature = "sqlite")]
+      let query_plan = if self._query_plan {
+        format!("{space}QUERY PLAN")
+      } else {
+        "".to_owned()
+      };
+      #[cfg(not(feature = "sqlite"))]
+      let query_plan = "";
+      format!("EXPLAIN{analyze}{options}{format}{query_plan}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      ExplainClause::Explain,
+      sql,
+    )
+  }
+
+  fn concat_statement(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if let Some(statement) = &self._explain {
+      let statement = statement.concat(fmts);
+      format!("{statement}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      ExplainClause::Statement,
+      sql,
+    )
+  }
+}
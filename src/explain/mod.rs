@@ -0,0 +1,2 @@
+mod explain;
+mod explain_internal;
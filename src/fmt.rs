@@ -1,6 +1,9 @@
+use crate::structure::Dialect;
+
 pub struct Formatter<'a> {
   pub comma: &'a str,
-  pub hr: &'a str, // horizontal rule
+  pub dialect: Option<Dialect>, // the dialect the query is rendered for, set by the as_string_for methods
+  pub hr: &'a str,              // horizontal rule
   pub indent: &'a str,
   pub lb: &'a str, // line break
   pub space: &'a str,
@@ -9,6 +12,7 @@ pub struct Formatter<'a> {
 pub fn one_line<'a>() -> Formatter<'a> {
   Formatter {
     comma: ", ",
+    dialect: None,
     hr: "",
     indent: "",
     lb: "",
@@ -16,9 +20,17 @@ pub fn one_line<'a>() -> Formatter<'a> {
   }
 }
 
+pub fn one_line_for<'a>(dialect: Dialect) -> Formatter<'a> {
+  Formatter {
+    dialect: Some(dialect),
+    ..one_line()
+  }
+}
+
 pub fn multiline<'a>() -> Formatter<'a> {
   Formatter {
     comma: ", ",
+    dialect: None,
     hr: "-- ------------------------------------------------------------------------------\x1b[0m",
     indent: "  ",
     lb: "\n",
@@ -26,12 +38,20 @@ pub fn multiline<'a>() -> Formatter<'a> {
   }
 }
 
+type SyntaxColor<'a> = (fn(&str) -> String, &'a str, &'a str);
+
 pub fn colorize(query: String) -> String {
-  let sql_syntax: [(fn(&str) -> String, &str, &str); 46] = [
+  let sql_syntax: [SyntaxColor; 56] = [
+    (blue, "ALTER ", "alter "),
     (blue, "AND ", "and "),
+    (blue, "COPY ", "copy "),
+    (blue, "CREATE ", "create "),
     (blue, "CROSS ", "cross "),
     (blue, "DELETE ", "delete "),
+    (blue, "DROP ", "drop "),
     (blue, "EXCEPT ", "except "),
+    (blue, "EXPLAIN ", "explain "),
+    (blue, "FOR ", "for "),
     (blue, "FROM ", "from "),
     (blue, "FULL ", "full "),
     (blue, "GROUP ", "group "),
@@ -43,16 +63,20 @@ pub fn colorize(query: String) -> String {
     (blue, "JOIN ", "join "),
     (blue, "LEFT ", "left "),
     (blue, "LIMIT ", "limit "),
+    (blue, "MERGE ", "merge "),
     (blue, "OFFSET ", "offset "),
     (blue, "ORDER ", "order "),
     (blue, "OVERRIDING ", "overriding "),
+    (blue, "REFRESH ", "refresh "),
     (blue, "RETURNING ", "returning "),
     (blue, "RIGHT ", "right "),
     (blue, "SELECT ", "select "),
     (blue, "SET ", "set "),
+    (blue, "TRUNCATE ", "truncate "),
     (blue, "UNION ", "union "),
     (blue, "UPDATE ", "update "),
     (blue, "VALUES ", "values "),
+    (blue, "WHEN ", "when "),
     (blue, "WHERE ", "where "),
     (blue, "WITH ", "with "),
     (blue, " ALL", " all"),
@@ -93,8 +117,7 @@ pub fn colorize(query: String) -> String {
 
 pub fn format(query: String, fmts: &Formatter) -> String {
   let template = format!("{0}{1}{0}{query}{0}{1}{0}", fmts.lb, fmts.hr);
-  let template = colorize(template);
-  template
+  colorize(template)
 }
 
 fn blue(text: &str) -> String {
@@ -1,8 +1,12 @@
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+use crate::structure::Cte;
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
-  structure::{Insert, InsertClause, Select},
+  behavior::{bind, push_unique, trim, Concat, ExplainQuery, TransactionQuery, WithQuery},
+  fmt, placeholder,
+  structure::{ConflictAction, ConflictTarget, Dialect, Error, Insert, InsertClause, Placeholder, Select},
+  value::Value,
 };
+use std::{borrow::Cow, collections::HashMap};
 
 impl<'a> Insert<'a> {
   /// Gets the current state of the Insert and returns it as string
@@ -26,6 +30,137 @@ impl<'a> Insert<'a> {
     self.concat(&fmts)
   }
 
+  /// Gets the current state of the Insert and returns it as string together with the bound values,
+  /// the value at index `n` of the list is the value of the placeholder `$n+1`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values_bind("($1)", [sql::Value::from("foo")])
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "INSERT INTO users (login) VALUES ($1)");
+  /// assert_eq!(params, vec![sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query(&self) -> (String, Vec<Value>) {
+    (self.as_string(), self.params())
+  }
+
+  /// The same as [as_query](Insert::as_query) method rendering the placeholders in the placeholder style,
+  /// using [Placeholder::QuestionMark] the values are repeated and reordered to follow the placeholders.
+  /// A positional placeholder without bound value is returned as [Error::UnboundPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_bind("($1, $2)", ["foo".into(), "Foo".into()])
+  ///   .as_query_with(sql::Placeholder::QuestionMark)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "INSERT INTO users (login, name) VALUES (?, ?)");
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from("Foo")]);
+  /// ```
+  pub fn as_query_with(&self, style: Placeholder) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(self.as_query(), &style)
+  }
+
+  /// The same as [as_query_with](Insert::as_query_with) method resolving the named placeholders like `:login`,
+  /// each name is replaced by a positional placeholder numbered after the bound values
+  /// in the order they appear, a repeated name reuses the same position. A name not found in the map is returned
+  /// as [Error::UnknownPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use std::collections::HashMap;
+  /// use sql_query_builder as sql;
+  ///
+  /// let named = HashMap::from([("login", sql::Value::from("foo")), ("tenant", sql::Value::from(42))]);
+  /// let (query, params) = sql::Insert::new()
+  ///   .insert_into("users (login, tenant_id)")
+  ///   .values("(:login, :tenant)")
+  ///   .as_query_named(&named, sql::Placeholder::Dollar)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "INSERT INTO users (login, tenant_id) VALUES ($1, $2)");
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_named(
+    &self,
+    named: &HashMap<&str, Value>,
+    style: Placeholder,
+  ) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(placeholder::resolve_named(self.as_query(), named)?, &style)
+  }
+
+  /// The same as [as_query](Insert::as_query) method checking the clauses in use against the dialect like
+  /// [as_string_for](Insert::as_string_for), the placeholders are rendered in the style of the dialect, `$1` for Postgres,
+  /// `?` for SQLite and MySQL and `@p1` for SQL Server, and the values follow the placeholders
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_bind("($1, $2)", ["foo".into(), "Foo".into()])
+  ///   .as_query_for(sql::Dialect::MySql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "INSERT INTO users (login, name) VALUES (?, ?)");
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from("Foo")]);
+  /// ```
+  pub fn as_query_for(&self, dialect: Dialect) -> Result<(String, Vec<Value>), Error> {
+    crate::dialect::render_query(self, dialect)
+  }
+
+  /// The same as [as_string](Insert::as_string) method checking the clauses in use, the builder and the nested builders,
+  /// against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause].
+  /// The placeholders are rendered in the style of the dialect, see [as_query_for](Insert::as_query_for)
+  /// The dialect specific clauses are still enabled by the feature flags, so one binary can target many dialects
+  /// enabling all the feature flags it needs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let insert = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')")
+  ///   .on_conflict("do nothing");
+  ///
+  /// assert_eq!(
+  ///   insert.as_string_for(sql::Dialect::Sqlite),
+  ///   Ok("INSERT INTO users (login) VALUES ('foo') ON CONFLICT do nothing".to_owned())
+  /// );
+  /// assert!(insert.as_string_for(sql::Dialect::MySql).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The same as [as_string](Insert::as_string) method rendering the placeholders in the placeholder style
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("($1)")
+  ///   .as_string_with(sql::Placeholder::At);
+  ///
+  /// assert_eq!(query, "INSERT INTO users (login) VALUES (@p1)");
+  /// ```
+  pub fn as_string_with(&self, style: Placeholder) -> String {
+    placeholder::convert(&self.as_string(), &style)
+  }
+
   /// Prints the current state of the Insert into console output in a more ease to read version.
   /// This method is useful to debug complex queries or just to print the generated SQL while you type
   ///
@@ -53,7 +188,118 @@ impl<'a> Insert<'a> {
     self
   }
 
-  /// The insert into clause. This method overrides the previous value
+  /// The `DO NOTHING` action of the conflict clause, the conflicting rows are skipped.
+  /// This method overrides the [do_update_set](Insert::do_update_set) action
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .on_conflict_column("login")
+  ///   .do_nothing()
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "INSERT INTO users (login, name) VALUES ('foo', 'Foo') ON CONFLICT (login) DO NOTHING"
+  /// );
+  /// ```
+  pub fn do_nothing(mut self) -> Self {
+    self._on_conflict = "";
+    self._on_conflict_action = Some(ConflictAction::DoNothing);
+    self
+  }
+
+  /// Updates all the columns listed in the insert into clause with the values proposed for insertion,
+  /// the `excluded` row, the columns of the conflict target are skipped. The assignments are rendered
+  /// before the ones of the [do_update_set](Insert::do_update_set) method, a column assigned by that method
+  /// is not assigned again from the `excluded` row. A `DO UPDATE` action without
+  /// assignments is returned as an error by the [as_string_for](Insert::as_string_for) method
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name, email)")
+  ///   .values("('foo', 'Foo', 'foo@mail.com')")
+  ///   .on_conflict_column("login")
+  ///   .do_update_excluded()
+  ///   .do_update_set("updated_at = now()")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "INSERT INTO users (login, name, email) VALUES ('foo', 'Foo', 'foo@mail.com') \
+  ///   ON CONFLICT (login) DO UPDATE SET name = excluded.name, email = excluded.email, updated_at = now()"
+  /// );
+  /// ```
+  pub fn do_update_excluded(mut self) -> Self {
+    let (excluded, _, _) = self.do_update();
+    *excluded = true;
+    self
+  }
+
+  /// The `DO UPDATE SET` action of the conflict clause, the assignments are accumulated on consecutive calls.
+  /// This method overrides the [do_nothing](Insert::do_nothing) action
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .on_conflict_column("login")
+  ///   .do_update_set("name = excluded.name")
+  ///   .do_update_set("updated_at = now()")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "INSERT INTO users (login, name) VALUES ('foo', 'Foo') \
+  ///   ON CONFLICT (login) DO UPDATE SET name = excluded.name, updated_at = now()"
+  /// );
+  /// ```
+  pub fn do_update_set(mut self, assignment: &str) -> Self {
+    let (_, set, _) = self.do_update();
+    push_unique(set, assignment.trim().to_owned());
+    self
+  }
+
+  /// The `WHERE` condition of the `DO UPDATE` action, only the conflicting rows that match the condition are updated.
+  /// The conditions are combined with the `AND` operator on consecutive calls
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .on_conflict_column("login")
+  ///   .do_update_set("name = excluded.name")
+  ///   .do_update_where("users.active = true")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "INSERT INTO users (login, name) VALUES ('foo', 'Foo') \
+  ///   ON CONFLICT (login) DO UPDATE SET name = excluded.name WHERE users.active = true"
+  /// );
+  /// ```
+  pub fn do_update_where(mut self, condition: &str) -> Self {
+    let (_, _, where_clause) = self.do_update();
+    push_unique(where_clause, condition.trim().to_owned());
+    self
+  }
+
+  /// The insert into clause. This method overrides the previous value. Only one of the insert into, insert or,
+  /// insert ignore into and replace into clauses can be used, more than one is returned as an error
+  /// by the [as_string_for](Insert::as_string_for) method
   ///
   /// # Examples
   /// ```
@@ -65,9 +311,51 @@ impl<'a> Insert<'a> {
   /// let insert = sql::Insert::new()
   ///   .insert_into("address (state, country)")
   ///   .insert_into("users (login, name)");
+  ///
+  /// let table = sql::quote_ident("Users", sql::Dialect::MySql);
+  /// let insert = sql::Insert::new()
+  ///   .insert_into(format!("{table} (login, name)"));
+  /// ```
+  pub fn insert_into(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._insert_into = trim(table_name.into());
+    self
+  }
+
+  /// The insert or clause, this method can be used enabling the feature flag `sqlite`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_or("REPLACE INTO users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "INSERT OR REPLACE INTO users (login, name) VALUES ('foo', 'Foo')");
+  /// ```
+  #[cfg(any(doc, feature = "sqlite"))]
+  pub fn insert_or(mut self, expression: impl Into<Cow<'a, str>>) -> Self {
+    self._insert_or = trim(expression.into());
+    self
+  }
+
+  /// The insert ignore into clause, this method can be used enabling the feature flag `mysql`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_ignore_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "INSERT IGNORE INTO users (login, name) VALUES ('foo', 'Foo')");
   /// ```
-  pub fn insert_into(mut self, table_name: &'a str) -> Self {
-    self._insert_into = table_name.trim();
+  #[cfg(any(doc, feature = "mysql"))]
+  pub fn insert_ignore_into(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._insert_ignore_into = trim(table_name.into());
     self
   }
 
@@ -76,9 +364,114 @@ impl<'a> Insert<'a> {
     Self::default()
   }
 
-  /// The on conflict clause. This method overrides the previous value
+  /// The on conflict clause. This method overrides the previous value and the conflict clause defined by the methods
+  /// [on_conflict_column](Insert::on_conflict_column), [do_nothing](Insert::do_nothing) and friends
   pub fn on_conflict(mut self, conflict: &'a str) -> Self {
     self._on_conflict = conflict.trim();
+    self._on_conflict_action = None;
+    self._on_conflict_target = None;
+    self
+  }
+
+  /// Adds a column to the conflict target, the columns are accumulated on consecutive calls.
+  /// This method overrides the [on_conflict_on_constraint](Insert::on_conflict_on_constraint) target.
+  /// A conflict target without action is returned as an error by the [as_string_for](Insert::as_string_for) method
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("members (tenant_id, login)")
+  ///   .values("(1, 'foo')")
+  ///   .on_conflict_column("tenant_id")
+  ///   .on_conflict_column("login")
+  ///   .do_nothing()
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "INSERT INTO members (tenant_id, login) VALUES (1, 'foo') ON CONFLICT (tenant_id, login) DO NOTHING"
+  /// );
+  /// ```
+  pub fn on_conflict_column(mut self, column_name: &str) -> Self {
+    self._on_conflict = "";
+    let column_name = column_name.trim().to_owned();
+    match &mut self._on_conflict_target {
+      Some(ConflictTarget::Columns(columns)) => push_unique(columns, column_name),
+      _ => self._on_conflict_target = Some(ConflictTarget::Columns(vec![column_name])),
+    }
+    self
+  }
+
+  /// Uses a named constraint as the conflict target. This method overrides the previous value and
+  /// the [on_conflict_column](Insert::on_conflict_column) target
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .on_conflict_on_constraint("users_login_key")
+  ///   .do_update_excluded()
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "INSERT INTO users (login, name) VALUES ('foo', 'Foo') \
+  ///   ON CONFLICT ON CONSTRAINT users_login_key DO UPDATE SET login = excluded.login, name = excluded.name"
+  /// );
+  /// ```
+  pub fn on_conflict_on_constraint(mut self, constraint_name: &str) -> Self {
+    self._on_conflict = "";
+    self._on_conflict_target = Some(ConflictTarget::Constraint(constraint_name.trim().to_owned()));
+    self
+  }
+
+  /// The on duplicate key update clause, this method can be used enabling the feature flag `mysql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .on_duplicate_key_update("name = VALUES(name)")
+  ///   .on_duplicate_key_update("updated_at = now()")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "INSERT INTO users (login, name) VALUES ('foo', 'Foo') \
+  ///   ON DUPLICATE KEY UPDATE name = VALUES(name), updated_at = now()"
+  /// );
+  /// ```
+  #[cfg(any(doc, feature = "mysql"))]
+  pub fn on_duplicate_key_update(mut self, assignment: &str) -> Self {
+    push_unique(&mut self._on_duplicate_key_update, assignment.trim().to_owned());
+    self
+  }
+
+  /// The output clause, this method can be used enabling the feature flag `mssql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .output("inserted.id")
+  ///   .values("('foo', 'Foo')")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "INSERT INTO users (login, name) OUTPUT inserted.id VALUES ('foo', 'Foo')");
+  /// ```
+  #[cfg(any(doc, feature = "mssql"))]
+  pub fn output(mut self, output_name: &str) -> Self {
+    push_unique(&mut self._output, output_name.trim().to_owned());
     self
   }
 
@@ -126,6 +519,25 @@ impl<'a> Insert<'a> {
     self
   }
 
+  /// The replace into clause, this method can be used enabling one of the feature flags `sqlite` or `mysql`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Insert::new()
+  ///   .replace_into("users (login, name)")
+  ///   .values("('foo', 'Foo')")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "REPLACE INTO users (login, name) VALUES ('foo', 'Foo')");
+  /// ```
+  #[cfg(any(doc, feature = "sqlite", feature = "mysql"))]
+  pub fn replace_into(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._replace_into = trim(table_name.into());
+    self
+  }
+
   /// Adds at the beginning a raw SQL query.
   ///
   /// # Examples
@@ -198,8 +610,8 @@ impl<'a> Insert<'a> {
     self
   }
 
-  /// The returning clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(any(doc, feature = "postgresql"))]
+  /// The returning clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
   pub fn returning(mut self, output_name: &str) -> Self {
     push_unique(&mut self._returning, output_name.trim().to_owned());
     self
@@ -211,7 +623,28 @@ impl<'a> Insert<'a> {
     self
   }
 
-  /// The with clause, this method can be used enabling the feature flag `postgresql`
+  /// The values clause with bound values, the placeholders `$1`, `$2`, ... of the expression refer to
+  /// the values in the same order and are renumbered after the values already bound
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Insert::new()
+  ///   .insert_into("users (login, name)")
+  ///   .values_bind("($1, $2)", [sql::Value::from("foo"), sql::Value::from("Foo")])
+  ///   .values_bind("($1, $2)", [sql::Value::from("bar"), sql::Value::from("Bar")])
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "INSERT INTO users (login, name) VALUES ($1, $2), ($3, $4)");
+  /// ```
+  pub fn values_bind(mut self, expression: &str, values: impl IntoIterator<Item = Value>) -> Self {
+    let expression = bind(&mut self._params, expression, values);
+    push_unique(&mut self._values, expression);
+    self
+  }
+
+  /// The with clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`
   ///
   /// # Examples
   /// ```
@@ -237,13 +670,17 @@ impl<'a> Insert<'a> {
   /// SELECT *
   /// FROM active_users
   /// ```
-  #[cfg(any(doc, feature = "postgresql"))]
-  pub fn with(mut self, name: &'a str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim(), std::sync::Arc::new(query)));
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+  pub fn with(mut self, name: impl Into<Cte<'a>>, query: impl WithQuery + 'static) -> Self {
+    self._with.push((name.into(), std::sync::Arc::new(query)));
     self
   }
 }
 
+impl ExplainQuery for Insert<'_> {}
+
+impl TransactionQuery for Insert<'_> {}
+
 impl WithQuery for Insert<'_> {}
 
 impl std::fmt::Display for Insert<'_> {
@@ -1,7 +1,9 @@
 use crate::{
   behavior::{concat_raw_before_after, Concat, ConcatMethods},
-  fmt,
-  structure::{Insert, InsertClause},
+  dialect::check,
+  fmt, placeholder,
+  structure::{ConflictAction, ConflictTarget, Dialect, Error, Insert, InsertClause},
+  value::Value,
 };
 
 impl<'a> ConcatMethods<'a, InsertClause> for Insert<'_> {}
@@ -10,52 +12,253 @@ impl Concat for Insert<'_> {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_owned();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    #[cfg(feature = "postgresql")]
+    query = self.concat_raw(query, fmts, &self._raw);
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_with(
         &self._raw_before,
         &self._raw_after,
         query,
-        &fmts,
+        fmts,
         InsertClause::With,
         &self._with,
       );
     }
-    query = self.concat_insert_into(query, &fmts);
-    query = self.concat_overriding(query, &fmts);
+    query = self.concat_insert_into(query, fmts);
+    #[cfg(feature = "sqlite")]
+    {
+      query = self.concat_insert_or(query, fmts);
+    }
+    #[cfg(feature = "mysql")]
+    {
+      query = self.concat_insert_ignore_into(query, fmts);
+    }
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    {
+      query = self.concat_replace_into(query, fmts);
+    }
+    query = self.concat_overriding(query, fmts);
+    #[cfg(feature = "mssql")]
+    {
+      query = self.concat_output(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        InsertClause::Output,
+        &self._output,
+      );
+    }
     query = self.concat_values(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       InsertClause::Values,
       &self._values,
     );
-    query = self.concat_select(query, &fmts);
-    query = self.concat_on_conflict(query, &fmts);
+    query = self.concat_select(query, fmts);
+    query = self.concat_on_conflict(query, fmts);
+    #[cfg(feature = "mysql")]
+    {
+      query = self.concat_on_duplicate_key_update(query, fmts);
+    }
 
-    #[cfg(feature = "postgresql")]
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_returning(
         &self._raw_before,
         &self._raw_after,
         query,
-        &fmts,
+        fmts,
         InsertClause::Returning,
         &self._returning,
       );
     }
 
-    query.trim_end().to_owned()
+    placeholder::resolve_nested(query.trim_end())
+  }
+
+  fn params(&self) -> Vec<Value> {
+    let params = self._params.iter().cloned();
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    let params = params.chain(self._with.iter().flat_map(|(_, query)| query.params()));
+    let params = params.chain(self._select.iter().flat_map(|select| select.params()));
+    params.collect()
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use Dialect::*;
+
+    check(
+      dialect,
+      "ON CONFLICT",
+      self._on_conflict.is_empty() == false || self._on_conflict_target.is_some() || self._on_conflict_action.is_some(),
+      &[Postgres, Sqlite],
+    )?;
+    check(
+      dialect,
+      "ON CONSTRAINT",
+      matches!(self._on_conflict_target, Some(ConflictTarget::Constraint(_))),
+      &[Postgres],
+    )?;
+    check(
+      dialect,
+      "ON CONFLICT WITHOUT ACTION",
+      self._on_conflict_target.is_some() && self._on_conflict_action.is_none(),
+      &[],
+    )?;
+    if matches!(self._on_conflict_action, Some(ConflictAction::DoUpdate { .. })) {
+      check(
+        dialect,
+        "DO UPDATE WITHOUT CONFLICT TARGET",
+        self._on_conflict_target.is_none(),
+        &[Sqlite],
+      )?;
+      check(
+        dialect,
+        "DO UPDATE WITHOUT ASSIGNMENTS",
+        self.do_update_assignments().is_empty(),
+        &[],
+      )?;
+    }
+    check(dialect, "OVERRIDING", self._overriding.is_empty() == false, &[Postgres])?;
+    let entry_clauses = usize::from(self._insert_into.is_empty() == false);
+    #[cfg(feature = "sqlite")]
+    let entry_clauses = entry_clauses + usize::from(self._insert_or.is_empty() == false);
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    let entry_clauses = entry_clauses + usize::from(self._replace_into.is_empty() == false);
+    #[cfg(feature = "mysql")]
+    let entry_clauses = entry_clauses + usize::from(self._insert_ignore_into.is_empty() == false);
+    check(dialect, "MULTIPLE INSERT CLAUSES", entry_clauses > 1, &[])?;
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      check(
+        dialect,
+        "RETURNING",
+        self._returning.is_empty() == false,
+        &[Postgres, Sqlite],
+      )?;
+      check(
+        dialect,
+        "WITH",
+        self._with.is_empty() == false,
+        &[Postgres, Sqlite, MsSql],
+      )?;
+      for (cte, query) in self._with.iter() {
+        cte.validate(dialect)?;
+        query.validate(dialect)?;
+      }
+    }
+    #[cfg(feature = "sqlite")]
+    {
+      check(dialect, "INSERT OR", self._insert_or.is_empty() == false, &[Sqlite])?;
+    }
+    #[cfg(any(feature = "sqlite", feature = "mysql"))]
+    {
+      check(
+        dialect,
+        "REPLACE INTO",
+        self._replace_into.is_empty() == false,
+        &[Sqlite, MySql],
+      )?;
+    }
+    #[cfg(feature = "mysql")]
+    {
+      check(
+        dialect,
+        "INSERT IGNORE INTO",
+        self._insert_ignore_into.is_empty() == false,
+        &[MySql],
+      )?;
+      check(
+        dialect,
+        "ON DUPLICATE KEY UPDATE",
+        self._on_duplicate_key_update.is_empty() == false,
+        &[MySql],
+      )?;
+    }
+    #[cfg(feature = "mssql")]
+    {
+      check(dialect, "OUTPUT", self._output.is_empty() == false, &[MsSql])?;
+    }
+    if let Some(select) = &self._select {
+      select.validate(dialect)?;
+    }
+
+    Ok(())
   }
 }
 
 impl Insert<'_> {
+  /// Ensures the conflict action is a `DO UPDATE` and returns its parts, the structured
+  /// conflict clause overrides the raw one of the `on_conflict` method
+  pub(crate) fn do_update(&mut self) -> (&mut bool, &mut Vec<String>, &mut Vec<String>) {
+    self._on_conflict = "";
+    if matches!(self._on_conflict_action, Some(ConflictAction::DoUpdate { .. })) == false {
+      self._on_conflict_action = Some(ConflictAction::DoUpdate {
+        excluded: false,
+        set: vec![],
+        where_clause: vec![],
+      });
+    }
+    match self._on_conflict_action.as_mut() {
+      Some(ConflictAction::DoUpdate {
+        excluded,
+        set,
+        where_clause,
+      }) => (excluded, set, where_clause),
+      _ => unreachable!(),
+    }
+  }
+
+  /// The assignments of the `DO UPDATE` action, the excluded ones first
+  fn do_update_assignments(&self) -> Vec<String> {
+    match &self._on_conflict_action {
+      Some(ConflictAction::DoUpdate { excluded, set, .. }) => {
+        let mut assignments = if *excluded {
+          self
+            .excluded_assignments()
+            .into_iter()
+            .filter(|excluded| set.iter().any(|set| assigned_column(set) == assigned_column(excluded)) == false)
+            .collect()
+        } else {
+          vec![]
+        };
+        assignments.extend(set.iter().cloned());
+        assignments
+      }
+      _ => vec![],
+    }
+  }
+
+  /// The `column = excluded.column` assignments of the columns listed in the insert into clause,
+  /// the columns of the conflict target are skipped
+  fn excluded_assignments(&self) -> Vec<String> {
+    let insert_into = self._insert_into.as_ref();
+    let columns = match (insert_into.find('('), insert_into.rfind(')')) {
+      (Some(start), Some(end)) if start < end => &insert_into[start + 1..end],
+      _ => return vec![],
+    };
+    let key_columns = match &self._on_conflict_target {
+      Some(ConflictTarget::Columns(key_columns)) => key_columns.as_slice(),
+      _ => &[],
+    };
+
+    columns
+      .split(',')
+      .map(|column| column.trim())
+      .filter(|column| {
+        column.is_empty() == false && key_columns.iter().any(|key| unquoted(key) == unquoted(column)) == false
+      })
+      .map(|column| format!("{column} = excluded.{column}"))
+      .collect()
+  }
+
   fn concat_insert_into(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._insert_into.is_empty() == false {
-      let insert_into = self._insert_into;
+      let insert_into = &self._insert_into;
       format!("INSERT INTO{space}{insert_into}{space}{lb}")
     } else {
       "".to_owned()
@@ -71,6 +274,66 @@ impl Insert<'_> {
     )
   }
 
+  #[cfg(feature = "mysql")]
+  fn concat_insert_ignore_into(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._insert_ignore_into.is_empty() == false {
+      let insert_ignore_into = &self._insert_ignore_into;
+      format!("INSERT IGNORE INTO{space}{insert_ignore_into}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::InsertIgnoreInto,
+      sql,
+    )
+  }
+
+  #[cfg(feature = "sqlite")]
+  fn concat_insert_or(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._insert_or.is_empty() == false {
+      let insert_or = &self._insert_or;
+      format!("INSERT OR{space}{insert_or}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::InsertOr,
+      sql,
+    )
+  }
+
+  #[cfg(any(feature = "sqlite", feature = "mysql"))]
+  fn concat_replace_into(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._replace_into.is_empty() == false {
+      let replace_into = &self._replace_into;
+      format!("REPLACE INTO{space}{replace_into}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::ReplaceInto,
+      sql,
+    )
+  }
+
   fn concat_overriding(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._overriding.is_empty() == false {
@@ -91,10 +354,31 @@ impl Insert<'_> {
   }
 
   fn concat_on_conflict(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
     let sql = if self._on_conflict.is_empty() == false {
       let overriding = self._on_conflict;
       format!("ON CONFLICT{space}{overriding}{space}{lb}")
+    } else if self._on_conflict_target.is_some() || self._on_conflict_action.is_some() {
+      let target = match &self._on_conflict_target {
+        Some(ConflictTarget::Columns(columns)) => format!("{space}({})", columns.join(comma)),
+        Some(ConflictTarget::Constraint(name)) => format!("{space}ON CONSTRAINT{space}{name}"),
+        None => "".to_owned(),
+      };
+      let action = match &self._on_conflict_action {
+        Some(ConflictAction::DoNothing) => format!("{space}DO NOTHING"),
+        Some(ConflictAction::DoUpdate { where_clause, .. }) => {
+          let assignments = self.do_update_assignments().join(comma);
+          let conditions = if where_clause.is_empty() == false {
+            let conditions = where_clause.join(&format!("{space}AND{space}"));
+            format!("{space}WHERE{space}{conditions}")
+          } else {
+            "".to_owned()
+          };
+          format!("{space}DO UPDATE SET{space}{assignments}{conditions}")
+        }
+        None => "".to_owned(),
+      };
+      format!("ON CONFLICT{target}{action}{space}{lb}")
     } else {
       "".to_owned()
     };
@@ -109,10 +393,30 @@ impl Insert<'_> {
     )
   }
 
+  #[cfg(feature = "mysql")]
+  fn concat_on_duplicate_key_update(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if self._on_duplicate_key_update.is_empty() == false {
+      let assignments = self._on_duplicate_key_update.join(comma);
+      format!("ON DUPLICATE KEY UPDATE{space}{assignments}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      InsertClause::OnDuplicateKeyUpdate,
+      sql,
+    )
+  }
+
   fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if let Some(select) = &self._select {
-      let select_string = select.concat(fmts);
+      let select_string = placeholder::nested(&select.concat(fmts));
       format!("{select_string}{space}{lb}")
     } else {
       "".to_owned()
@@ -128,3 +432,18 @@ impl Insert<'_> {
     )
   }
 }
+
+/// The unquoted column of an assignment like `"name" = excluded."name"`
+fn assigned_column(assignment: &str) -> &str {
+  let column = assignment.split('=').next().unwrap_or_default();
+  unquoted(column.trim())
+}
+
+/// The identifier without the quotes of the dialects, used to compare a quoted and an unquoted name
+fn unquoted(name: &str) -> &str {
+  let quotes = [('"', '"'), ('`', '`'), ('[', ']')];
+  quotes
+    .iter()
+    .find_map(|(start, end)| name.strip_prefix(*start)?.strip_suffix(*end))
+    .unwrap_or(name)
+}
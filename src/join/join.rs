@@ -0,0 +1,112 @@
+use crate::{
+  behavior::{push_unique, Concat},
+  fmt,
+  structure::{Join, JoinKind},
+};
+
+impl Join {
+  /// Gets the current state of the [Join] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let join = sql::Join::new()
+  ///   .inner_join("orders o")
+  ///   .on("o.user_id = u.id")
+  ///   .as_string();
+  ///
+  /// assert_eq!(join, "INNER JOIN orders o ON o.user_id = u.id");
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The full join clause. This method overrides the previous join clause
+  pub fn full_join(mut self, table: &str) -> Self {
+    self._kind = Some(JoinKind::Full);
+    self._table = table.trim().to_owned();
+    self
+  }
+
+  /// The inner join clause. This method overrides the previous join clause
+  pub fn inner_join(mut self, table: &str) -> Self {
+    self._kind = Some(JoinKind::Inner);
+    self._table = table.trim().to_owned();
+    self
+  }
+
+  /// The left join clause. This method overrides the previous join clause
+  pub fn left_join(mut self, table: &str) -> Self {
+    self._kind = Some(JoinKind::Left);
+    self._table = table.trim().to_owned();
+    self
+  }
+
+  /// Create Join's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The `ON` condition of the join, the conditions are combined with the `AND` operator on consecutive calls.
+  /// The conditions are not rendered when the [using](Join::using) column list is defined.
+  /// Without one of the join type methods like [inner_join](Join::inner_join) the join is not rendered
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let join = sql::Join::new()
+  ///   .inner_join("orders o")
+  ///   .on("o.user_id = u.id")
+  ///   .on("o.status = 'paid'")
+  ///   .as_string();
+  ///
+  /// assert_eq!(join, "INNER JOIN orders o ON o.user_id = u.id AND o.status = 'paid'");
+  /// ```
+  pub fn on(mut self, condition: &str) -> Self {
+    push_unique(&mut self._on, condition.trim().to_owned());
+    self
+  }
+
+  /// The right join clause. This method overrides the previous join clause
+  pub fn right_join(mut self, table: &str) -> Self {
+    self._kind = Some(JoinKind::Right);
+    self._table = table.trim().to_owned();
+    self
+  }
+
+  /// The `USING` column list of the join, the columns are accumulated on consecutive calls.
+  /// The column list takes precedence over the [on](Join::on) conditions, a join with both is returned as an error
+  /// by the `as_string_for` methods of the builders
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let join = sql::Join::new()
+  ///   .inner_join("orders")
+  ///   .using("user_id")
+  ///   .using("tenant_id")
+  ///   .as_string();
+  ///
+  /// assert_eq!(join, "INNER JOIN orders USING (user_id, tenant_id)");
+  /// ```
+  pub fn using(mut self, column: &str) -> Self {
+    push_unique(&mut self._using, column.trim().to_owned());
+    self
+  }
+}
+
+impl std::fmt::Display for Join {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Join {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
@@ -0,0 +1,86 @@
+use crate::{
+  behavior::Concat,
+  fmt,
+  structure::{Dialect, Error, Join, JoinKind},
+  value::Value,
+};
+
+impl Concat for Join {
+  /// The join is always rendered in one line like the joins of the [Select](crate::Select) builder
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, space, .. } = fmts;
+    let Some(kind) = self._kind else {
+      return "".to_owned();
+    };
+    let mut items = vec![kind.keyword().to_owned()];
+
+    if self._table.is_empty() == false {
+      items.push(self._table.clone());
+    }
+    if self._using.is_empty() == false {
+      let columns = self._using.join(comma);
+      items.push(format!("USING{space}({columns})"));
+    } else if self._on.is_empty() == false {
+      let conditions = self._on.join(&format!("{space}AND{space}"));
+      items.push(format!("ON{space}{conditions}"));
+    }
+
+    items.join(space)
+  }
+
+  fn params(&self) -> Vec<Value> {
+    vec![]
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(
+      dialect,
+      "JOIN WITHOUT TYPE",
+      self._kind.is_none()
+        && (self._table.is_empty() == false || self._on.is_empty() == false || self._using.is_empty() == false),
+      &[],
+    )?;
+    check(
+      dialect,
+      "FULL JOIN",
+      self._kind == Some(JoinKind::Full),
+      &[Postgres, Sqlite, MsSql],
+    )?;
+    check(
+      dialect,
+      "NATURAL JOIN",
+      self._kind == Some(JoinKind::Natural),
+      &[Postgres, Sqlite, MySql],
+    )?;
+    check(
+      dialect,
+      "USING",
+      self._using.is_empty() == false,
+      &[Postgres, Sqlite, MySql],
+    )?;
+    check(
+      dialect,
+      "USING WITH ON",
+      self._using.is_empty() == false && self._on.is_empty() == false,
+      &[],
+    )?;
+
+    Ok(())
+  }
+}
+
+impl JoinKind {
+  fn keyword(&self) -> &'static str {
+    match self {
+      Self::Cross => "CROSS JOIN",
+      Self::Full => "FULL JOIN",
+      Self::Inner => "INNER JOIN",
+      Self::Left => "LEFT JOIN",
+      Self::Natural => "NATURAL JOIN",
+      Self::Right => "RIGHT JOIN",
+    }
+  }
+}
@@ -0,0 +1,2 @@
+mod join;
+mod join_internal;
@@ -1,14 +1,51 @@
 #![doc = include_str!("../README.md")]
 
+mod alter_table;
 mod behavior;
+#[cfg(feature = "postgresql")]
+mod copy;
+mod create_index;
+mod create_table;
+mod create_view;
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
+mod cte;
 mod delete;
+mod dialect;
+mod drop_index;
+mod drop_table;
+mod drop_view;
+mod explain;
 mod fmt;
 mod insert;
+mod join;
+mod merge;
+mod over;
+mod placeholder;
+mod quote;
+#[cfg(feature = "postgresql")]
+mod refresh_materialized_view;
 mod select;
 mod structure;
+mod transaction;
+mod truncate;
 mod update;
+mod value;
 mod values;
 
+pub use crate::quote::{quote_ident, quote_literal, quote_qualified};
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+pub use crate::structure::Cte;
+#[cfg(any(doc, feature = "postgresql", feature = "mysql"))]
+pub use crate::structure::ExplainFormat;
+#[cfg(any(doc, feature = "postgresql"))]
+pub use crate::structure::ExplainOption;
 pub use crate::structure::{
-  Delete, DeleteClause, Insert, InsertClause, Select, SelectClause, Update, UpdateClause, Values, ValuesClause,
+  AlterTable, AlterTableClause, CreateIndex, CreateIndexClause, CreateTable, CreateTableClause, CreateView,
+  CreateViewClause, Delete, DeleteClause, Dialect, DropIndex, DropIndexClause, DropTable, DropTableClause, DropView,
+  DropViewClause, Error, Explain, ExplainClause, Insert, InsertClause, IsolationLevel, Join, Merge, MergeClause, Over,
+  Placeholder, Select, SelectClause, Transaction, TransactionClause, Truncate, TruncateClause, Update, UpdateClause,
+  Values, ValuesClause,
 };
+#[cfg(any(doc, feature = "postgresql"))]
+pub use crate::structure::{Copy, CopyClause, CopyFormat, RefreshMaterializedView, RefreshMaterializedViewClause};
+pub use crate::value::Value;
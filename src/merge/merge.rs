@@ -0,0 +1,724 @@
+use crate::{
+  behavior::{bind, push_unique, trim, Concat, ExplainQuery, TransactionQuery, UsingQuery},
+  fmt, placeholder,
+  structure::{Dialect, Error, Merge, MergeAction, MergeClause, MergeWhen, Placeholder},
+  value::Value,
+};
+use std::{borrow::Cow, collections::HashMap};
+
+impl<'a> Merge<'a> {
+  /// Gets the current state of the [Merge] and returns it as string together with the bound values,
+  /// the value at index `n` of the list is the value of the placeholder `$n+1`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set_bind("synced_by = $1", "foo")
+  ///   .as_query();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN UPDATE SET synced_by = $1"
+  /// );
+  /// assert_eq!(params, vec![sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query(&self) -> (String, Vec<Value>) {
+    (self.as_string(), self.params())
+  }
+
+  /// The same as [as_query_with](Merge::as_query_with) method resolving the named placeholders like `:login`,
+  /// each name is replaced by a positional placeholder numbered after the bound values
+  /// in the order they appear, a repeated name reuses the same position. A name not found in the map is returned
+  /// as [Error::UnknownPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use std::collections::HashMap;
+  /// use sql_query_builder as sql;
+  ///
+  /// let named = HashMap::from([("login", sql::Value::from("foo"))]);
+  /// let (query, params) = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set("synced_by = :login")
+  ///   .as_query_named(&named, sql::Placeholder::Dollar)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN UPDATE SET synced_by = $1"
+  /// );
+  /// assert_eq!(params, vec![sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query_named(
+    &self,
+    named: &HashMap<&str, Value>,
+    style: Placeholder,
+  ) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(placeholder::resolve_named(self.as_query(), named)?, &style)
+  }
+
+  /// The same as [as_query](Merge::as_query) method rendering the placeholders in the placeholder style,
+  /// using [Placeholder::QuestionMark] the values are repeated and reordered to follow the placeholders.
+  /// A positional placeholder without bound value is returned as [Error::UnboundPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set_bind("synced_by = $1", "foo")
+  ///   .as_query_with(sql::Placeholder::At)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN UPDATE SET synced_by = @p1"
+  /// );
+  /// assert_eq!(params, vec![sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query_with(&self, style: Placeholder) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(self.as_query(), &style)
+  }
+
+  /// Gets the current state of the [Merge] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set("name = b.name")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN UPDATE SET name = b.name
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_query](Merge::as_query) method checking the clauses in use against the dialect like
+  /// [as_string_for](Merge::as_string_for), the placeholders are rendered in the style of the dialect, `$1` for Postgres,
+  /// `?` for SQLite and MySQL and `@p1` for SQL Server, and the values follow the placeholders
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set_bind("synced_by = $1", "foo")
+  ///   .as_query_for(sql::Dialect::MsSql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN UPDATE SET synced_by = @p1;"
+  /// );
+  /// assert_eq!(params, vec![sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query_for(&self, dialect: Dialect) -> Result<(String, Vec<Value>), Error> {
+    crate::dialect::render_query(self, dialect)
+  }
+
+  /// The same as [as_string](Merge::as_string) method checking the clauses in use, the builder and the nested builders,
+  /// against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause].
+  /// The placeholders are rendered in the style of the dialect, see [as_query_for](Merge::as_query_for).
+  /// The SQL Server requires the `MERGE` statement to end with a semicolon, the statement rendered for it ends with `;`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .delete();
+  ///
+  /// assert_eq!(
+  ///   merge.as_string_for(sql::Dialect::MsSql),
+  ///   Ok("MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN DELETE;".to_owned())
+  /// );
+  /// assert!(merge.as_string_for(sql::Dialect::MySql).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The same as [as_string](Merge::as_string) method rendering the placeholders in the placeholder style
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set("synced_by = $1")
+  ///   .as_string_with(sql::Placeholder::QuestionMark);
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN UPDATE SET synced_by = ?"
+  /// );
+  /// ```
+  pub fn as_string_with(&self, style: Placeholder) -> String {
+    placeholder::convert(&self.as_string(), &style)
+  }
+
+  /// Prints the current state of the [Merge] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set("name = b.name")
+  ///   .when_not_matched()
+  ///   .insert("(id, name)")
+  ///   .values("(b.id, b.name)")
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c
+  /// USING customers_bk b
+  /// ON c.id = b.id
+  /// WHEN MATCHED THEN UPDATE SET name = b.name
+  /// WHEN NOT MATCHED THEN INSERT (id, name) VALUES (b.id, b.name)
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// The `DELETE` action of the last `WHEN` clause, without a `WHEN MATCHED` as the last clause a new one is started.
+  /// The action overrides the previous action of the clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .when_matched_and("b.deleted = true")
+  ///   .delete()
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED AND b.deleted = true THEN DELETE"
+  /// );
+  /// ```
+  pub fn delete(mut self) -> Self {
+    self.last_when(true).action = Some(MergeAction::Delete);
+    self
+  }
+
+  /// The `DO NOTHING` action of the last `WHEN` clause, without a `WHEN NOT MATCHED` as the last clause a new one is started.
+  /// The action overrides the previous action of the clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .do_nothing()
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN NOT MATCHED THEN DO NOTHING"
+  /// );
+  /// ```
+  pub fn do_nothing(mut self) -> Self {
+    self.last_when(false).action = Some(MergeAction::DoNothing);
+    self
+  }
+
+  /// The columns of the `INSERT` action of the last `WHEN` clause, without a `WHEN NOT MATCHED` as the last clause
+  /// a new one is started. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .insert("(id, name)")
+  ///   .values("(b.id, b.name)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id \
+  ///   WHEN NOT MATCHED THEN INSERT (id, name) VALUES (b.id, b.name)"
+  /// );
+  /// ```
+  pub fn insert(mut self, columns: &str) -> Self {
+    let columns = columns.trim().to_owned();
+    let when = self.last_when(false);
+    match &mut when.action {
+      Some(MergeAction::Insert {
+        columns: prev_columns, ..
+      }) => *prev_columns = columns,
+      _ => {
+        when.action = Some(MergeAction::Insert {
+          columns,
+          values: vec![],
+        });
+      }
+    }
+    self
+  }
+
+  /// The merge into clause, the target table of the merge. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c");
+  ///
+  /// let merge = sql::Merge::new()
+  ///   .merge_into(sql::quote_ident("Customers", sql::Dialect::Postgres));
+  /// ```
+  pub fn merge_into(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._merge_into = trim(table_name.into());
+    self
+  }
+
+  /// Create Merge's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The on clause, the join condition between the target and the source,
+  /// the conditions of consecutive calls are joined by `AND`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .on("c.tenant_id = b.tenant_id")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id AND c.tenant_id = b.tenant_id"
+  /// );
+  /// ```
+  pub fn on(mut self, condition: &str) -> Self {
+    push_unique(&mut self._on, condition.trim().to_owned());
+    self
+  }
+
+  /// Prints the current state of the [Merge] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "merge into customers c";
+  /// let merge = sql::Merge::new()
+  ///   .raw(raw_query)
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// merge into customers c USING customers_bk b ON c.id = b.id
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "when not matched by source then delete";
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set("name = b.name")
+  ///   .raw_after(sql::MergeClause::When, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN UPDATE SET name = b.name when not matched by source then delete
+  /// ```
+  pub fn raw_after(mut self, clause: MergeClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "merge into customers c";
+  /// let merge = sql::Merge::new()
+  ///   .raw_before(sql::MergeClause::Using, raw)
+  ///   .using("customers_bk b")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// merge into customers c USING customers_bk b
+  /// ```
+  pub fn raw_before(mut self, clause: MergeClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// The set clause of the `UPDATE` action of the last `WHEN` clause, without a `WHEN MATCHED` as the last clause
+  /// a new one is started. The assignments of consecutive calls are accumulated
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set("name = b.name")
+  ///   .set("email = b.email")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id \
+  ///   WHEN MATCHED THEN UPDATE SET name = b.name, email = b.email"
+  /// );
+  /// ```
+  pub fn set(mut self, value: &str) -> Self {
+    self.push_set(value.trim().to_owned());
+    self
+  }
+
+  /// The same as [set](Merge::set) method with a bound value, the placeholder `$1` of the assignment refers to the value
+  /// and is renumbered after the values already bound
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set_bind("synced_by = $1", "foo")
+  ///   .set_bind("synced_at = $1", "2024-01-01")
+  ///   .as_query();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id \
+  ///   WHEN MATCHED THEN UPDATE SET synced_by = $1, synced_at = $2"
+  /// );
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from("2024-01-01")]);
+  /// ```
+  pub fn set_bind(mut self, assignment: &str, value: impl Into<Value>) -> Self {
+    let assignment = bind(&mut self._params, assignment, [value.into()]);
+    self.push_set(assignment);
+    self
+  }
+
+  /// The using clause, the source table of the merge. This method overrides the previous value
+  /// and the previous [using_query](Merge::using_query)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let merge = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b");
+  /// ```
+  pub fn using(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._using = trim(table_name.into());
+    self._using_query = None;
+    self
+  }
+
+  /// The using clause with a [Select](crate::Select) or a [Values](crate::Values) as the source of the merge,
+  /// the alias can have the column names like `b (id, name)`. This method overrides the previous value
+  /// and the previous [using](Merge::using)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let source = sql::Values::new()
+  ///   .values("(1, 'Foo')")
+  ///   .values("(2, 'Bar')");
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using_query("b (id, name)", source)
+  ///   .on("c.id = b.id")
+  ///   .set("name = b.name")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING (VALUES (1, 'Foo'), (2, 'Bar')) AS b (id, name) ON c.id = b.id \
+  ///   WHEN MATCHED THEN UPDATE SET name = b.name"
+  /// );
+  /// ```
+  pub fn using_query(mut self, alias: &'a str, query: impl UsingQuery + 'static) -> Self {
+    self._using_query = Some((alias.trim(), std::sync::Arc::new(query)));
+    self._using = Cow::default();
+    self
+  }
+
+  /// The values clause of the `INSERT` action of the last `WHEN` clause, without a `WHEN NOT MATCHED`
+  /// as the last clause a new one is started. The action inserts one row, more than one values is returned
+  /// as an error by the [as_string_for](Merge::as_string_for) method
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .values("(b.id, b.name)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN NOT MATCHED THEN INSERT VALUES (b.id, b.name)"
+  /// );
+  /// ```
+  pub fn values(mut self, value: &str) -> Self {
+    self.push_values(value.trim().to_owned());
+    self
+  }
+
+  /// The same as [values](Merge::values) method with bound values, the placeholders `$1`, `$2`, ... of the expression
+  /// refer to the values in the same order and are renumbered after the values already bound
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .set_bind("synced_by = $1", "foo")
+  ///   .when_not_matched()
+  ///   .insert("(id, name, synced_by)")
+  ///   .values_bind("(b.id, b.name, $1)", [sql::Value::from("foo")])
+  ///   .as_query();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id \
+  ///   WHEN MATCHED THEN UPDATE SET synced_by = $1 \
+  ///   WHEN NOT MATCHED THEN INSERT (id, name, synced_by) VALUES (b.id, b.name, $2)"
+  /// );
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from("foo")]);
+  /// ```
+  pub fn values_bind(mut self, expression: &str, values: impl IntoIterator<Item = Value>) -> Self {
+    let expression = bind(&mut self._params, expression, values);
+    self.push_values(expression);
+    self
+  }
+
+  /// Starts a `WHEN MATCHED` clause, the action is defined by the next call of [set](Merge::set)
+  /// or [delete](Merge::delete) methods, a `WHEN` clause without action is returned as an error
+  /// by the [as_string_for](Merge::as_string_for) method
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .when_matched()
+  ///   .set("name = b.name")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN UPDATE SET name = b.name"
+  /// );
+  /// ```
+  pub fn when_matched(self) -> Self {
+    self.when_matched_and("")
+  }
+
+  /// Starts a `WHEN MATCHED AND condition` clause, the action is defined by the next call of [set](Merge::set)
+  /// or [delete](Merge::delete) methods
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .when_matched_and("b.deleted = true")
+  ///   .delete()
+  ///   .when_matched()
+  ///   .set("name = b.name")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id \
+  ///   WHEN MATCHED AND b.deleted = true THEN DELETE \
+  ///   WHEN MATCHED THEN UPDATE SET name = b.name"
+  /// );
+  /// ```
+  pub fn when_matched_and(mut self, condition: &str) -> Self {
+    self._when.push(MergeWhen {
+      action: None,
+      condition: condition.trim().to_owned(),
+      matched: true,
+    });
+    self
+  }
+
+  /// Starts a `WHEN NOT MATCHED` clause, the action is defined by the next call of [insert](Merge::insert),
+  /// [values](Merge::values) or [do_nothing](Merge::do_nothing) methods
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .when_not_matched()
+  ///   .insert("(id, name)")
+  ///   .values("(b.id, b.name)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id \
+  ///   WHEN NOT MATCHED THEN INSERT (id, name) VALUES (b.id, b.name)"
+  /// );
+  /// ```
+  pub fn when_not_matched(self) -> Self {
+    self.when_not_matched_and("")
+  }
+
+  /// Starts a `WHEN NOT MATCHED AND condition` clause, the action is defined by the next call of
+  /// [insert](Merge::insert), [values](Merge::values) or [do_nothing](Merge::do_nothing) methods
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Merge::new()
+  ///   .merge_into("customers c")
+  ///   .using("customers_bk b")
+  ///   .on("c.id = b.id")
+  ///   .when_not_matched_and("b.active = true")
+  ///   .values("(b.id, b.name)")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "MERGE INTO customers c USING customers_bk b ON c.id = b.id \
+  ///   WHEN NOT MATCHED AND b.active = true THEN INSERT VALUES (b.id, b.name)"
+  /// );
+  /// ```
+  pub fn when_not_matched_and(mut self, condition: &str) -> Self {
+    self._when.push(MergeWhen {
+      action: None,
+      condition: condition.trim().to_owned(),
+      matched: false,
+    });
+    self
+  }
+}
+
+impl ExplainQuery for Merge<'_> {}
+
+impl TransactionQuery for Merge<'_> {}
+
+impl std::fmt::Display for Merge<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Merge<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
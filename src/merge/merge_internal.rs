@@ -0,0 +1,225 @@
+use crate::{
+  behavior::{concat_raw_before_after, push_unique, Concat, ConcatMethods},
+  dialect::check,
+  fmt, placeholder,
+  structure::{Dialect, Error, Merge, MergeAction, MergeClause, MergeWhen},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, MergeClause> for Merge<'_> {}
+
+impl Concat for Merge<'_> {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_merge_into(query, fmts);
+    query = self.concat_using(query, fmts);
+    query = self.concat_on(query, fmts);
+    query = self.concat_when(query, fmts);
+
+    let query = placeholder::resolve_nested(query.trim_end());
+    if fmts.dialect == Some(Dialect::MsSql) && query.is_empty() == false {
+      format!("{query};")
+    } else {
+      query
+    }
+  }
+
+  fn params(&self) -> Vec<Value> {
+    let params = self._params.iter().cloned();
+    let params = params.chain(self._using_query.iter().flat_map(|(_, query)| query.params()));
+    params.collect()
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use Dialect::*;
+
+    check(
+      dialect,
+      "MERGE",
+      self._merge_into.is_empty() == false,
+      &[Postgres, MsSql],
+    )?;
+    check(
+      dialect,
+      "DO NOTHING",
+      self
+        ._when
+        .iter()
+        .any(|when| when.action == Some(MergeAction::DoNothing)),
+      &[Postgres],
+    )?;
+    check(
+      dialect,
+      "WHEN WITHOUT ACTION",
+      self._when.iter().any(|when| when.action.is_none()),
+      &[],
+    )?;
+    check(
+      dialect,
+      "MULTIPLE VALUES",
+      self
+        ._when
+        .iter()
+        .any(|when| matches!(&when.action, Some(MergeAction::Insert { values, .. }) if values.len() > 1)),
+      &[],
+    )?;
+    if let Some((_, query)) = &self._using_query {
+      query.validate(dialect)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl Merge<'_> {
+  fn concat_merge_into(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._merge_into.is_empty() == false {
+      let table_name = &self._merge_into;
+      format!("MERGE INTO{space}{table_name}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      MergeClause::MergeInto,
+      sql,
+    )
+  }
+
+  fn concat_on(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, indent, .. } = fmts;
+    let sql = if self._on.is_empty() == false {
+      let conditions = self._on.join(&format!("{space}{lb}{indent}AND{space}"));
+      format!("ON{space}{conditions}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, MergeClause::On, sql)
+  }
+
+  fn concat_using(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter {
+      comma,
+      lb,
+      indent,
+      space,
+      ..
+    } = fmts;
+    let sql = if let Some((alias, using_query)) = &self._using_query {
+      let inner_lb = format!("{lb}{indent}");
+      let inner_fmts = fmt::Formatter {
+        comma,
+        lb: inner_lb.as_str(),
+        indent,
+        space,
+        ..*fmts
+      };
+      let query_string = placeholder::nested(&using_query.concat(&inner_fmts));
+      format!("USING{space}({lb}{indent}{query_string}{lb}){space}AS{space}{alias}{space}{lb}")
+    } else if self._using.is_empty() == false {
+      let source = &self._using;
+      format!("USING{space}{source}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      MergeClause::Using,
+      sql,
+    )
+  }
+
+  fn concat_when(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = self._when.iter().fold("".to_owned(), |acc, when| {
+      let MergeWhen {
+        action,
+        condition,
+        matched,
+      } = when;
+      let matched = if *matched {
+        "MATCHED".to_owned()
+      } else {
+        format!("NOT{space}MATCHED")
+      };
+      let condition = if condition.is_empty() == false {
+        format!("{space}AND{space}{condition}")
+      } else {
+        "".to_owned()
+      };
+      let action = match action {
+        Some(MergeAction::Delete) => format!("{space}THEN{space}DELETE"),
+        Some(MergeAction::DoNothing) => format!("{space}THEN{space}DO NOTHING"),
+        Some(MergeAction::Insert { columns, values }) => {
+          let columns = if columns.is_empty() == false {
+            format!("{space}{columns}")
+          } else {
+            "".to_owned()
+          };
+          let values = if values.is_empty() == false {
+            let values = values.join(comma);
+            format!("{space}VALUES{space}{values}")
+          } else {
+            "".to_owned()
+          };
+          format!("{space}THEN{space}INSERT{columns}{values}")
+        }
+        Some(MergeAction::Update(assignments)) => {
+          let assignments = assignments.join(comma);
+          format!("{space}THEN{space}UPDATE SET{space}{assignments}")
+        }
+        None => "".to_owned(),
+      };
+
+      format!("{acc}WHEN{space}{matched}{condition}{action}{space}{lb}")
+    });
+
+    concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, MergeClause::When, sql)
+  }
+
+  /// The last `WHEN` clause when it has the same kind, otherwise a new one is started
+  pub(crate) fn last_when(&mut self, matched: bool) -> &mut MergeWhen {
+    let is_same_kind = self._when.last().is_some_and(|when| when.matched == matched);
+    if is_same_kind == false {
+      self._when.push(MergeWhen {
+        action: None,
+        condition: "".to_owned(),
+        matched,
+      });
+    }
+    self._when.last_mut().unwrap()
+  }
+
+  pub(crate) fn push_set(&mut self, value: String) {
+    let when = self.last_when(true);
+    match &mut when.action {
+      Some(MergeAction::Update(assignments)) => push_unique(assignments, value),
+      _ => when.action = Some(MergeAction::Update(vec![value])),
+    }
+  }
+
+  pub(crate) fn push_values(&mut self, value: String) {
+    let when = self.last_when(false);
+    match &mut when.action {
+      Some(MergeAction::Insert { values, .. }) => push_unique(values, value),
+      _ => {
+        when.action = Some(MergeAction::Insert {
+          columns: "".to_owned(),
+          values: vec![value],
+        })
+      }
+    }
+  }
+}
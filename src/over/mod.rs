@@ -0,0 +1,2 @@
+mod over;
+mod over_internal;
@@ -0,0 +1,95 @@
+use crate::{
+  behavior::{push_unique, Concat},
+  fmt,
+  structure::Over,
+};
+
+impl Over {
+  /// Gets the current state of the [Over] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let over = sql::Over::new()
+  ///   .partition_by("department_id")
+  ///   .as_string();
+  ///
+  /// assert_eq!(over, "(PARTITION BY department_id)");
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The frame clause of the window, rendered after the order by clause. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let over = sql::Over::new()
+  ///   .order_by("created_at")
+  ///   .frame("ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW")
+  ///   .as_string();
+  ///
+  /// assert_eq!(over, "(ORDER BY created_at ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)");
+  /// ```
+  pub fn frame(mut self, frame: &str) -> Self {
+    self._frame = frame.trim().to_owned();
+    self
+  }
+
+  /// Create Over's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// The order by clause of the window
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let over = sql::Over::new()
+  ///   .order_by("score desc")
+  ///   .order_by("created_at")
+  ///   .as_string();
+  ///
+  /// assert_eq!(over, "(ORDER BY score desc, created_at)");
+  /// ```
+  pub fn order_by(mut self, column: &str) -> Self {
+    push_unique(&mut self._order_by, column.trim().to_owned());
+    self
+  }
+
+  /// The partition by clause of the window
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let over = sql::Over::new()
+  ///   .partition_by("tenant_id")
+  ///   .partition_by("department_id")
+  ///   .as_string();
+  ///
+  /// assert_eq!(over, "(PARTITION BY tenant_id, department_id)");
+  /// ```
+  pub fn partition_by(mut self, column: &str) -> Self {
+    push_unique(&mut self._partition_by, column.trim().to_owned());
+    self
+  }
+}
+
+impl std::fmt::Display for Over {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Over {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
@@ -0,0 +1,37 @@
+use crate::{
+  behavior::Concat,
+  fmt,
+  structure::{Dialect, Error, Over},
+  value::Value,
+};
+
+impl Concat for Over {
+  /// The window specification is always rendered in one line, it's used inside the column expressions
+  /// and the window clause of the [Select](crate::Select) builder
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, space, .. } = fmts;
+    let mut items = vec![];
+
+    if self._partition_by.is_empty() == false {
+      let columns = self._partition_by.join(comma);
+      items.push(format!("PARTITION BY{space}{columns}"));
+    }
+    if self._order_by.is_empty() == false {
+      let columns = self._order_by.join(comma);
+      items.push(format!("ORDER BY{space}{columns}"));
+    }
+    if self._frame.is_empty() == false {
+      items.push(self._frame.clone());
+    }
+
+    format!("({})", items.join(space))
+  }
+
+  fn params(&self) -> Vec<Value> {
+    vec![]
+  }
+
+  fn validate(&self, _dialect: Dialect) -> Result<(), Error> {
+    Ok(())
+  }
+}
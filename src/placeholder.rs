@@ -0,0 +1,287 @@
+use crate::{
+  structure::{Error, Placeholder},
+  value::Value,
+};
+use std::collections::HashMap;
+
+/// A piece of a SQL string, quoted literals, quoted identifiers and comments are always kept as text
+#[derive(Debug, PartialEq)]
+pub enum Token<'a> {
+  Text(&'a str),
+  Positional(usize),
+  Named(&'a str),
+}
+
+/// Splits the SQL in text, positional placeholders like `$1` and named placeholders like `:login`
+pub fn tokenize(sql: &str) -> Vec<Token<'_>> {
+  let bytes = sql.as_bytes();
+  let mut tokens = vec![];
+  let mut text_start = 0;
+  let mut index = 0;
+
+  while index < bytes.len() {
+    let end = match bytes[index] {
+      b'\'' | b'"' | b'`' => skip_quoted(bytes, index),
+      b'[' if is_bracket_quote(bytes, index) => skip_bracketed(bytes, index),
+      b'-' if bytes.get(index + 1) == Some(&b'-') => skip_line_comment(bytes, index),
+      b'/' if bytes.get(index + 1) == Some(&b'*') => skip_block_comment(bytes, index),
+      b'$' if is_word_boundary(bytes, index) => {
+        let digits_end = skip_digits(bytes, index + 1);
+        if digits_end > index + 1 {
+          let position = sql[index + 1..digits_end].parse::<usize>().unwrap_or(0);
+          if text_start < index {
+            tokens.push(Token::Text(&sql[text_start..index]));
+          }
+          tokens.push(Token::Positional(position));
+          text_start = digits_end;
+          digits_end
+        } else {
+          skip_dollar_quoted(bytes, index)
+        }
+      }
+      b':' if is_word_boundary(bytes, index) && is_identifier_start(bytes.get(index + 1)) => {
+        let name_end = skip_identifier(bytes, index + 1);
+        if text_start < index {
+          tokens.push(Token::Text(&sql[text_start..index]));
+        }
+        tokens.push(Token::Named(&sql[index + 1..name_end]));
+        text_start = name_end;
+        name_end
+      }
+      _ => index + 1,
+    };
+    index = end;
+  }
+
+  if text_start < bytes.len() {
+    tokens.push(Token::Text(&sql[text_start..]));
+  }
+
+  tokens
+}
+
+/// The highest positional placeholder of the SQL
+pub fn max_position(sql: &str) -> usize {
+  tokenize(sql).iter().fold(0, |acc, token| match token {
+    Token::Positional(position) => acc.max(*position),
+    Token::Text(_) | Token::Named(_) => acc,
+  })
+}
+
+/// Marks the SQL of a nested builder to be renumbered by [resolve_nested]
+pub fn nested(sql: &str) -> String {
+  format!("{NESTED_START}{sql}{NESTED_END}")
+}
+
+/// Renumbers the placeholders of the nested builders past the placeholders of the enclosing builder,
+/// each nested builder in the order they appear
+pub fn resolve_nested(sql: &str) -> String {
+  if sql.contains(NESTED_START) == false {
+    return sql.to_owned();
+  }
+
+  let own_sql = sql
+    .split(NESTED_START)
+    .map(|segment| segment.split_once(NESTED_END).map_or(segment, |(_, own)| own))
+    .collect::<Vec<_>>()
+    .join(" ");
+  let mut offset = max_position(&own_sql);
+
+  sql.split(NESTED_START).fold("".to_owned(), |acc, segment| {
+    let Some((nested_sql, own)) = segment.split_once(NESTED_END) else {
+      return format!("{acc}{segment}");
+    };
+    let nested_sql_shifted = shift(nested_sql, offset);
+    offset += max_position(nested_sql);
+    format!("{acc}{nested_sql_shifted}{own}")
+  })
+}
+
+const NESTED_START: char = '\x0e';
+const NESTED_END: char = '\x0f';
+
+/// Adds the offset to all positional placeholders of the SQL
+pub fn shift(sql: &str, offset: usize) -> String {
+  if offset == 0 {
+    return sql.to_owned();
+  }
+
+  tokenize(sql).iter().fold("".to_owned(), |acc, token| match token {
+    Token::Text(text) => format!("{acc}{text}"),
+    Token::Named(name) => format!("{acc}:{name}"),
+    Token::Positional(position) => format!("{acc}${}", position + offset),
+  })
+}
+
+/// Renders the positional placeholders of the SQL in the placeholder style
+pub fn convert(sql: &str, style: &Placeholder) -> String {
+  if *style == Placeholder::Dollar {
+    return sql.to_owned();
+  }
+
+  tokenize(sql).iter().fold("".to_owned(), |acc, token| match token {
+    Token::Text(text) => format!("{acc}{text}"),
+    Token::Named(name) => format!("{acc}:{name}"),
+    Token::Positional(position) => match style {
+      Placeholder::Dollar => format!("{acc}${position}"),
+      Placeholder::QuestionMark => format!("{acc}?"),
+      Placeholder::Colon => format!("{acc}:p{position}"),
+      Placeholder::At => format!("{acc}@p{position}"),
+    },
+  })
+}
+
+/// Renders the query in the placeholder style, the values are repeated and reordered to follow
+/// the placeholders when the style has no position
+pub fn convert_query(query: (String, Vec<Value>), style: &Placeholder) -> Result<(String, Vec<Value>), Error> {
+  let (sql, params) = query;
+  check_bound(&sql, params.len())?;
+  if *style != Placeholder::QuestionMark {
+    return Ok((convert(&sql, style), params));
+  }
+
+  let params = tokenize(&sql)
+    .iter()
+    .filter_map(|token| match token {
+      Token::Positional(position) => Some(params[position - 1].clone()),
+      Token::Text(_) | Token::Named(_) => None,
+    })
+    .collect();
+
+  Ok((convert(&sql, style), params))
+}
+
+/// Returns an error for the first positional placeholder of the SQL without bound value
+pub fn check_bound(sql: &str, params_len: usize) -> Result<(), Error> {
+  let unbound = tokenize(sql).iter().find_map(|token| match token {
+    Token::Positional(position) if *position == 0 || *position > params_len => Some(*position),
+    Token::Positional(_) | Token::Text(_) | Token::Named(_) => None,
+  });
+
+  match unbound {
+    Some(position) => Err(Error::UnboundPlaceholder { position }),
+    None => Ok(()),
+  }
+}
+
+/// Replaces the named placeholders by positional placeholders numbered after the bound values,
+/// a repeated name reuses the same position
+pub fn resolve_named(query: (String, Vec<Value>), named: &HashMap<&str, Value>) -> Result<(String, Vec<Value>), Error> {
+  let (sql, mut params) = query;
+  check_bound(&sql, params.len())?;
+  let mut positions: Vec<&str> = vec![];
+  let offset = params.len();
+
+  let sql = tokenize(&sql)
+    .iter()
+    .try_fold("".to_owned(), |acc, token| match token {
+      Token::Text(text) => Ok(format!("{acc}{text}")),
+      Token::Positional(position) => Ok(format!("{acc}${position}")),
+      Token::Named(name) => {
+        let value = named
+          .get(name)
+          .ok_or_else(|| Error::UnknownPlaceholder { name: name.to_string() })?;
+        let index = positions.iter().position(|item| item == name).unwrap_or_else(|| {
+          positions.push(name);
+          params.push(value.clone());
+          positions.len() - 1
+        });
+        Ok(format!("{acc}${}", offset + index + 1))
+      }
+    })?;
+
+  Ok((sql, params))
+}
+
+fn is_word_boundary(bytes: &[u8], index: usize) -> bool {
+  index == 0 || {
+    let prev = bytes[index - 1];
+    (prev.is_ascii_alphanumeric() || prev == b'_' || prev == b'$' || prev == b':') == false
+  }
+}
+
+fn is_identifier_start(byte: Option<&u8>) -> bool {
+  byte.is_some_and(|byte| byte.is_ascii_alphabetic() || *byte == b'_')
+}
+
+fn skip_identifier(bytes: &[u8], start: usize) -> usize {
+  let mut index = start;
+  while index < bytes.len() && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'_') {
+    index += 1;
+  }
+  index
+}
+
+fn skip_digits(bytes: &[u8], start: usize) -> usize {
+  let mut index = start;
+  while index < bytes.len() && bytes[index].is_ascii_digit() {
+    index += 1;
+  }
+  index
+}
+
+fn skip_quoted(bytes: &[u8], start: usize) -> usize {
+  let quote = bytes[start];
+  let mut index = start + 1;
+  while index < bytes.len() && bytes[index] != quote {
+    index += 1;
+  }
+  (index + 1).min(bytes.len())
+}
+
+/// A `[` starting a word is a quoted identifier like `[total amount]`, right after an expression is an array subscript
+fn is_bracket_quote(bytes: &[u8], index: usize) -> bool {
+  is_word_boundary(bytes, index) && (index == 0 || matches!(bytes[index - 1], b')' | b']') == false)
+}
+
+/// Skips identifiers quoted with brackets, a doubled `]]` is an escaped closing bracket
+fn skip_bracketed(bytes: &[u8], start: usize) -> usize {
+  let mut index = start + 1;
+  while index < bytes.len() {
+    if bytes[index] == b']' {
+      if bytes.get(index + 1) != Some(&b']') {
+        return index + 1;
+      }
+      index += 1;
+    }
+    index += 1;
+  }
+  bytes.len()
+}
+
+fn skip_line_comment(bytes: &[u8], start: usize) -> usize {
+  let mut index = start + 2;
+  while index < bytes.len() && bytes[index] != b'\n' {
+    index += 1;
+  }
+  index
+}
+
+fn skip_block_comment(bytes: &[u8], start: usize) -> usize {
+  let mut index = start + 2;
+  while index + 1 < bytes.len() && (bytes[index] != b'*' || bytes[index + 1] != b'/') {
+    index += 1;
+  }
+  (index + 2).min(bytes.len())
+}
+
+/// Skips Postgres dollar-quoted strings like `$$text$$` or `$tag$text$tag$`
+fn skip_dollar_quoted(bytes: &[u8], start: usize) -> usize {
+  let mut tag_end = start + 1;
+  while tag_end < bytes.len() && (bytes[tag_end].is_ascii_alphanumeric() || bytes[tag_end] == b'_') {
+    tag_end += 1;
+  }
+  if tag_end >= bytes.len() || bytes[tag_end] != b'$' || bytes[start + 1].is_ascii_digit() {
+    return start + 1;
+  }
+
+  let tag = &bytes[start..=tag_end];
+  let mut index = tag_end + 1;
+  while index + tag.len() <= bytes.len() {
+    if &bytes[index..index + tag.len()] == tag {
+      return index + tag.len();
+    }
+    index += 1;
+  }
+  bytes.len()
+}
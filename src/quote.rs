@@ -0,0 +1,55 @@
+use crate::structure::Dialect;
+
+/// Quotes an identifier like a table or a column name using the quoting rule of the dialect,
+/// the quotes inside the name are escaped
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// assert_eq!(sql::quote_ident("user name", sql::Dialect::Postgres), r#""user name""#);
+/// assert_eq!(sql::quote_ident("user`name", sql::Dialect::MySql), "`user``name`");
+/// assert_eq!(sql::quote_ident("user]name", sql::Dialect::MsSql), "[user]]name]");
+/// ```
+pub fn quote_ident(name: &str, dialect: Dialect) -> String {
+  match dialect {
+    Dialect::Postgres | Dialect::Sqlite => format!("\"{}\"", name.replace('"', "\"\"")),
+    Dialect::MySql => format!("`{}`", name.replace('`', "``")),
+    Dialect::MsSql => format!("[{}]", name.replace(']', "]]")),
+  }
+}
+
+/// Quotes a string literal using the quoting rule of the dialect, the quotes inside the value are escaped
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// assert_eq!(sql::quote_literal("O'Neil", sql::Dialect::Postgres), "'O''Neil'");
+/// assert_eq!(sql::quote_literal(r"C:\temp", sql::Dialect::MySql), r"'C:\\temp'");
+/// ```
+pub fn quote_literal(value: &str, dialect: Dialect) -> String {
+  match dialect {
+    Dialect::Postgres | Dialect::Sqlite | Dialect::MsSql => format!("'{}'", value.replace('\'', "''")),
+    Dialect::MySql => format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''")),
+  }
+}
+
+/// Quotes each part of a qualified name like `schema.table` or `table.column` and joins them with a dot
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let table = sql::quote_qualified(&["public", "users"], sql::Dialect::Postgres);
+/// let select = sql::Select::new().select("*").from(&table);
+///
+/// assert_eq!(select.as_string(), r#"SELECT * FROM "public"."users""#);
+/// ```
+pub fn quote_qualified(names: &[&str], dialect: Dialect) -> String {
+  names
+    .iter()
+    .map(|name| quote_ident(name, dialect))
+    .collect::<Vec<_>>()
+    .join(".")
+}
@@ -0,0 +1,2 @@
+mod refresh_materialized_view;
+mod refresh_materialized_view_internal;
@@ -0,0 +1,245 @@
+use crate::{
+  behavior::{push_unique, trim, Concat, TransactionQuery},
+  fmt,
+  structure::{Dialect, Error, RefreshMaterializedView, RefreshMaterializedViewClause},
+};
+use std::borrow::Cow;
+
+impl<'a> RefreshMaterializedView<'a> {
+  /// Gets the current state of the [RefreshMaterializedView] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::RefreshMaterializedView::new()
+  ///   .refresh_materialized_view("sales_summary")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// REFRESH MATERIALIZED VIEW sales_summary
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](RefreshMaterializedView::as_string) method checking the clauses in use against the dialect.
+  /// A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let refresh = sql::RefreshMaterializedView::new()
+  ///   .refresh_materialized_view("sales_summary");
+  ///
+  /// assert_eq!(
+  ///   refresh.as_string_for(sql::Dialect::Postgres),
+  ///   Ok("REFRESH MATERIALIZED VIEW sales_summary".to_owned())
+  /// );
+  /// assert!(refresh.as_string_for(sql::Dialect::MySql).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// Adds the `CONCURRENTLY` option, the view is refreshed without locking out the concurrent selects on it
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::RefreshMaterializedView::new()
+  ///   .refresh_materialized_view("sales_summary")
+  ///   .concurrently()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "REFRESH MATERIALIZED VIEW CONCURRENTLY sales_summary");
+  /// ```
+  pub fn concurrently(mut self) -> Self {
+    self._concurrently = true;
+    self
+  }
+
+  /// Prints the current state of the [RefreshMaterializedView] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let refresh = sql::RefreshMaterializedView::new()
+  ///   .refresh_materialized_view("sales_summary")
+  ///   .with_no_data()
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// REFRESH MATERIALIZED VIEW sales_summary
+  /// WITH NO DATA
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Create RefreshMaterializedView's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [RefreshMaterializedView] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* nightly refresh */";
+  /// let refresh = sql::RefreshMaterializedView::new()
+  ///   .raw(raw_query)
+  ///   .refresh_materialized_view("sales_summary")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* nightly refresh */ REFRESH MATERIALIZED VIEW sales_summary
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* nightly refresh */";
+  /// let refresh = sql::RefreshMaterializedView::new()
+  ///   .refresh_materialized_view("sales_summary")
+  ///   .raw_after(sql::RefreshMaterializedViewClause::RefreshMaterializedView, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// REFRESH MATERIALIZED VIEW sales_summary /* nightly refresh */
+  /// ```
+  pub fn raw_after(mut self, clause: RefreshMaterializedViewClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* nightly refresh */";
+  /// let refresh = sql::RefreshMaterializedView::new()
+  ///   .raw_before(sql::RefreshMaterializedViewClause::RefreshMaterializedView, raw)
+  ///   .refresh_materialized_view("sales_summary")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* nightly refresh */ REFRESH MATERIALIZED VIEW sales_summary
+  /// ```
+  pub fn raw_before(mut self, clause: RefreshMaterializedViewClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// The refresh materialized view clause. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let refresh = sql::RefreshMaterializedView::new()
+  ///   .refresh_materialized_view("sales_summary");
+  ///
+  /// let refresh = sql::RefreshMaterializedView::new()
+  ///   .refresh_materialized_view(sql::quote_ident("SalesSummary", sql::Dialect::Postgres));
+  /// ```
+  pub fn refresh_materialized_view(mut self, view_name: impl Into<Cow<'a, str>>) -> Self {
+    self._refresh_materialized_view = trim(view_name.into());
+    self
+  }
+
+  /// Adds the `WITH DATA` option, the view is populated by the refresh.
+  /// This method overrides the [with_no_data](RefreshMaterializedView::with_no_data) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::RefreshMaterializedView::new()
+  ///   .refresh_materialized_view("sales_summary")
+  ///   .with_data()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "REFRESH MATERIALIZED VIEW sales_summary WITH DATA");
+  /// ```
+  pub fn with_data(mut self) -> Self {
+    self._with_data = Some(true);
+    self
+  }
+
+  /// Adds the `WITH NO DATA` option, the data of the view is discarded and the view can't be queried
+  /// until the next refresh. This method overrides the [with_data](RefreshMaterializedView::with_data) option.
+  /// The option combined with [concurrently](RefreshMaterializedView::concurrently) is returned as an error
+  /// by the [as_string_for](RefreshMaterializedView::as_string_for) method
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::RefreshMaterializedView::new()
+  ///   .refresh_materialized_view("sales_summary")
+  ///   .with_no_data()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "REFRESH MATERIALIZED VIEW sales_summary WITH NO DATA");
+  /// ```
+  pub fn with_no_data(mut self) -> Self {
+    self._with_data = Some(false);
+    self
+  }
+}
+
+impl TransactionQuery for RefreshMaterializedView<'_> {}
+
+impl std::fmt::Display for RefreshMaterializedView<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for RefreshMaterializedView<'_> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
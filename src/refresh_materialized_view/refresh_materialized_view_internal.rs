@@ -0,0 +1,88 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt,
+  structure::{Dialect, Error, RefreshMaterializedView, RefreshMaterializedViewClause},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, RefreshMaterializedViewClause> for RefreshMaterializedView<'_> {}
+
+impl Concat for RefreshMaterializedView<'_> {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_refresh_materialized_view(query, fmts);
+    query = self.concat_with_data(query, fmts);
+
+    query.trim_end().to_owned()
+  }
+
+  fn params(&self) -> Vec<Value> {
+    vec![]
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(
+      dialect,
+      "REFRESH MATERIALIZED VIEW",
+      self._refresh_materialized_view.is_empty() == false,
+      &[Postgres],
+    )?;
+    check(
+      dialect,
+      "CONCURRENTLY WITH NO DATA",
+      self._concurrently && self._with_data == Some(false),
+      &[],
+    )?;
+
+    Ok(())
+  }
+}
+
+impl RefreshMaterializedView<'_> {
+  fn concat_refresh_materialized_view(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._refresh_materialized_view.is_empty() == false {
+      let view_name = &self._refresh_materialized_view;
+      let concurrently = if self._concurrently {
+        format!("CONCURRENTLY{space}")
+      } else {
+        "".to_owned()
+      };
+      format!("REFRESH MATERIALIZED VIEW{space}{concurrently}{view_name}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      RefreshMaterializedViewClause::RefreshMaterializedView,
+      sql,
+    )
+  }
+
+  fn concat_with_data(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = match self._with_data {
+      Some(true) => format!("WITH DATA{space}{lb}"),
+      Some(false) => format!("WITH NO DATA{space}{lb}"),
+      None => "".to_owned(),
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      RefreshMaterializedViewClause::WithData,
+      sql,
+    )
+  }
+}
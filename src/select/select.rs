@@ -1,8 +1,14 @@
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+use crate::structure::Cte;
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
-  structure::{Select, SelectClause},
+  behavior::{bind, push_unique, Concat, ExplainQuery, TransactionQuery, UsingQuery, WithQuery},
+  fmt, placeholder,
+  structure::{
+    Dialect, Error, Join, JoinKind, LockStrength, LockWait, Over, Placeholder, Select, SelectClause, SelectJoin,
+  },
+  value::Value,
 };
+use std::collections::HashMap;
 
 impl<'a> Select<'a> {
   /// The same as [where_clause](Select::where_clause) method, useful to write more idiomatic SQL query
@@ -20,6 +26,21 @@ impl<'a> Select<'a> {
     self
   }
 
+  /// The same as [where_bind](Select::where_bind) method, useful to write more idiomatic SQL query
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .where_bind("login = $1", "foo")
+  ///   .and_bind("active = $1", true);
+  /// ```
+  pub fn and_bind(mut self, condition: &str, value: impl Into<Value>) -> Self {
+    self = self.where_bind(condition, value);
+    self
+  }
+
   /// Gets the current state of the Select returns it as string
   ///
   /// # Examples
@@ -42,6 +63,201 @@ impl<'a> Select<'a> {
     self.concat(&fmts)
   }
 
+  /// Gets the current state of the Select and returns it as string together with the bound values,
+  /// the value at index `n` of the list is the value of the placeholder `$n+1`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_bind("login = $1", "foo")
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "SELECT id FROM users WHERE login = $1");
+  /// assert_eq!(params, vec![sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query(&self) -> (String, Vec<Value>) {
+    (self.as_string(), self.params())
+  }
+
+  /// The same as [as_query](Select::as_query) method rendering the placeholders in the placeholder style,
+  /// using [Placeholder::QuestionMark] the values are repeated and reordered to follow the placeholders.
+  /// A positional placeholder without bound value is returned as [Error::UnboundPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Select::new()
+  ///   .select("status, count(*)")
+  ///   .from("orders")
+  ///   .group_by("status")
+  ///   .having_bind("count(*) > $1", 10)
+  ///   .where_bind("owner_login = $1", "foo")
+  ///   .as_query_with(sql::Placeholder::QuestionMark)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "SELECT status, count(*) FROM orders WHERE owner_login = ? GROUP BY status HAVING count(*) > ?"
+  /// );
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from(10)]);
+  /// ```
+  pub fn as_query_with(&self, style: Placeholder) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(self.as_query(), &style)
+  }
+
+  /// The same as [as_query_with](Select::as_query_with) method resolving the named placeholders like `:login`,
+  /// each name is replaced by a positional placeholder numbered after the bound values
+  /// in the order they appear, a repeated name reuses the same position. A name not found in the map is returned
+  /// as [Error::UnknownPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use std::collections::HashMap;
+  /// use sql_query_builder as sql;
+  ///
+  /// let named = HashMap::from([("login", sql::Value::from("foo")), ("tenant", sql::Value::from(42))]);
+  /// let (query, params) = sql::Select::new()
+  ///   .select("*")
+  ///   .from("orders")
+  ///   .where_clause("owner_login = :login")
+  ///   .and("approver_login <> :login")
+  ///   .and("tenant_id = :tenant")
+  ///   .as_query_named(&named, sql::Placeholder::Dollar)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "SELECT * FROM orders WHERE owner_login = $1 AND approver_login <> $1 AND tenant_id = $2");
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_named(
+    &self,
+    named: &HashMap<&str, Value>,
+    style: Placeholder,
+  ) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(placeholder::resolve_named(self.as_query(), named)?, &style)
+  }
+
+  /// The same as [as_query](Select::as_query) method checking the clauses in use against the dialect like
+  /// [as_string_for](Select::as_string_for), the placeholders are rendered in the style of the dialect, `$1` for Postgres,
+  /// `?` for SQLite and MySQL and `@p1` for SQL Server, and the values follow the placeholders
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .where_bind("login = $1", "foo")
+  ///   .as_query_for(sql::Dialect::MsSql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "SELECT * FROM users WHERE login = @p1");
+  /// assert_eq!(params, vec![sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query_for(&self, dialect: Dialect) -> Result<(String, Vec<Value>), Error> {
+    crate::dialect::render_query(self, dialect)
+  }
+
+  /// The same as [as_string](Select::as_string) method checking the clauses in use, the builder and the nested builders,
+  /// against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause].
+  /// The placeholders are rendered in the style of the dialect, see [as_query_for](Select::as_query_for)
+  /// The dialect specific clauses are still enabled by the feature flags, so one binary can target many dialects
+  /// enabling all the feature flags it needs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let select = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .limit("10");
+  ///
+  /// assert_eq!(select.as_string_for(sql::Dialect::Postgres), Ok("SELECT * FROM users LIMIT 10".to_owned()));
+  /// assert!(select.as_string_for(sql::Dialect::MsSql).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The same as [as_string](Select::as_string) method rendering the placeholders in the placeholder style
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("users")
+  ///   .where_clause("login = $1")
+  ///   .as_string_with(sql::Placeholder::At);
+  ///
+  /// assert_eq!(query, "SELECT id FROM users WHERE login = @p1");
+  /// ```
+  pub fn as_string_with(&self, style: Placeholder) -> String {
+    placeholder::convert(&self.as_string(), &style)
+  }
+
+  /// The limit clause of the compound query, the limit applies to the result of the combinators like
+  /// [union](Select::union) instead of the last select. This method overrides the previous value,
+  /// this method can be used enabling one of the feature flags `postgresql` or `sqlite`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .union_all(sql::Select::new().select("login").from("users_bk"))
+  ///   .compound_order_by("login")
+  ///   .compound_limit("10")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "(SELECT login FROM users) UNION ALL (SELECT login FROM users_bk) ORDER BY login LIMIT 10"
+  /// );
+  /// ```
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+  pub fn compound_limit(mut self, num: &'a str) -> Self {
+    self._compound_limit = num.trim();
+    self
+  }
+
+  /// The order by clause of the compound query, the order applies to the result of the combinators like
+  /// [union](Select::union) instead of the last select. The columns are accumulated on consecutive calls,
+  /// this method can be used enabling one of the feature flags `postgresql` or `sqlite`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("login, created_at")
+  ///   .from("users")
+  ///   .union(sql::Select::new().select("login, created_at").from("users_bk"))
+  ///   .compound_order_by("created_at desc")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "(SELECT login, created_at FROM users) \
+  ///   UNION (SELECT login, created_at FROM users_bk) \
+  ///   ORDER BY created_at desc"
+  /// );
+  /// ```
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+  pub fn compound_order_by(mut self, column: &str) -> Self {
+    push_unique(&mut self._compound_order_by, column.trim().to_owned());
+    self
+  }
+
   /// Prints the current state of the Select into console output in a more ease to read version.
   /// This method is useful to debug complex queries or just to print the generated SQL while you type
   ///
@@ -92,10 +308,149 @@ impl<'a> Select<'a> {
     self
   }
 
-  /// The except clause, this method can be used enabling the feature flag `postgresql`
+  /// Adds the `DISTINCT` option to the select clause, the duplicated rows are removed from the result.
+  /// The option is rendered right after the `SELECT` keyword regardless of the call order
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("country")
+  ///   .distinct()
+  ///   .select("city")
+  ///   .from("addresses")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT DISTINCT country, city FROM addresses");
+  /// ```
+  pub fn distinct(mut self) -> Self {
+    self._distinct = true;
+    self
+  }
+
+  /// Adds the `DISTINCT ON` option to the select clause, only the first row of each group of equal expressions is kept,
+  /// this method can be used enabling the feature flag `postgresql`. The expressions are accumulated on consecutive calls
+  /// and the option takes precedence over the [distinct](Select::distinct) method
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("user_id, created_at, status")
+  ///   .distinct_on("user_id")
+  ///   .from("orders")
+  ///   .order_by("user_id, created_at desc")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "SELECT DISTINCT ON (user_id) user_id, created_at, status FROM orders ORDER BY user_id, created_at desc"
+  /// );
+  /// ```
   #[cfg(any(doc, feature = "postgresql"))]
+  pub fn distinct_on(mut self, expression: &str) -> Self {
+    push_unique(&mut self._distinct_on, expression.trim().to_owned());
+    self
+  }
+
+  /// The except clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`.
+  /// The operands are wrapped in parentheses, except when rendered by `as_string_for(Dialect::Sqlite)` since SQLite doesn't accept them
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
   pub fn except(mut self, select: Self) -> Self {
-    self._except.push(select);
+    self._except.push((false, select));
+    self
+  }
+
+  /// The except clause with the `ALL` option, the duplicated rows are kept,
+  /// this method can be used enabling one of the feature flags `postgresql` or `sqlite`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .except_all(sql::Select::new().select("login").from("banned_users"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "(SELECT login FROM users) EXCEPT ALL (SELECT login FROM banned_users)");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+  pub fn except_all(mut self, select: Self) -> Self {
+    self._except.push((true, select));
+    self
+  }
+
+  /// The `FOR NO KEY UPDATE` row locking clause, a weaker lock than `FOR UPDATE` that doesn't block the inserts
+  /// referencing the locked rows. This method overrides the lock strength of [for_update](Select::for_update) and [for_share](Select::for_share)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("accounts")
+  ///   .where_clause("id = 1")
+  ///   .for_no_key_update()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT * FROM accounts WHERE id = 1 FOR NO KEY UPDATE");
+  /// ```
+  pub fn for_no_key_update(mut self) -> Self {
+    self._lock = Some(LockStrength::NoKeyUpdate);
+    self
+  }
+
+  /// The `FOR SHARE` row locking clause. This method overrides the lock strength of [for_update](Select::for_update)
+  /// and [for_no_key_update](Select::for_no_key_update)
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("accounts")
+  ///   .where_clause("id = 1")
+  ///   .for_share()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT * FROM accounts WHERE id = 1 FOR SHARE");
+  /// ```
+  pub fn for_share(mut self) -> Self {
+    self._lock = Some(LockStrength::Share);
+    self
+  }
+
+  /// The `FOR UPDATE` row locking clause, rendered after the limit and offset clauses. This method overrides
+  /// the lock strength of [for_share](Select::for_share) and [for_no_key_update](Select::for_no_key_update).
+  /// A locking clause isn't accepted together with the `UNION`, `INTERSECT` and `EXCEPT` combinators
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("jobs")
+  ///   .where_clause("status = 'pending'")
+  ///   .order_by("created_at")
+  ///   .limit("1")
+  ///   .for_update()
+  ///   .skip_locked()
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "SELECT id FROM jobs WHERE status = 'pending' ORDER BY created_at LIMIT 1 FOR UPDATE SKIP LOCKED"
+  /// );
+  /// ```
+  pub fn for_update(mut self) -> Self {
+    self._lock = Some(LockStrength::Update);
     self
   }
 
@@ -117,42 +472,190 @@ impl<'a> Select<'a> {
     self
   }
 
+  /// The having clause with a bound value, the placeholder `$1` of the condition refers to the value
+  /// and is renumbered after the values already bound
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Select::new()
+  ///   .select("status, count(*)")
+  ///   .from("orders")
+  ///   .where_bind("owner_login = $1", "foo")
+  ///   .group_by("status")
+  ///   .having_bind("count(*) > $1", 10)
+  ///   .as_query();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "SELECT status, count(*) FROM orders WHERE owner_login = $1 GROUP BY status HAVING count(*) > $2"
+  /// );
+  /// ```
+  pub fn having_bind(mut self, condition: &str, value: impl Into<Value>) -> Self {
+    let condition = bind(&mut self._params, condition, [value.into()]);
+    push_unique(&mut self._having, condition);
+    self
+  }
+
   /// The cross join clause
   pub fn cross_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    let table = format!("CROSS JOIN {table}");
-    push_unique(&mut self._join, table);
+    self.push_join(JoinKind::Cross, table);
+    self
+  }
+
+  /// The cross join lateral clause, the subquery can reference the columns of the preceding tables
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let last_order = sql::Select::new()
+  ///   .select("o.total")
+  ///   .from("orders o")
+  ///   .where_clause("o.user_id = u.id")
+  ///   .order_by("o.created_at desc")
+  ///   .limit("1");
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("u.login, lo.total")
+  ///   .from("users u")
+  ///   .cross_join_lateral("lo", last_order)
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "SELECT u.login, lo.total FROM users u \
+  ///   CROSS JOIN LATERAL (SELECT o.total FROM orders o WHERE o.user_id = u.id ORDER BY o.created_at desc LIMIT 1) lo"
+  /// );
+  /// ```
+  pub fn cross_join_lateral(mut self, alias: &str, select: Self) -> Self {
+    self._join.push(SelectJoin::Lateral {
+      alias: alias.trim().to_owned(),
+      join: "CROSS JOIN LATERAL",
+      select: Box::new(select),
+    });
+    self
+  }
+
+  /// The full join clause
+  pub fn full_join(mut self, table: &str) -> Self {
+    self.push_join(JoinKind::Full, table);
     self
   }
 
   /// The inner join clause
   pub fn inner_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    let table = format!("INNER JOIN {table}");
-    push_unique(&mut self._join, table);
+    self.push_join(JoinKind::Inner, table);
+    self
+  }
+
+  /// Adds a join defined by the [Join] builder, the join condition can be defined by the `ON` conditions or
+  /// by the `USING` column list
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .join(sql::Join::new().inner_join("orders").using("user_id"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT * FROM users INNER JOIN orders USING (user_id)");
+  /// ```
+  pub fn join(mut self, join: Join) -> Self {
+    if self
+      ._join
+      .iter()
+      .any(|item| matches!(item, SelectJoin::Join(prev) if *prev == join))
+      == false
+    {
+      self._join.push(SelectJoin::Join(join));
+    }
     self
   }
 
   /// The left join clause
   pub fn left_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    let table = format!("LEFT JOIN {table}");
-    push_unique(&mut self._join, table);
+    self.push_join(JoinKind::Left, table);
+    self
+  }
+
+  /// The left join lateral clause, the subquery can reference the columns of the preceding tables.
+  /// The join is rendered with the `ON true` condition, so the rows without a match in the subquery are kept
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let last_order = sql::Select::new()
+  ///   .select("o.total")
+  ///   .from("orders o")
+  ///   .where_clause("o.user_id = u.id")
+  ///   .order_by("o.created_at desc")
+  ///   .limit("1");
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("u.login, lo.total")
+  ///   .from("users u")
+  ///   .left_join_lateral("lo", last_order)
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "SELECT u.login, lo.total FROM users u \
+  ///   LEFT JOIN LATERAL (SELECT o.total FROM orders o WHERE o.user_id = u.id ORDER BY o.created_at desc LIMIT 1) lo ON true"
+  /// );
+  /// ```
+  pub fn left_join_lateral(mut self, alias: &str, select: Self) -> Self {
+    self._join.push(SelectJoin::Lateral {
+      alias: alias.trim().to_owned(),
+      join: "LEFT JOIN LATERAL",
+      select: Box::new(select),
+    });
+    self
+  }
+
+  /// The natural join clause, the tables are joined by the columns with the same name
+  pub fn natural_join(mut self, table: &str) -> Self {
+    self.push_join(JoinKind::Natural, table);
     self
   }
 
   /// The right join clause
   pub fn right_join(mut self, table: &str) -> Self {
-    let table = table.trim();
-    let table = format!("RIGHT JOIN {table}");
-    push_unique(&mut self._join, table);
+    self.push_join(JoinKind::Right, table);
     self
   }
 
-  /// The intersect clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(any(doc, feature = "postgresql"))]
+  /// The intersect clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`.
+  /// The operands are wrapped in parentheses, except when rendered by `as_string_for(Dialect::Sqlite)` since SQLite doesn't accept them
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
   pub fn intersect(mut self, select: Self) -> Self {
-    self._intersect.push(select);
+    self._intersect.push((false, select));
+    self
+  }
+
+  /// The intersect clause with the `ALL` option, the duplicated rows are kept,
+  /// this method can be used enabling one of the feature flags `postgresql` or `sqlite`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .intersect_all(sql::Select::new().select("login").from("admins"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "(SELECT login FROM users) INTERSECT ALL (SELECT login FROM admins)");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+  pub fn intersect_all(mut self, select: Self) -> Self {
+    self._intersect.push((true, select));
     self
   }
 
@@ -169,16 +672,74 @@ impl<'a> Select<'a> {
   ///   .limit("1000")
   ///   .limit("123");
   /// ```
+  ///
+  /// The MySQL form `LIMIT offset, count` can be written passing both numbers
+  ///
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .limit("20, 10")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT * FROM users LIMIT 20, 10");
+  /// ```
   pub fn limit(mut self, num: &'a str) -> Self {
     self._limit = num.trim();
     self
   }
 
+  /// Restricts the row locking clause to the rows of the table, the tables are accumulated on consecutive calls.
+  /// The option is rendered only with one of the lock strength methods like [for_update](Select::for_update),
+  /// without it the [as_string_for](Select::as_string_for) method returns an error
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("orders o")
+  ///   .inner_join("users u on u.id = o.user_id")
+  ///   .for_update()
+  ///   .lock_of("o")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT * FROM orders o INNER JOIN users u on u.id = o.user_id FOR UPDATE OF o");
+  /// ```
+  pub fn lock_of(mut self, table: &str) -> Self {
+    push_unique(&mut self._lock_of, table.trim().to_owned());
+    self
+  }
+
   /// Create Select's instance
   pub fn new() -> Self {
     Self::default()
   }
 
+  /// The `NOWAIT` option of the row locking clause, the query fails instead of waiting for the rows locked by
+  /// other transactions. This method overrides the [skip_locked](Select::skip_locked) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("accounts")
+  ///   .for_update()
+  ///   .nowait()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT * FROM accounts FOR UPDATE NOWAIT");
+  /// ```
+  pub fn nowait(mut self) -> Self {
+    self._lock_wait = Some(LockWait::NoWait);
+    self
+  }
+
   /// The offset clause. This method overrides the previous value
   ///
   /// # Examples
@@ -197,6 +758,29 @@ impl<'a> Select<'a> {
     self
   }
 
+  /// The fetch next clause, this method can be used enabling the feature flag `mssql`. This method overrides the previous value.
+  /// The offset clause is rendered as `OFFSET n ROWS` when the fetch next clause is in use or the query is rendered for SQL Server
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .order_by("id")
+  ///   .offset("20")
+  ///   .fetch_next("10")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT * FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY");
+  /// ```
+  #[cfg(any(doc, feature = "mssql"))]
+  pub fn fetch_next(mut self, num: &'a str) -> Self {
+    self._fetch_next = num.trim();
+    self
+  }
+
   /// The order by clause
   pub fn order_by(mut self, column: &str) -> Self {
     push_unique(&mut self._order_by, column.trim().to_owned());
@@ -295,10 +879,95 @@ impl<'a> Select<'a> {
     self
   }
 
-  /// The union clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(any(doc, feature = "postgresql"))]
+  /// The `SKIP LOCKED` option of the row locking clause, the rows locked by other transactions are skipped.
+  /// This method overrides the [nowait](Select::nowait) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("id")
+  ///   .from("jobs")
+  ///   .limit("10")
+  ///   .for_update()
+  ///   .skip_locked()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT id FROM jobs LIMIT 10 FOR UPDATE SKIP LOCKED");
+  /// ```
+  pub fn skip_locked(mut self) -> Self {
+    self._lock_wait = Some(LockWait::SkipLocked);
+    self
+  }
+
+  /// The union clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`.
+  /// The operands are wrapped in parentheses, except when rendered by `as_string_for(Dialect::Sqlite)` since SQLite doesn't accept them
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
   pub fn union(mut self, select: Self) -> Self {
-    self._union.push(select);
+    self._union.push((false, select));
+    self
+  }
+
+  /// The union clause with the `ALL` option, the duplicated rows are kept,
+  /// this method can be used enabling one of the feature flags `postgresql` or `sqlite`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("login")
+  ///   .from("users")
+  ///   .union_all(sql::Select::new().select("login").from("users_bk"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "(SELECT login FROM users) UNION ALL (SELECT login FROM users_bk)");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+  pub fn union_all(mut self, select: Self) -> Self {
+    self._union.push((true, select));
+    self
+  }
+
+  /// The table hint of the from clause, this method can be used enabling the feature flag `mssql`.
+  /// The hints are rendered after the from clause, so they apply to the last table of the from clause
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("*")
+  ///   .from("users")
+  ///   .table_hint("NOLOCK")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT * FROM users WITH (NOLOCK)");
+  /// ```
+  #[cfg(any(doc, feature = "mssql"))]
+  pub fn table_hint(mut self, hint: &str) -> Self {
+    push_unique(&mut self._table_hint, hint.trim().to_owned());
+    self
+  }
+
+  /// The top option of the select clause, this method can be used enabling the feature flag `mssql`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("id, login")
+  ///   .top("10")
+  ///   .from("users")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "SELECT TOP 10 id, login FROM users");
+  /// ```
+  #[cfg(any(doc, feature = "mssql"))]
+  pub fn top(mut self, num: &'a str) -> Self {
+    self._top = num.trim();
     self
   }
 
@@ -317,7 +986,56 @@ impl<'a> Select<'a> {
     self
   }
 
-  /// The with clause, this method can be used enabling the feature flag `postgresql`
+  /// The where clause with a bound value, the placeholder `$1` of the condition refers to the value
+  /// and is renumbered after the values already bound
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Select::new()
+  ///   .from("users")
+  ///   .where_bind("login = $1", "foo")
+  ///   .where_bind("created_at > $1", "2023-01-01")
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "FROM users WHERE login = $1 AND created_at > $2");
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from("2023-01-01")]);
+  /// ```
+  pub fn where_bind(mut self, condition: &str, value: impl Into<Value>) -> Self {
+    let condition = bind(&mut self._params, condition, [value.into()]);
+    push_unique(&mut self._where, condition);
+    self
+  }
+
+  /// The window clause, defines a named window that can be referenced by the `OVER` expressions of the select clause.
+  /// The windows are accumulated on consecutive calls and rendered between the having and the order by clauses
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Select::new()
+  ///   .select("name, rank() OVER w, avg(salary) OVER w")
+  ///   .from("employees")
+  ///   .window("w", sql::Over::new().partition_by("department_id").order_by("salary desc"))
+  ///   .order_by("name")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "SELECT name, rank() OVER w, avg(salary) OVER w \
+  ///   FROM employees \
+  ///   WINDOW w AS (PARTITION BY department_id ORDER BY salary desc) \
+  ///   ORDER BY name"
+  /// );
+  /// ```
+  pub fn window(mut self, name: &str, over: Over) -> Self {
+    push_unique(&mut self._window, (name.trim().to_owned(), over));
+    self
+  }
+
+  /// The with clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`
   ///
   /// # Examples
   /// ```
@@ -344,13 +1062,19 @@ impl<'a> Select<'a> {
   /// FROM orders
   /// WHERE owner_login in (select * from active_users)
   /// ```
-  #[cfg(any(doc, feature = "postgresql"))]
-  pub fn with(mut self, name: &'a str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim(), std::sync::Arc::new(query)));
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+  pub fn with(mut self, name: impl Into<Cte<'a>>, query: impl WithQuery + 'static) -> Self {
+    self._with.push((name.into(), std::sync::Arc::new(query)));
     self
   }
 }
 
+impl ExplainQuery for Select<'_> {}
+
+impl TransactionQuery for Select<'_> {}
+
+impl UsingQuery for Select<'_> {}
+
 impl WithQuery for Select<'_> {}
 
 impl std::fmt::Display for Select<'_> {
@@ -1,7 +1,9 @@
 use crate::{
   behavior::{concat_raw_before_after, Concat, ConcatMethods},
-  fmt,
-  structure::{Select, SelectClause},
+  dialect::check,
+  fmt, placeholder,
+  structure::{Dialect, Error, Join, JoinKind, LockStrength, LockWait, Select, SelectClause, SelectJoin},
+  value::Value,
 };
 
 impl<'a> ConcatMethods<'a, SelectClause> for Select<'_> {}
@@ -10,55 +12,268 @@ impl Concat for Select<'_> {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_owned();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    #[cfg(feature = "postgresql")]
+    query = self.concat_raw(query, fmts, &self._raw);
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_with(
         &self._raw_before,
         &self._raw_after,
         query,
-        &fmts,
+        fmts,
         SelectClause::With,
         &self._with,
       );
     }
-    query = self.concat_select(query, &fmts);
+    query = self.concat_select(query, fmts);
     query = self.concat_from(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       SelectClause::From,
       &self._from,
     );
-    query = self.concat_join(query, &fmts);
+    #[cfg(feature = "mssql")]
+    {
+      query = self.concat_table_hint(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        SelectClause::TableHint,
+        &self._table_hint,
+      );
+    }
+    query = self.concat_join(query, fmts);
     query = self.concat_where(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       SelectClause::Where,
       &self._where,
     );
-    query = self.concat_group_by(query, &fmts);
-    query = self.concat_having(query, &fmts);
-    query = self.concat_order_by(query, &fmts);
-    query = self.concat_limit(query, &fmts);
-    query = self.concat_offset(query, &fmts);
-    #[cfg(feature = "postgresql")]
+    query = self.concat_group_by(query, fmts);
+    query = self.concat_having(query, fmts);
+    query = self.concat_window(query, fmts);
+    query = self.concat_order_by(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      SelectClause::OrderBy,
+      &self._order_by,
+    );
+    query = self.concat_limit(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      SelectClause::Limit,
+      self._limit,
+    );
+    query = self.concat_offset(query, fmts);
+    #[cfg(feature = "mssql")]
+    {
+      query = self.concat_fetch_next(query, fmts);
+    }
+    query = self.concat_lock(query, fmts);
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       use crate::structure::Combinator;
-      query = self.concat_combinator(query, &fmts, Combinator::Except);
-      query = self.concat_combinator(query, &fmts, Combinator::Intersect);
-      query = self.concat_combinator(query, &fmts, Combinator::Union);
+      query = self.concat_combinator(query, fmts, Combinator::Except);
+      query = self.concat_combinator(query, fmts, Combinator::Intersect);
+      query = self.concat_combinator(query, fmts, Combinator::Union);
+      query = self.concat_order_by(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        SelectClause::CompoundOrderBy,
+        &self._compound_order_by,
+      );
+      query = self.concat_limit(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        SelectClause::CompoundLimit,
+        self._compound_limit,
+      );
+    }
+
+    placeholder::resolve_nested(query.trim_end())
+  }
+
+  fn params(&self) -> Vec<Value> {
+    let params = self._params.iter().cloned();
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    let params = params.chain(self._with.iter().flat_map(|(_, query)| query.params()));
+    let params = params.chain(self._join.iter().flat_map(|join| match join {
+      SelectJoin::Join(_) => vec![],
+      SelectJoin::Lateral { select, .. } => select.params(),
+    }));
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    let params = params
+      .chain(self._except.iter().flat_map(|(_, select)| select.params()))
+      .chain(self._intersect.iter().flat_map(|(_, select)| select.params()))
+      .chain(self._union.iter().flat_map(|(_, select)| select.params()));
+    params.collect()
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use Dialect::*;
+
+    check(
+      dialect,
+      "LIMIT",
+      self._limit.is_empty() == false,
+      &[Postgres, Sqlite, MySql],
+    )?;
+    for join in self._join.iter() {
+      match join {
+        SelectJoin::Join(join) => join.validate(dialect)?,
+        SelectJoin::Lateral { select, .. } => {
+          check(dialect, "LATERAL", true, &[Postgres, MySql])?;
+          select.validate(dialect)?;
+        }
+      }
+    }
+    if let Some(strength) = self._lock {
+      let clause = match strength {
+        LockStrength::NoKeyUpdate => "FOR NO KEY UPDATE",
+        LockStrength::Share => "FOR SHARE",
+        LockStrength::Update => "FOR UPDATE",
+      };
+      let dialects: &[Dialect] = match strength {
+        LockStrength::NoKeyUpdate => &[Postgres],
+        LockStrength::Share | LockStrength::Update => &[Postgres, MySql],
+      };
+      check(dialect, clause, true, dialects)?;
+    }
+    check(
+      dialect,
+      "LOCK OPTION WITHOUT STRENGTH",
+      self._lock.is_none() && (self._lock_of.is_empty() == false || self._lock_wait.is_some()),
+      &[],
+    )?;
+    #[cfg(feature = "postgresql")]
+    {
+      check(
+        dialect,
+        "DISTINCT ON",
+        self._distinct_on.is_empty() == false,
+        &[Postgres],
+      )?;
+    }
+    #[cfg(feature = "mssql")]
+    {
+      check(
+        dialect,
+        "FETCH NEXT",
+        self._fetch_next.is_empty() == false,
+        &[Postgres, MsSql],
+      )?;
+      check(dialect, "TABLE HINT", self._table_hint.is_empty() == false, &[MsSql])?;
+      check(dialect, "TOP", self._top.is_empty() == false, &[MsSql])?;
+      check(
+        dialect,
+        "FETCH NEXT WITHOUT OFFSET",
+        self._fetch_next.is_empty() == false && self._offset.is_empty(),
+        &[Postgres],
+      )?;
+      check(
+        dialect,
+        "LIMIT WITH FETCH NEXT",
+        self._fetch_next.is_empty() == false && self._limit.is_empty() == false,
+        &[],
+      )?;
+      check(
+        dialect,
+        "TOP WITH OFFSET",
+        self._top.is_empty() == false && (self._offset.is_empty() == false || self._fetch_next.is_empty() == false),
+        &[],
+      )?;
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      for (cte, query) in self._with.iter() {
+        cte.validate(dialect)?;
+        query.validate(dialect)?;
+      }
+      check(
+        dialect,
+        "LIMIT",
+        self._compound_limit.is_empty() == false,
+        &[Postgres, Sqlite, MySql],
+      )?;
+      check(
+        dialect,
+        "EXCEPT ALL",
+        self._except.iter().any(|(all, _)| *all),
+        &[Postgres, MySql],
+      )?;
+      check(
+        dialect,
+        "INTERSECT ALL",
+        self._intersect.iter().any(|(all, _)| *all),
+        &[Postgres, MySql],
+      )?;
+      let operands = self._except.iter().chain(&self._intersect).chain(&self._union);
+      let is_compound =
+        self._except.is_empty() == false || self._intersect.is_empty() == false || self._union.is_empty() == false;
+      let needs_parentheses = |select: &Select| {
+        select._order_by.is_empty() == false || select._limit.is_empty() == false || select._offset.is_empty() == false
+      };
+      let operand_needs_parentheses = operands
+        .clone()
+        .any(|(_, select)| needs_parentheses(select) || select._with.is_empty() == false);
+      for (_, select) in operands {
+        select.validate(dialect)?;
+      }
+      check(
+        dialect,
+        "PARENTHESIZED COMPOUND OPERAND",
+        is_compound && (needs_parentheses(self) || operand_needs_parentheses),
+        &[Postgres, MySql],
+      )?;
+      let operand_has_lock = self
+        ._except
+        .iter()
+        .chain(&self._intersect)
+        .chain(&self._union)
+        .any(|(_, select)| select._lock.is_some());
+      check(
+        dialect,
+        "LOCK WITH COMPOUND QUERY",
+        is_compound && (self._lock.is_some() || operand_has_lock),
+        &[],
+      )?;
     }
 
-    query.trim_end().to_owned()
+    Ok(())
   }
 }
 
 impl Select<'_> {
-  #[cfg(feature = "postgresql")]
+  /// Adds a join of the join methods that take the table as a string, the duplicated joins are skipped
+  pub(crate) fn push_join(&mut self, kind: JoinKind, table: &str) {
+    let join = Join {
+      _kind: Some(kind),
+      _table: table.trim().to_owned(),
+      ..Default::default()
+    };
+    if self
+      ._join
+      .iter()
+      .any(|item| matches!(item, SelectJoin::Join(prev) if *prev == join))
+      == false
+    {
+      self._join.push(SelectJoin::Join(join));
+    }
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   fn concat_combinator(
     &self,
     query: String,
@@ -94,18 +309,46 @@ impl Select<'_> {
       return format!("{query}{raw_before}{space_before}{sql}{raw_after}{space_after}");
     }
 
-    let right_stmt = clause_list.iter().fold("".to_owned(), |acc, select| {
-      let query = select.concat(&fmts);
-      format!("{acc}{clause_name}{space}({lb}{query}){space}{lb}")
+    // SQLite doesn't accept parentheses around the operands of a compound select
+    let (open, close) = if fmts.dialect == Some(Dialect::Sqlite) {
+      ("", "")
+    } else {
+      ("(", ")")
+    };
+
+    let right_stmt = clause_list.iter().fold("".to_owned(), |acc, (all, select)| {
+      let query = placeholder::nested(&select.concat(fmts));
+      let all = if *all { format!("{space}ALL") } else { "".to_owned() };
+      format!("{acc}{clause_name}{all}{space}{open}{lb}{query}{close}{space}{lb}")
     });
 
     let query = query.trim_end();
     let space_before = space;
-    let left_stmt = format!("({query}{raw_before}){space_before}");
+    let left_stmt = format!("{open}{query}{raw_before}{close}{space_before}");
 
     format!("{left_stmt}{right_stmt}{raw_after}{space_after}")
   }
 
+  #[cfg(feature = "mssql")]
+  fn concat_fetch_next(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._fetch_next.is_empty() == false {
+      let count = self._fetch_next;
+      format!("FETCH NEXT{space}{count}{space}ROWS ONLY{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      SelectClause::FetchNext,
+      sql,
+    )
+  }
+
   fn concat_group_by(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
     let sql = if self._group_by.is_empty() == false {
@@ -145,9 +388,40 @@ impl Select<'_> {
   }
 
   fn concat_join(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._join.is_empty() == false {
-      let joins = self._join.join(format!("{space}{lb}").as_str());
+    let fmt::Formatter {
+      comma,
+      lb,
+      indent,
+      space,
+      ..
+    } = fmts;
+    let joins = self
+      ._join
+      .iter()
+      .map(|join| match join {
+        SelectJoin::Join(join) => join.concat(fmts),
+        SelectJoin::Lateral { alias, join, select } => {
+          let inner_lb = format!("{lb}{indent}");
+          let inner_fmts = fmt::Formatter {
+            comma,
+            lb: inner_lb.as_str(),
+            indent,
+            space,
+            ..*fmts
+          };
+          let select_string = placeholder::nested(&select.concat(&inner_fmts));
+          let condition = if *join == "LEFT JOIN LATERAL" {
+            format!("{space}ON{space}true")
+          } else {
+            "".to_owned()
+          };
+          format!("{join}{space}({lb}{indent}{select_string}{lb}){space}{alias}{condition}")
+        }
+      })
+      .filter(|join| join.is_empty() == false)
+      .collect::<Vec<_>>();
+    let sql = if joins.is_empty() == false {
+      let joins = joins.join(format!("{space}{lb}").as_str());
       format!("{joins}{space}{lb}")
     } else {
       "".to_owned()
@@ -163,11 +437,26 @@ impl Select<'_> {
     )
   }
 
-  fn concat_limit(&self, query: String, fmts: &fmt::Formatter) -> String {
-    let fmt::Formatter { lb, space, .. } = fmts;
-    let sql = if self._limit.is_empty() == false {
-      let count = self._limit;
-      format!("LIMIT{space}{count}{space}{lb}")
+  fn concat_lock(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if let Some(strength) = self._lock {
+      let strength = match strength {
+        LockStrength::NoKeyUpdate => format!("NO{space}KEY{space}UPDATE"),
+        LockStrength::Share => "SHARE".to_owned(),
+        LockStrength::Update => "UPDATE".to_owned(),
+      };
+      let tables = if self._lock_of.is_empty() == false {
+        let tables = self._lock_of.join(comma);
+        format!("{space}OF{space}{tables}")
+      } else {
+        "".to_owned()
+      };
+      let wait = match self._lock_wait {
+        Some(LockWait::NoWait) => format!("{space}NOWAIT"),
+        Some(LockWait::SkipLocked) => format!("{space}SKIP{space}LOCKED"),
+        None => "".to_owned(),
+      };
+      format!("FOR{space}{strength}{tables}{wait}{space}{lb}")
     } else {
       "".to_owned()
     };
@@ -177,7 +466,7 @@ impl Select<'_> {
       &self._raw_after,
       query,
       fmts,
-      SelectClause::Limit,
+      SelectClause::Lock,
       sql,
     )
   }
@@ -186,7 +475,22 @@ impl Select<'_> {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._offset.is_empty() == false {
       let start = self._offset;
-      format!("OFFSET{space}{start}{space}{lb}")
+      #[cfg(feature = "mssql")]
+      let needs_rows = fmts.dialect == Some(Dialect::MsSql) || self._fetch_next.is_empty() == false;
+      #[cfg(not(feature = "mssql"))]
+      let needs_rows = fmts.dialect == Some(Dialect::MsSql);
+      let unit_written = ["ROW", "ROWS"].iter().any(|unit| {
+        start
+          .rsplit(' ')
+          .next()
+          .is_some_and(|last| last.eq_ignore_ascii_case(unit))
+      });
+      let rows = if needs_rows && unit_written == false {
+        format!("{space}ROWS")
+      } else {
+        "".to_owned()
+      };
+      format!("OFFSET{space}{start}{rows}{space}{lb}")
     } else {
       "".to_owned()
     };
@@ -201,11 +505,30 @@ impl Select<'_> {
     )
   }
 
-  fn concat_order_by(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._order_by.is_empty() == false {
-      let columns = self._order_by.join(comma);
-      format!("ORDER BY{space}{columns}{space}{lb}")
+    let sql = if self._select.is_empty() == false {
+      let columns = self._select.join(comma);
+      #[cfg(feature = "mssql")]
+      let columns = if self._top.is_empty() == false {
+        let top = self._top;
+        format!("TOP{space}{top}{space}{columns}")
+      } else {
+        columns
+      };
+      let distinct = if self._distinct {
+        format!("DISTINCT{space}")
+      } else {
+        "".to_owned()
+      };
+      #[cfg(feature = "postgresql")]
+      let distinct = if self._distinct_on.is_empty() == false {
+        let expressions = self._distinct_on.join(comma);
+        format!("DISTINCT ON{space}({expressions}){space}")
+      } else {
+        distinct
+      };
+      format!("SELECT{space}{distinct}{columns}{space}{lb}")
     } else {
       "".to_owned()
     };
@@ -215,16 +538,21 @@ impl Select<'_> {
       &self._raw_after,
       query,
       fmts,
-      SelectClause::OrderBy,
+      SelectClause::Select,
       sql,
     )
   }
 
-  fn concat_select(&self, query: String, fmts: &fmt::Formatter) -> String {
+  fn concat_window(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { comma, lb, space, .. } = fmts;
-    let sql = if self._select.is_empty() == false {
-      let columns = self._select.join(comma);
-      format!("SELECT{space}{columns}{space}{lb}")
+    let sql = if self._window.is_empty() == false {
+      let windows = self
+        ._window
+        .iter()
+        .map(|(name, over)| format!("{name}{space}AS{space}{}", over.concat(fmts)))
+        .collect::<Vec<_>>()
+        .join(comma);
+      format!("WINDOW{space}{windows}{space}{lb}")
     } else {
       "".to_owned()
     };
@@ -234,7 +562,7 @@ impl Select<'_> {
       &self._raw_after,
       query,
       fmts,
-      SelectClause::Select,
+      SelectClause::Window,
       sql,
     )
   }
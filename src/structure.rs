@@ -1,23 +1,366 @@
-#[cfg(feature = "postgresql")]
+use crate::value::Value;
+use std::borrow::Cow;
+
+/// Builder to contruct a [AlterTable] command
+#[derive(Default, Clone)]
+pub struct AlterTable<'a> {
+  pub(crate) _actions: Vec<AlterTableAction>,
+  pub(crate) _alter_table: Cow<'a, str>,
+  pub(crate) _raw_after: Vec<(AlterTableClause, String)>,
+  pub(crate) _raw_before: Vec<(AlterTableClause, String)>,
+  pub(crate) _raw: Vec<String>,
+}
+
+/// The actions of the [AlterTable] builder in the call order
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) enum AlterTableAction {
+  AddColumn(String),
+  AddConstraint(String),
+  AlterColumnDropDefault(String),
+  AlterColumnSetDefault(String, String),
+  AlterColumnType(String, String),
+  DropColumn(String),
+  DropConstraint(String),
+  RenameColumn(String, String),
+  RenameTo(String),
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [AlterTable] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "default 'guest'";
+/// let alter_table = sql::AlterTable::new()
+///   .alter_table("users")
+///   .add_column("login varchar(40)")
+///   .raw_after(sql::AlterTableClause::AddColumn, raw)
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum AlterTableClause {
+  AddColumn,
+  AddConstraint,
+  AlterColumn,
+  AlterTable,
+  DropColumn,
+  DropConstraint,
+  RenameColumn,
+  RenameTo,
+}
+
+#[cfg(any(feature = "postgresql", feature = "sqlite"))]
 pub enum Combinator {
   Except,
   Intersect,
   Union,
 }
 
+#[derive(Clone, PartialEq)]
+pub(crate) enum ConflictAction {
+  DoNothing,
+  DoUpdate {
+    excluded: bool,
+    set: Vec<String>,
+    where_clause: Vec<String>,
+  },
+}
+
+#[derive(Clone, PartialEq)]
+pub(crate) enum ConflictTarget {
+  Columns(Vec<String>),
+  Constraint(String),
+}
+
+/// Builder to contruct a [Copy](struct@crate::Copy) command, this builder can be used enabling the feature flag `postgresql`
+#[cfg(any(doc, feature = "postgresql"))]
+#[derive(Default, Clone)]
+pub struct Copy<'a> {
+  pub(crate) _copy: Cow<'a, str>,
+  pub(crate) _delimiter: Option<String>,
+  pub(crate) _format: Option<CopyFormat>,
+  pub(crate) _from: String,
+  pub(crate) _header: bool,
+  pub(crate) _null: Option<String>,
+  pub(crate) _raw_after: Vec<(CopyClause, String)>,
+  pub(crate) _raw_before: Vec<(CopyClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _select: Option<Select<'a>>,
+  pub(crate) _to: String,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [Copy](struct@crate::Copy) builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* the users export */";
+/// let copy = sql::Copy::new()
+///   .raw_before(sql::CopyClause::Copy, raw)
+///   .copy("users")
+///   .to_stdout()
+///   .as_string();
+/// ```
+#[cfg(any(doc, feature = "postgresql"))]
+#[derive(PartialEq, Clone)]
+pub enum CopyClause {
+  Copy,
+  From,
+  To,
+  With,
+}
+
+/// The data formats of the [Copy](struct@crate::Copy) builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::Copy::new()
+///   .copy("users")
+///   .from_stdin()
+///   .format(sql::CopyFormat::Binary)
+///   .as_string();
+///
+/// assert_eq!(query, "COPY users FROM STDIN WITH (FORMAT binary)");
+/// ```
+#[cfg(any(doc, feature = "postgresql"))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CopyFormat {
+  Binary,
+  Csv,
+  Text,
+}
+
+/// Builder to contruct a [CreateIndex] command
+#[derive(Default, Clone)]
+pub struct CreateIndex<'a> {
+  pub(crate) _column: Vec<String>,
+  pub(crate) _create_index: Cow<'a, str>,
+  pub(crate) _if_not_exists: bool,
+  pub(crate) _include: Vec<String>,
+  pub(crate) _on: Cow<'a, str>,
+  pub(crate) _raw_after: Vec<(CreateIndexClause, String)>,
+  pub(crate) _raw_before: Vec<(CreateIndexClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _unique: bool,
+  pub(crate) _using: &'a str,
+  pub(crate) _where: Vec<String>,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _concurrently: bool,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [CreateIndex] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* the login index */";
+/// let create_index = sql::CreateIndex::new()
+///   .raw_before(sql::CreateIndexClause::CreateIndex, raw)
+///   .create_index("users_login_idx")
+///   .on("users")
+///   .column("login")
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum CreateIndexClause {
+  Column,
+  CreateIndex,
+  Include,
+  On,
+  Using,
+  Where,
+}
+
+/// Builder to contruct a [CreateTable] command
+#[derive(Default, Clone)]
+pub struct CreateTable<'a> {
+  pub(crate) _check: Vec<String>,
+  pub(crate) _column: Vec<String>,
+  pub(crate) _constraint: Vec<String>,
+  pub(crate) _create_table: Cow<'a, str>,
+  pub(crate) _foreign_key: Vec<String>,
+  pub(crate) _if_not_exists: bool,
+  pub(crate) _primary_key: &'a str,
+  pub(crate) _raw_after: Vec<(CreateTableClause, String)>,
+  pub(crate) _raw_before: Vec<(CreateTableClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _unique: Vec<String>,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _inherits: Vec<String>,
+  #[cfg(feature = "postgresql")]
+  pub(crate) _partition_by: &'a str,
+  #[cfg(feature = "postgresql")]
+  pub(crate) _tablespace: &'a str,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [CreateTable] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* the primary key */";
+/// let create_table = sql::CreateTable::new()
+///   .create_table("users")
+///   .column("id serial")
+///   .raw_after(sql::CreateTableClause::Column, raw)
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum CreateTableClause {
+  Check,
+  Column,
+  Constraint,
+  CreateTable,
+  ForeignKey,
+  PrimaryKey,
+  Unique,
+
+  #[cfg(feature = "postgresql")]
+  Inherits,
+  #[cfg(feature = "postgresql")]
+  PartitionBy,
+  #[cfg(feature = "postgresql")]
+  Tablespace,
+}
+
+/// Builder to contruct a [CreateView] command
+#[derive(Default, Clone)]
+pub struct CreateView<'a> {
+  pub(crate) _column: Vec<String>,
+  pub(crate) _create_view: Cow<'a, str>,
+  pub(crate) _or_replace: bool,
+  pub(crate) _raw_after: Vec<(CreateViewClause, String)>,
+  pub(crate) _raw_before: Vec<(CreateViewClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _select: Option<Select<'a>>,
+  pub(crate) _with_check_option: bool,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _materialized: bool,
+  #[cfg(feature = "postgresql")]
+  pub(crate) _with_data: Option<bool>,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [CreateView] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* the active users */";
+/// let create_view = sql::CreateView::new()
+///   .raw_before(sql::CreateViewClause::CreateView, raw)
+///   .create_view("active_users")
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum CreateViewClause {
+  Column,
+  CreateView,
+  Select,
+  WithCheckOption,
+
+  #[cfg(feature = "postgresql")]
+  WithData,
+}
+
+/// Builder to contruct a common table expression of the `with` method, defines the name of the expression
+/// and its options. A string can be used in place of the builder when only the name is needed
+///
+/// # Examples
+/// ```
+/// # #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let subordinates = sql::Select::new()
+///   .select("id, manager_id")
+///   .from("employees")
+///   .where_clause("id = 1")
+///   .union(
+///     sql::Select::new()
+///       .select("e.id, e.manager_id")
+///       .from("employees e")
+///       .inner_join("subordinates s ON s.id = e.manager_id"),
+///   );
+///
+/// let query = sql::Select::new()
+///   .with(
+///     sql::Cte::new("subordinates").recursive().column("id").column("manager_id"),
+///     subordinates,
+///   )
+///   .select("*")
+///   .from("subordinates")
+///   .as_string();
+///
+/// assert_eq!(
+///   query,
+///   "WITH RECURSIVE subordinates(id, manager_id) AS (\
+///   (SELECT id, manager_id FROM employees WHERE id = 1) \
+///   UNION \
+///   (SELECT e.id, e.manager_id FROM employees e INNER JOIN subordinates s ON s.id = e.manager_id)\
+///   ) \
+///   SELECT * FROM subordinates"
+/// );
+/// # }
+/// ```
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+#[derive(Default, Clone, PartialEq)]
+pub struct Cte<'a> {
+  pub(crate) _column: Vec<String>,
+  pub(crate) _name: &'a str,
+  pub(crate) _recursive: bool,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _materialized: Option<bool>,
+}
+
+/// The databases supported by the quoting functions like [quote_ident](crate::quote_ident) and by the
+/// `as_string_for` methods of the builders
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dialect {
+  /// Identifiers quoted with double quotes `"name"`
+  Postgres,
+  /// Identifiers quoted with double quotes `"name"`
+  Sqlite,
+  /// Identifiers quoted with backticks `` `name` `` and backslashes escaped inside literals
+  MySql,
+  /// Identifiers quoted with brackets `[name]`
+  MsSql,
+}
+
 /// Builder to contruct a [Delete] command
 #[derive(Default, Clone)]
 pub struct Delete<'a> {
-  pub(crate) _delete_from: &'a str,
+  pub(crate) _delete_from: Cow<'a, str>,
+  pub(crate) _params: Vec<Value>,
   pub(crate) _raw_after: Vec<(DeleteClause, String)>,
   pub(crate) _raw_before: Vec<(DeleteClause, String)>,
   pub(crate) _raw: Vec<String>,
   pub(crate) _where: Vec<String>,
 
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub(crate) _returning: Vec<String>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _with: Vec<(&'a str, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _with: Vec<(Cte<'a>, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+
+  #[cfg(feature = "mysql")]
+  pub(crate) _limit: &'a str,
+  #[cfg(feature = "mysql")]
+  pub(crate) _order_by: Vec<String>,
+
+  #[cfg(feature = "mssql")]
+  pub(crate) _output: Vec<String>,
+  #[cfg(feature = "mssql")]
+  pub(crate) _table_hint: Vec<String>,
 }
 
 /// All available clauses to be used in `raw_before` and `raw_after` methods on [Delete] builder
@@ -37,28 +380,264 @@ pub enum DeleteClause {
   DeleteFrom,
   Where,
 
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   Returning,
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   With,
+
+  #[cfg(feature = "mysql")]
+  Limit,
+  #[cfg(feature = "mysql")]
+  OrderBy,
+
+  #[cfg(feature = "mssql")]
+  Output,
+  #[cfg(feature = "mssql")]
+  TableHint,
+}
+
+/// Builder to contruct a [DropIndex] command
+#[derive(Default, Clone)]
+pub struct DropIndex {
+  pub(crate) _cascade: bool,
+  pub(crate) _drop_index: Vec<String>,
+  pub(crate) _if_exists: bool,
+  pub(crate) _on: String,
+  pub(crate) _raw_after: Vec<(DropIndexClause, String)>,
+  pub(crate) _raw_before: Vec<(DropIndexClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _restrict: bool,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [DropIndex] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* teardown */";
+/// let drop_index = sql::DropIndex::new()
+///   .raw_before(sql::DropIndexClause::DropIndex, raw)
+///   .drop_index("users_login_idx")
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum DropIndexClause {
+  DropIndex,
+}
+
+/// Builder to contruct a [DropTable] command
+#[derive(Default, Clone)]
+pub struct DropTable {
+  pub(crate) _cascade: bool,
+  pub(crate) _drop_table: Vec<String>,
+  pub(crate) _if_exists: bool,
+  pub(crate) _raw_after: Vec<(DropTableClause, String)>,
+  pub(crate) _raw_before: Vec<(DropTableClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _restrict: bool,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [DropTable] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* teardown */";
+/// let drop_table = sql::DropTable::new()
+///   .raw_before(sql::DropTableClause::DropTable, raw)
+///   .drop_table("users")
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum DropTableClause {
+  DropTable,
+}
+
+/// Builder to contruct a [DropView] command
+#[derive(Default, Clone)]
+pub struct DropView {
+  pub(crate) _cascade: bool,
+  pub(crate) _drop_view: Vec<String>,
+  pub(crate) _if_exists: bool,
+  pub(crate) _raw_after: Vec<(DropViewClause, String)>,
+  pub(crate) _raw_before: Vec<(DropViewClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _restrict: bool,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [DropView] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* teardown */";
+/// let drop_view = sql::DropView::new()
+///   .raw_before(sql::DropViewClause::DropView, raw)
+///   .drop_view("active_users")
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum DropViewClause {
+  DropView,
+}
+
+/// The errors returned by the `as_string_for` methods of the builders
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let result = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .limit("10")
+///   .as_string_for(sql::Dialect::MsSql);
+///
+/// assert_eq!(
+///   result,
+///   Err(sql::Error::UnsupportedClause {
+///     clause: "LIMIT",
+///     dialect: sql::Dialect::MsSql
+///   })
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+  /// A clause in use, on the builder or on one of its nested builders, that the dialect doesn't support
+  UnsupportedClause { clause: &'static str, dialect: Dialect },
+  /// A positional placeholder like `$3` without bound value, the values returned would not follow the placeholders
+  UnboundPlaceholder { position: usize },
+  /// A named placeholder like `:login` not found in the map of the `as_query_named` methods
+  UnknownPlaceholder { name: String },
+  /// The placeholders of a [Transaction] belong to more than one statement, the database drivers
+  /// can't send a query with several statements and parameters, each statement must be executed on its own
+  MultipleStatementsWithParams,
+}
+
+/// Builder to contruct a [Explain] command, the statement is rendered after the `EXPLAIN` keyword and its options
+#[derive(Default, Clone)]
+pub struct Explain {
+  pub(crate) _analyze: bool,
+  pub(crate) _explain: Option<std::sync::Arc<dyn crate::behavior::ExplainQuery>>,
+  pub(crate) _raw_after: Vec<(ExplainClause, String)>,
+  pub(crate) _raw_before: Vec<(ExplainClause, String)>,
+  pub(crate) _raw: Vec<String>,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _option: Vec<ExplainOption>,
+
+  #[cfg(feature = "sqlite")]
+  pub(crate) _query_plan: bool,
+
+  #[cfg(any(feature = "postgresql", feature = "mysql"))]
+  pub(crate) _format: Option<ExplainFormat>,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [Explain] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* the slow query */";
+/// let explain = sql::Explain::new()
+///   .raw_before(sql::ExplainClause::Explain, raw)
+///   .explain(sql::Select::new().select("*").from("orders"))
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum ExplainClause {
+  Explain,
+  Statement,
+}
+
+/// The output formats of the [Explain] builder, `Text`, `Xml` and `Yaml` are formats of the PostgreSQL,
+/// `Traditional` and `Tree` are formats of the MySQL and `Json` is available in both
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "postgresql")]
+/// # {
+/// use sql_query_builder as sql;
+///
+/// let explain = sql::Explain::new()
+///   .format(sql::ExplainFormat::Json)
+///   .explain(sql::Select::new().select("*").from("orders"));
+/// # }
+/// ```
+#[cfg(any(doc, feature = "postgresql", feature = "mysql"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExplainFormat {
+  Json,
+  Text,
+  Traditional,
+  Tree,
+  Xml,
+  Yaml,
+}
+
+/// The options of the PostgreSQL `EXPLAIN`, rendered in parentheses in the call order
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let query = sql::Explain::new()
+///   .option(sql::ExplainOption::Analyze)
+///   .option(sql::ExplainOption::Costs(false))
+///   .explain(sql::Select::new().select("*").from("orders"))
+///   .as_string();
+///
+/// assert_eq!(query, "EXPLAIN (ANALYZE, COSTS FALSE) SELECT * FROM orders");
+/// ```
+#[cfg(any(doc, feature = "postgresql"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExplainOption {
+  Analyze,
+  Buffers,
+  Costs(bool),
+  Settings,
+  Summary,
+  Timing(bool),
+  Verbose,
+  Wal,
 }
 
 /// Builder to contruct a [Insert] command
 #[derive(Default, Clone)]
 pub struct Insert<'a> {
-  pub(crate) _insert_into: &'a str,
+  pub(crate) _insert_into: Cow<'a, str>,
   pub(crate) _on_conflict: &'a str,
+  pub(crate) _on_conflict_action: Option<ConflictAction>,
+  pub(crate) _on_conflict_target: Option<ConflictTarget>,
   pub(crate) _overriding: &'a str,
+  pub(crate) _params: Vec<Value>,
   pub(crate) _raw_after: Vec<(InsertClause, String)>,
   pub(crate) _raw_before: Vec<(InsertClause, String)>,
   pub(crate) _raw: Vec<String>,
   pub(crate) _select: Option<Select<'a>>,
   pub(crate) _values: Vec<String>,
 
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub(crate) _returning: Vec<String>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _with: Vec<(&'a str, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _with: Vec<(Cte<'a>, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+
+  #[cfg(feature = "sqlite")]
+  pub(crate) _insert_or: Cow<'a, str>,
+  #[cfg(any(feature = "sqlite", feature = "mysql"))]
+  pub(crate) _replace_into: Cow<'a, str>,
+
+  #[cfg(feature = "mysql")]
+  pub(crate) _insert_ignore_into: Cow<'a, str>,
+  #[cfg(feature = "mysql")]
+  pub(crate) _on_duplicate_key_update: Vec<String>,
+
+  #[cfg(feature = "mssql")]
+  pub(crate) _output: Vec<String>,
 }
 
 /// All available clauses to be used in `raw_before` and `raw_after` methods on [Insert] builder
@@ -81,36 +660,281 @@ pub enum InsertClause {
   Select,
   Values,
 
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   Returning,
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   With,
+
+  #[cfg(feature = "sqlite")]
+  InsertOr,
+  #[cfg(any(feature = "sqlite", feature = "mysql"))]
+  ReplaceInto,
+
+  #[cfg(feature = "mysql")]
+  InsertIgnoreInto,
+  #[cfg(feature = "mysql")]
+  OnDuplicateKeyUpdate,
+
+  #[cfg(feature = "mssql")]
+  Output,
+}
+
+/// The isolation levels of the [Transaction] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let transaction = sql::Transaction::new()
+///   .isolation_level(sql::IsolationLevel::Serializable)
+///   .statement(sql::Delete::new().delete_from("users"));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IsolationLevel {
+  ReadUncommitted,
+  ReadCommitted,
+  RepeatableRead,
+  Serializable,
+}
+
+/// Builder to contruct a join of the [Select::join] method, the join condition is defined
+/// by the `ON` conditions or by the `USING` column list
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let join = sql::Join::new()
+///   .left_join("addresses a")
+///   .on("a.user_id = u.id")
+///   .on("a.main = true");
+///
+/// let query = sql::Select::new()
+///   .select("u.login, a.city")
+///   .from("users u")
+///   .join(join)
+///   .as_string();
+///
+/// assert_eq!(
+///   query,
+///   "SELECT u.login, a.city FROM users u LEFT JOIN addresses a ON a.user_id = u.id AND a.main = true"
+/// );
+/// ```
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct Join {
+  pub(crate) _kind: Option<JoinKind>,
+  pub(crate) _on: Vec<String>,
+  pub(crate) _table: String,
+  pub(crate) _using: Vec<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JoinKind {
+  Cross,
+  Full,
+  Inner,
+  Left,
+  Natural,
+  Right,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum LockStrength {
+  NoKeyUpdate,
+  Share,
+  Update,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum LockWait {
+  NoWait,
+  SkipLocked,
+}
+
+/// Builder to contruct a [Merge] command
+#[derive(Default, Clone)]
+pub struct Merge<'a> {
+  pub(crate) _merge_into: Cow<'a, str>,
+  pub(crate) _on: Vec<String>,
+  pub(crate) _params: Vec<Value>,
+  pub(crate) _raw_after: Vec<(MergeClause, String)>,
+  pub(crate) _raw_before: Vec<(MergeClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _using: Cow<'a, str>,
+  pub(crate) _using_query: Option<(&'a str, std::sync::Arc<dyn crate::behavior::UsingQuery>)>,
+  pub(crate) _when: Vec<MergeWhen>,
+}
+
+#[derive(Clone, PartialEq)]
+pub(crate) enum MergeAction {
+  Delete,
+  DoNothing,
+  Insert { columns: String, values: Vec<String> },
+  Update(Vec<String>),
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [Merge] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* sync the customers */";
+/// let merge = sql::Merge::new()
+///   .raw_before(sql::MergeClause::MergeInto, raw)
+///   .merge_into("customers")
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum MergeClause {
+  MergeInto,
+  On,
+  Using,
+  When,
+}
+
+#[derive(Clone)]
+pub(crate) struct MergeWhen {
+  pub(crate) action: Option<MergeAction>,
+  pub(crate) condition: String,
+  pub(crate) matched: bool,
+}
+
+/// Builder to contruct the window specification of an `OVER` expression or a named window of the
+/// [Select::window] clause, the specification is rendered in parentheses
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let over = sql::Over::new()
+///   .partition_by("department_id")
+///   .order_by("salary desc");
+///
+/// let query = sql::Select::new()
+///   .select(&format!("name, row_number() OVER {over}"))
+///   .from("employees")
+///   .as_string();
+///
+/// assert_eq!(
+///   query,
+///   "SELECT name, row_number() OVER (PARTITION BY department_id ORDER BY salary desc) FROM employees"
+/// );
+/// ```
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct Over {
+  pub(crate) _frame: String,
+  pub(crate) _order_by: Vec<String>,
+  pub(crate) _partition_by: Vec<String>,
+}
+
+/// The placeholder styles used by `as_string_with` and `as_query_with` methods to render the positional
+/// placeholders `$1`, `$2`, ... of the builders, quoted literals and comments are kept untouched
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let select = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .where_clause("login = $1")
+///   .and("active = $2");
+///
+/// let query = select.as_string_with(sql::Placeholder::QuestionMark);
+/// assert_eq!(query, "SELECT * FROM users WHERE login = ? AND active = ?");
+///
+/// let query = select.as_string_with(sql::Placeholder::At);
+/// assert_eq!(query, "SELECT * FROM users WHERE login = @p1 AND active = @p2");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Placeholder {
+  /// `$1`, `$2`, ... used by Postgres
+  Dollar,
+  /// `?` used by SQLite and MySQL, the values must be passed in the order the placeholders appear
+  QuestionMark,
+  /// `:p1`, `:p2`, ... used by SQLite and Oracle. The names are made from the position since the values
+  /// are bound by position, the named placeholders like `:login` are resolved by the `as_query_named` methods
+  Colon,
+  /// `@p1`, `@p2`, ... used by SQL Server
+  At,
+}
+
+/// Builder to contruct a [RefreshMaterializedView] command, this builder can be used enabling the feature flag `postgresql`
+#[cfg(any(doc, feature = "postgresql"))]
+#[derive(Default, Clone)]
+pub struct RefreshMaterializedView<'a> {
+  pub(crate) _concurrently: bool,
+  pub(crate) _raw_after: Vec<(RefreshMaterializedViewClause, String)>,
+  pub(crate) _raw_before: Vec<(RefreshMaterializedViewClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _refresh_materialized_view: Cow<'a, str>,
+  pub(crate) _with_data: Option<bool>,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [RefreshMaterializedView] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* nightly refresh */";
+/// let refresh = sql::RefreshMaterializedView::new()
+///   .raw_before(sql::RefreshMaterializedViewClause::RefreshMaterializedView, raw)
+///   .refresh_materialized_view("sales_summary")
+///   .as_string();
+/// ```
+#[cfg(any(doc, feature = "postgresql"))]
+#[derive(PartialEq, Clone)]
+pub enum RefreshMaterializedViewClause {
+  RefreshMaterializedView,
+  WithData,
 }
 
 /// Builder to contruct a [Select] command
 #[derive(Default, Clone)]
 pub struct Select<'a> {
+  pub(crate) _distinct: bool,
   pub(crate) _from: Vec<String>,
   pub(crate) _group_by: Vec<String>,
   pub(crate) _having: Vec<String>,
-  pub(crate) _join: Vec<String>,
+  pub(crate) _join: Vec<SelectJoin<'a>>,
   pub(crate) _limit: &'a str,
+  pub(crate) _lock: Option<LockStrength>,
+  pub(crate) _lock_of: Vec<String>,
+  pub(crate) _lock_wait: Option<LockWait>,
   pub(crate) _offset: &'a str,
   pub(crate) _order_by: Vec<String>,
+  pub(crate) _params: Vec<Value>,
   pub(crate) _raw_after: Vec<(SelectClause, String)>,
   pub(crate) _raw_before: Vec<(SelectClause, String)>,
   pub(crate) _raw: Vec<String>,
   pub(crate) _select: Vec<String>,
   pub(crate) _where: Vec<String>,
+  pub(crate) _window: Vec<(String, Over)>,
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _compound_limit: &'a str,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _compound_order_by: Vec<String>,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _except: Vec<(bool, Self)>,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _intersect: Vec<(bool, Self)>,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _union: Vec<(bool, Self)>,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _with: Vec<(Cte<'a>, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
 
   #[cfg(feature = "postgresql")]
-  pub(crate) _except: Vec<Self>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _intersect: Vec<Self>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _union: Vec<Self>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _with: Vec<(&'a str, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  pub(crate) _distinct_on: Vec<String>,
+
+  #[cfg(feature = "mssql")]
+  pub(crate) _fetch_next: &'a str,
+  #[cfg(feature = "mssql")]
+  pub(crate) _table_hint: Vec<String>,
+  #[cfg(feature = "mssql")]
+  pub(crate) _top: &'a str,
 }
 
 /// All available clauses to be used in `raw_before` and `raw_after` methods on [Select] builder
@@ -134,37 +958,140 @@ pub enum SelectClause {
   Having,
   Join,
   Limit,
+  Lock,
   Offset,
   OrderBy,
   Select,
   Where,
+  Window,
 
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  CompoundLimit,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  CompoundOrderBy,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   Except,
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   Intersect,
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   Union,
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   With,
+
+  #[cfg(feature = "mssql")]
+  FetchNext,
+  #[cfg(feature = "mssql")]
+  TableHint,
+}
+
+#[derive(Clone)]
+pub(crate) enum SelectJoin<'a> {
+  Join(Join),
+  Lateral {
+    alias: String,
+    join: &'static str,
+    select: Box<Select<'a>>,
+  },
+}
+
+/// Builder to contruct a [Transaction] script, the statements are wrapped in `BEGIN` and `COMMIT`
+/// and separated by `;`
+#[derive(Default, Clone)]
+pub struct Transaction {
+  pub(crate) _isolation_level: Option<IsolationLevel>,
+  pub(crate) _ordered_commands: Vec<TransactionCommand>,
+  pub(crate) _raw_after: Vec<(TransactionClause, String)>,
+  pub(crate) _raw_before: Vec<(TransactionClause, String)>,
+  pub(crate) _raw: Vec<String>,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [Transaction] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "lock table users in exclusive mode;";
+/// let transaction = sql::Transaction::new()
+///   .raw_after(sql::TransactionClause::Begin, raw)
+///   .statement(sql::Delete::new().delete_from("users"))
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum TransactionClause {
+  Begin,
+  Commit,
+  SetTransaction,
+}
+
+#[derive(Clone)]
+pub(crate) enum TransactionCommand {
+  RollbackTo(String),
+  Savepoint(String),
+  Statement(std::sync::Arc<dyn crate::behavior::TransactionQuery>),
+}
+
+/// Builder to contruct a [Truncate] command
+#[derive(Default, Clone)]
+pub struct Truncate {
+  pub(crate) _cascade: bool,
+  pub(crate) _raw_after: Vec<(TruncateClause, String)>,
+  pub(crate) _raw_before: Vec<(TruncateClause, String)>,
+  pub(crate) _raw: Vec<String>,
+  pub(crate) _restrict: bool,
+  pub(crate) _truncate: Vec<String>,
+
+  #[cfg(feature = "postgresql")]
+  pub(crate) _restart_identity: bool,
+}
+
+/// All available clauses to be used in `raw_before` and `raw_after` methods on [Truncate] builder
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let raw = "/* teardown */";
+/// let truncate = sql::Truncate::new()
+///   .raw_before(sql::TruncateClause::Truncate, raw)
+///   .truncate("users")
+///   .as_string();
+/// ```
+#[derive(PartialEq, Clone)]
+pub enum TruncateClause {
+  Truncate,
 }
 
 /// Builder to contruct a [Update] command
 #[derive(Default, Clone)]
 pub struct Update<'a> {
+  pub(crate) _params: Vec<Value>,
   pub(crate) _raw_after: Vec<(UpdateClause, String)>,
   pub(crate) _raw_before: Vec<(UpdateClause, String)>,
   pub(crate) _raw: Vec<String>,
   pub(crate) _set: Vec<String>,
-  pub(crate) _update: &'a str,
+  pub(crate) _update: Cow<'a, str>,
   pub(crate) _where: Vec<String>,
 
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub(crate) _from: Vec<String>,
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   pub(crate) _returning: Vec<String>,
-  #[cfg(feature = "postgresql")]
-  pub(crate) _with: Vec<(&'a str, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+  pub(crate) _with: Vec<(Cte<'a>, std::sync::Arc<dyn crate::behavior::WithQuery>)>,
+
+  #[cfg(feature = "sqlite")]
+  pub(crate) _update_or: Cow<'a, str>,
+
+  #[cfg(feature = "mysql")]
+  pub(crate) _limit: &'a str,
+  #[cfg(feature = "mysql")]
+  pub(crate) _order_by: Vec<String>,
+
+  #[cfg(feature = "mssql")]
+  pub(crate) _output: Vec<String>,
+  #[cfg(feature = "mssql")]
+  pub(crate) _table_hint: Vec<String>,
 }
 
 /// All available clauses to be used in `raw_before` and `raw_after` methods on [Update] builder
@@ -185,17 +1112,31 @@ pub enum UpdateClause {
   Update,
   Where,
 
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   From,
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   Returning,
-  #[cfg(feature = "postgresql")]
+  #[cfg(any(feature = "postgresql", feature = "sqlite"))]
   With,
+
+  #[cfg(feature = "sqlite")]
+  UpdateOr,
+
+  #[cfg(feature = "mysql")]
+  Limit,
+  #[cfg(feature = "mysql")]
+  OrderBy,
+
+  #[cfg(feature = "mssql")]
+  Output,
+  #[cfg(feature = "mssql")]
+  TableHint,
 }
 
 /// Builder to contruct a [Values] command
 #[derive(Default, Clone)]
 pub struct Values {
+  pub(crate) _params: Vec<Value>,
   pub(crate) _raw_after: Vec<(ValuesClause, String)>,
   pub(crate) _raw_before: Vec<(ValuesClause, String)>,
   pub(crate) _raw: Vec<String>,
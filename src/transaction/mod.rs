@@ -0,0 +1,2 @@
+mod transaction;
+mod transaction_internal;
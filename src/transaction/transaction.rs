@@ -0,0 +1,388 @@
+use crate::{
+  behavior::{push_unique, Concat, TransactionQuery},
+  fmt, placeholder,
+  structure::{Dialect, Error, IsolationLevel, Placeholder, Transaction, TransactionClause, TransactionCommand},
+  value::Value,
+};
+use std::collections::HashMap;
+
+impl Transaction {
+  /// Gets the current state of the [Transaction] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .statement(sql::Insert::new().insert_into("users (login)").values("('foo')"))
+  ///   .statement(sql::Delete::new().delete_from("sessions").where_clause("login = 'foo'"))
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// BEGIN; INSERT INTO users (login) VALUES ('foo'); DELETE FROM sessions WHERE login = 'foo'; COMMIT;
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// Gets the current state of the [Transaction] and returns it as string together with the bound values
+  /// of the statements, the placeholders of each statement are renumbered after the placeholders of the previous ones.
+  /// The database drivers can't send several statements together with parameters, the placeholders used in more than
+  /// one statement are returned as [Error::MultipleStatementsWithParams]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Transaction::new()
+  ///   .statement(sql::Delete::new().delete_from("sessions").where_clause("expired = true"))
+  ///   .statement(sql::Delete::new().delete_from("users").where_bind("login = $1", "foo"))
+  ///   .as_query()
+  ///   .unwrap();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "BEGIN; DELETE FROM sessions WHERE expired = true; DELETE FROM users WHERE login = $1; COMMIT;"
+  /// );
+  /// assert_eq!(params, vec![sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query(&self) -> Result<(String, Vec<Value>), Error> {
+    self.check_params()?;
+    Ok((self.as_string(), self.params()))
+  }
+
+  /// The same as [as_query](Transaction::as_query) method rendering the placeholders in the placeholder style,
+  /// using [Placeholder::QuestionMark] the values are repeated and reordered to follow the placeholders.
+  /// A positional placeholder without bound value is returned as [Error::UnboundPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Transaction::new()
+  ///   .statement(sql::Delete::new().delete_from("users").where_bind("id = $1", 42))
+  ///   .as_query_with(sql::Placeholder::QuestionMark)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "BEGIN; DELETE FROM users WHERE id = ?; COMMIT;");
+  /// assert_eq!(params, vec![sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_with(&self, style: Placeholder) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(self.as_query()?, &style)
+  }
+
+  /// The same as [as_query_with](Transaction::as_query_with) method resolving the named placeholders like `:login`,
+  /// each name is replaced by a positional placeholder numbered after the bound values
+  /// in the order they appear, a repeated name reuses the same position. A name not found in the map is returned
+  /// as [Error::UnknownPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use std::collections::HashMap;
+  /// use sql_query_builder as sql;
+  ///
+  /// let named = HashMap::from([("login", sql::Value::from("foo"))]);
+  /// let (query, params) = sql::Transaction::new()
+  ///   .statement(sql::Delete::new().delete_from("users").where_clause("login = :login or owner = :login"))
+  ///   .as_query_named(&named, sql::Placeholder::Dollar)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "BEGIN; DELETE FROM users WHERE login = $1 or owner = $1; COMMIT;"
+  /// );
+  /// assert_eq!(params, vec![sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query_named(
+    &self,
+    named: &HashMap<&str, Value>,
+    style: Placeholder,
+  ) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(placeholder::resolve_named(self.as_query()?, named)?, &style)
+  }
+
+  /// The same as [as_query](Transaction::as_query) method checking the clauses in use against the dialect like
+  /// [as_string_for](Transaction::as_string_for), the placeholders are rendered in the style of the dialect, `$1` for Postgres,
+  /// `?` for SQLite and MySQL and `@p1` for SQL Server, and the values follow the placeholders
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Transaction::new()
+  ///   .statement(sql::Delete::new().delete_from("users").where_bind("id = $1", 42))
+  ///   .as_query_for(sql::Dialect::MySql)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "BEGIN; DELETE FROM users WHERE id = ?; COMMIT;");
+  /// assert_eq!(params, vec![sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_for(&self, dialect: Dialect) -> Result<(String, Vec<Value>), Error> {
+    self.check_params()?;
+    crate::dialect::render_query(self, dialect)
+  }
+
+  /// The same as [as_string](Transaction::as_string) method checking the clauses in use, the transaction
+  /// and its statements, against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let transaction = sql::Transaction::new()
+  ///   .statement(sql::Delete::new().delete_from("sessions"));
+  ///
+  /// assert_eq!(
+  ///   transaction.as_string_for(sql::Dialect::Sqlite),
+  ///   Ok("BEGIN; DELETE FROM sessions; COMMIT;".to_owned())
+  /// );
+  /// assert!(transaction.as_string_for(sql::Dialect::MsSql).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The same as [as_string](Transaction::as_string) method rendering the placeholders in the placeholder style
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .statement(sql::Delete::new().delete_from("users").where_clause("id = $1"))
+  ///   .as_string_with(sql::Placeholder::At);
+  ///
+  /// assert_eq!(query, "BEGIN; DELETE FROM users WHERE id = @p1; COMMIT;");
+  /// ```
+  pub fn as_string_with(&self, style: Placeholder) -> String {
+    placeholder::convert(&self.as_string(), &style)
+  }
+
+  /// Prints the current state of the [Transaction] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let transaction = sql::Transaction::new()
+  ///   .statement(sql::Insert::new().insert_into("users (login)").values("('foo')"))
+  ///   .statement(sql::Delete::new().delete_from("sessions").where_clause("login = 'foo'"))
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// BEGIN;
+  /// INSERT INTO users (login)
+  /// VALUES
+  /// ('foo');
+  /// DELETE FROM sessions
+  /// WHERE login = 'foo';
+  /// COMMIT;
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Sets the isolation level of the transaction, rendered as a `SET TRANSACTION` statement after `BEGIN`.
+  /// This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .isolation_level(sql::IsolationLevel::Serializable)
+  ///   .statement(sql::Update::new().update("accounts").set("balance = balance - 10"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "BEGIN; SET TRANSACTION ISOLATION LEVEL SERIALIZABLE; UPDATE accounts SET balance = balance - 10; COMMIT;"
+  /// );
+  /// ```
+  pub fn isolation_level(mut self, isolation_level: IsolationLevel) -> Self {
+    self._isolation_level = Some(isolation_level);
+    self
+  }
+
+  /// Create Transaction's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [Transaction] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* the signup script */";
+  /// let transaction = sql::Transaction::new()
+  ///   .raw(raw_query)
+  ///   .statement(sql::Insert::new().insert_into("users (login)").values("('foo')"))
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* the signup script */ BEGIN; INSERT INTO users (login) VALUES ('foo'); COMMIT;
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "lock table users in exclusive mode;";
+  /// let transaction = sql::Transaction::new()
+  ///   .raw_after(sql::TransactionClause::Begin, raw)
+  ///   .statement(sql::Delete::new().delete_from("users"))
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// BEGIN; lock table users in exclusive mode; DELETE FROM users; COMMIT;
+  /// ```
+  pub fn raw_after(mut self, clause: TransactionClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "analyze users;";
+  /// let transaction = sql::Transaction::new()
+  ///   .statement(sql::Delete::new().delete_from("users"))
+  ///   .raw_before(sql::TransactionClause::Commit, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// BEGIN; DELETE FROM users; analyze users; COMMIT;
+  /// ```
+  pub fn raw_before(mut self, clause: TransactionClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Rolls back the statements executed after the savepoint, the statement is rendered in the call order
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .statement(sql::Insert::new().insert_into("users (login)").values("('foo')"))
+  ///   .savepoint("before_orders")
+  ///   .statement(sql::Delete::new().delete_from("orders"))
+  ///   .rollback_to("before_orders")
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "BEGIN; \
+  ///   INSERT INTO users (login) VALUES ('foo'); \
+  ///   SAVEPOINT before_orders; \
+  ///   DELETE FROM orders; \
+  ///   ROLLBACK TO SAVEPOINT before_orders; \
+  ///   COMMIT;"
+  /// );
+  /// ```
+  pub fn rollback_to(mut self, savepoint_name: &str) -> Self {
+    let command = TransactionCommand::RollbackTo(savepoint_name.trim().to_owned());
+    self._ordered_commands.push(command);
+    self
+  }
+
+  /// Defines a savepoint, the statement is rendered in the call order
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .savepoint("before_cleanup")
+  ///   .statement(sql::Delete::new().delete_from("sessions"))
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "BEGIN; SAVEPOINT before_cleanup; DELETE FROM sessions; COMMIT;");
+  /// ```
+  pub fn savepoint(mut self, savepoint_name: &str) -> Self {
+    let command = TransactionCommand::Savepoint(savepoint_name.trim().to_owned());
+    self._ordered_commands.push(command);
+    self
+  }
+
+  /// Adds a statement to the transaction, any builder of the crate can be used and the statements
+  /// are rendered in the call order
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let create_users = sql::CreateTable::new()
+  ///   .create_table("users")
+  ///   .column("login varchar(40)");
+  /// let insert_foo = sql::Insert::new()
+  ///   .insert_into("users (login)")
+  ///   .values("('foo')");
+  ///
+  /// let query = sql::Transaction::new()
+  ///   .statement(create_users)
+  ///   .statement(insert_foo)
+  ///   .as_string();
+  ///
+  /// assert_eq!(
+  ///   query,
+  ///   "BEGIN; CREATE TABLE users (login varchar(40)); INSERT INTO users (login) VALUES ('foo'); COMMIT;"
+  /// );
+  /// ```
+  pub fn statement(mut self, query: impl TransactionQuery + 'static) -> Self {
+    let command = TransactionCommand::Statement(std::sync::Arc::new(query));
+    self._ordered_commands.push(command);
+    self
+  }
+}
+
+impl std::fmt::Display for Transaction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Transaction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
@@ -0,0 +1,163 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods, TransactionQuery},
+  fmt,
+  placeholder::{self, Token},
+  structure::{Dialect, Error, IsolationLevel, Transaction, TransactionClause, TransactionCommand},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, TransactionClause> for Transaction {}
+
+impl Concat for Transaction {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_begin(query, fmts);
+    query = self.concat_set_transaction(query, fmts);
+    query = self.concat_ordered_commands(query, fmts);
+    query = self.concat_commit(query, fmts);
+
+    placeholder::resolve_nested(query.trim_end())
+  }
+
+  /// The bound values of the statements in the call order, the placeholders of each statement
+  /// are renumbered after the placeholders of the previous statements
+  fn params(&self) -> Vec<Value> {
+    self
+      ._ordered_commands
+      .iter()
+      .flat_map(|command| match command {
+        TransactionCommand::Statement(query) => query.params(),
+        TransactionCommand::RollbackTo(_) | TransactionCommand::Savepoint(_) => vec![],
+      })
+      .collect()
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(
+      dialect,
+      "BEGIN",
+      self._ordered_commands.is_empty() == false,
+      &[Postgres, Sqlite, MySql],
+    )?;
+    check(
+      dialect,
+      "SET TRANSACTION",
+      self._isolation_level.is_some() && self._ordered_commands.is_empty() == false,
+      &[Postgres],
+    )?;
+    for command in &self._ordered_commands {
+      if let TransactionCommand::Statement(query) = command {
+        check(
+          dialect,
+          "CONCURRENTLY IN TRANSACTION",
+          query.is_transaction_unsafe(),
+          &[],
+        )?;
+        query.validate(dialect)?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl Transaction {
+  /// Returns [Error::MultipleStatementsWithParams] when the placeholders belong to more than one statement
+  pub(crate) fn check_params(&self) -> Result<(), Error> {
+    let has_placeholders = |query: &dyn TransactionQuery| {
+      let sql = query.concat(&fmt::one_line());
+      placeholder::tokenize(&sql)
+        .iter()
+        .any(|token| matches!(token, Token::Positional(_) | Token::Named(_)))
+    };
+    let statements_with_params = self
+      ._ordered_commands
+      .iter()
+      .filter(|command| matches!(command, TransactionCommand::Statement(query) if has_placeholders(query.as_ref())))
+      .count();
+    if statements_with_params > 1 {
+      return Err(Error::MultipleStatementsWithParams);
+    }
+    Ok(())
+  }
+
+  fn concat_begin(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._ordered_commands.is_empty() == false {
+      format!("BEGIN;{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      TransactionClause::Begin,
+      sql,
+    )
+  }
+
+  fn concat_commit(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._ordered_commands.is_empty() == false {
+      format!("COMMIT;{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      TransactionClause::Commit,
+      sql,
+    )
+  }
+
+  fn concat_ordered_commands(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+
+    self._ordered_commands.iter().fold(query, |acc, command| {
+      let sql = match command {
+        TransactionCommand::RollbackTo(name) => format!("ROLLBACK TO SAVEPOINT{space}{name}"),
+        TransactionCommand::Savepoint(name) => format!("SAVEPOINT{space}{name}"),
+        TransactionCommand::Statement(query) => placeholder::nested(&query.concat(fmts)),
+      };
+
+      format!("{acc}{sql};{space}{lb}")
+    })
+  }
+
+  fn concat_set_transaction(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = match (self._isolation_level, self._ordered_commands.is_empty()) {
+      (Some(isolation_level), false) => {
+        let isolation_level = match isolation_level {
+          IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+          IsolationLevel::ReadCommitted => "READ COMMITTED",
+          IsolationLevel::RepeatableRead => "REPEATABLE READ",
+          IsolationLevel::Serializable => "SERIALIZABLE",
+        };
+        format!("SET TRANSACTION ISOLATION LEVEL{space}{isolation_level};{space}{lb}")
+      }
+      _ => "".to_owned(),
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      TransactionClause::SetTransaction,
+      sql,
+    )
+  }
+}
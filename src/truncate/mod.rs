@@ -0,0 +1,2 @@
+mod truncate;
+mod truncate_internal;
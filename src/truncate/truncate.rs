@@ -0,0 +1,247 @@
+use crate::{
+  behavior::{push_unique, Concat, TransactionQuery},
+  fmt,
+  structure::{Dialect, Error, Truncate, TruncateClause},
+};
+
+impl Truncate {
+  /// Gets the current state of the [Truncate] and returns it as string
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  /// ```sql
+  /// TRUNCATE TABLE users
+  /// ```
+  pub fn as_string(&self) -> String {
+    let fmts = fmt::one_line();
+    self.concat(&fmts)
+  }
+
+  /// The same as [as_string](Truncate::as_string) method checking the clauses in use against the dialect.
+  /// A clause the dialect doesn't support is returned as [Error::UnsupportedClause]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let truncate = sql::Truncate::new()
+  ///   .truncate("users");
+  ///
+  /// assert_eq!(
+  ///   truncate.as_string_for(sql::Dialect::MySql),
+  ///   Ok("TRUNCATE TABLE users".to_owned())
+  /// );
+  /// assert!(truncate.as_string_for(sql::Dialect::Sqlite).is_err());
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// Adds the `CASCADE` option, the tables that have foreign keys to the truncated tables are truncated too.
+  /// This method overrides the [restrict](Truncate::restrict) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .cascade()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "TRUNCATE TABLE users CASCADE");
+  /// ```
+  pub fn cascade(mut self) -> Self {
+    self._cascade = true;
+    self._restrict = false;
+    self
+  }
+
+  /// Prints the current state of the [Truncate] into console output in a more ease to read version.
+  /// This method is useful to debug complex queries or just to print the generated SQL while you type
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let truncate = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .truncate("orders")
+  ///   .debug();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users, orders
+  /// ```
+  pub fn debug(self) -> Self {
+    let fmts = fmt::multiline();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Create Truncate's instance
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Prints the current state of the [Truncate] into console output similar to debug method,
+  /// the difference is that this method prints in one line.
+  pub fn print(self) -> Self {
+    let fmts = fmt::one_line();
+    println!("{}", fmt::format(self.concat(&fmts), &fmts));
+    self
+  }
+
+  /// Adds at the beginning a raw SQL query.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw_query = "/* teardown */";
+  /// let truncate = sql::Truncate::new()
+  ///   .raw(raw_query)
+  ///   .truncate("users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* teardown */ TRUNCATE TABLE users
+  /// ```
+  pub fn raw(mut self, raw_sql: &str) -> Self {
+    push_unique(&mut self._raw, raw_sql.trim().to_owned());
+    self
+  }
+
+  /// Adds a raw SQL query after a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* teardown */";
+  /// let truncate = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .raw_after(sql::TruncateClause::Truncate, raw)
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// TRUNCATE TABLE users /* teardown */
+  /// ```
+  pub fn raw_after(mut self, clause: TruncateClause, raw_sql: &str) -> Self {
+    self._raw_after.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds a raw SQL query before a specified clause.
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let raw = "/* teardown */";
+  /// let truncate = sql::Truncate::new()
+  ///   .raw_before(sql::TruncateClause::Truncate, raw)
+  ///   .truncate("users")
+  ///   .as_string();
+  /// ```
+  ///
+  /// Output
+  ///
+  /// ```sql
+  /// /* teardown */ TRUNCATE TABLE users
+  /// ```
+  pub fn raw_before(mut self, clause: TruncateClause, raw_sql: &str) -> Self {
+    self._raw_before.push((clause, raw_sql.trim().to_owned()));
+    self
+  }
+
+  /// Adds the `RESTART IDENTITY` option, the sequences owned by the columns of the truncated tables are reset,
+  /// this method can be used enabling the feature flag `postgresql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .restart_identity()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "TRUNCATE TABLE users RESTART IDENTITY");
+  /// ```
+  #[cfg(any(doc, feature = "postgresql"))]
+  pub fn restart_identity(mut self) -> Self {
+    self._restart_identity = true;
+    self
+  }
+
+  /// Adds the `RESTRICT` option, the statement fails if other tables have foreign keys to the truncated tables.
+  /// This method overrides the [cascade](Truncate::cascade) option
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .restrict()
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "TRUNCATE TABLE users RESTRICT");
+  /// ```
+  pub fn restrict(mut self) -> Self {
+    self._restrict = true;
+    self._cascade = false;
+    self
+  }
+
+  /// The truncate clause, the table names are accumulated and truncated in the same statement.
+  /// Only PostgreSQL accepts more than one table
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Truncate::new()
+  ///   .truncate("users")
+  ///   .truncate("orders")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "TRUNCATE TABLE users, orders");
+  /// ```
+  pub fn truncate(mut self, table_name: &str) -> Self {
+    push_unique(&mut self._truncate, table_name.trim().to_owned());
+    self
+  }
+}
+
+impl TransactionQuery for Truncate {}
+
+impl std::fmt::Display for Truncate {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_string())
+  }
+}
+
+impl std::fmt::Debug for Truncate {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let fmts = fmt::multiline();
+    write!(f, "{}", fmt::format(self.concat(&fmts), &fmts))
+  }
+}
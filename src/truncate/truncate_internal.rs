@@ -0,0 +1,78 @@
+use crate::{
+  behavior::{concat_raw_before_after, Concat, ConcatMethods},
+  fmt,
+  structure::{Dialect, Error, Truncate, TruncateClause},
+  value::Value,
+};
+
+impl<'a> ConcatMethods<'a, TruncateClause> for Truncate {}
+
+impl Concat for Truncate {
+  fn concat(&self, fmts: &fmt::Formatter) -> String {
+    let mut query = "".to_owned();
+
+    query = self.concat_raw(query, fmts, &self._raw);
+    query = self.concat_truncate(query, fmts);
+
+    query.trim_end().to_owned()
+  }
+
+  fn params(&self) -> Vec<Value> {
+    vec![]
+  }
+
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use crate::dialect::check;
+    use Dialect::*;
+
+    check(
+      dialect,
+      "TRUNCATE",
+      self._truncate.is_empty() == false,
+      &[Postgres, MySql, MsSql],
+    )?;
+    check(dialect, "MULTIPLE TABLES", self._truncate.len() > 1, &[Postgres])?;
+    check(dialect, "CASCADE", self._cascade, &[Postgres])?;
+    check(dialect, "RESTRICT", self._restrict, &[Postgres])?;
+    #[cfg(feature = "postgresql")]
+    check(dialect, "RESTART IDENTITY", self._restart_identity, &[Postgres])?;
+
+    Ok(())
+  }
+}
+
+impl Truncate {
+  fn concat_truncate(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { comma, lb, space, .. } = fmts;
+    let sql = if self._truncate.is_empty() == false {
+      let table_names = self._truncate.join(comma);
+      #[cfg(feature = "postgresql")]
+      let restart_identity = if self._restart_identity {
+        format!("RESTART IDENTITY{space}")
+      } else {
+        "".to_owned()
+      };
+      #[cfg(not(feature = "postgresql"))]
+      let restart_identity = "";
+      let drop_behavior = if self._cascade {
+        format!("CASCADE{space}")
+      } else if self._restrict {
+        format!("RESTRICT{space}")
+      } else {
+        "".to_owned()
+      };
+      format!("TRUNCATE TABLE{space}{table_names}{space}{restart_identity}{drop_behavior}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      TruncateClause::Truncate,
+      sql,
+    )
+  }
+}
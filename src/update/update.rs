@@ -1,8 +1,12 @@
+#[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+use crate::structure::Cte;
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
-  structure::{Update, UpdateClause},
+  behavior::{bind, push_unique, trim, Concat, ExplainQuery, TransactionQuery, WithQuery},
+  fmt, placeholder,
+  structure::{Dialect, Error, Placeholder, Update, UpdateClause},
+  value::Value,
 };
+use std::{borrow::Cow, collections::HashMap};
 
 impl<'a> Update<'a> {
   /// The same as [where_clause](Update::where_clause) method, useful to write more idiomatic SQL query
@@ -22,6 +26,21 @@ impl<'a> Update<'a> {
     self
   }
 
+  /// The same as [where_bind](Update::where_bind) method, useful to write more idiomatic SQL query
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .where_bind("login = $1", "foo")
+  ///   .and_bind("active = $1", true);
+  /// ```
+  pub fn and_bind(mut self, condition: &str, value: impl Into<Value>) -> Self {
+    self = self.where_bind(condition, value);
+    self
+  }
+
   /// Gets the current state of the Update and returns it as string
   ///
   /// # Examples
@@ -43,6 +62,139 @@ impl<'a> Update<'a> {
     self.concat(&fmts)
   }
 
+  /// Gets the current state of the Update and returns it as string together with the bound values,
+  /// the value at index `n` of the list is the value of the placeholder `$n+1`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Update::new()
+  ///   .update("users")
+  ///   .set_bind("name = $1", "Foo")
+  ///   .where_bind("login = $1", "foo")
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "UPDATE users SET name = $1 WHERE login = $2");
+  /// assert_eq!(params, vec![sql::Value::from("Foo"), sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query(&self) -> (String, Vec<Value>) {
+    (self.as_string(), self.params())
+  }
+
+  /// The same as [as_query](Update::as_query) method rendering the placeholders in the placeholder style,
+  /// using [Placeholder::QuestionMark] the values are repeated and reordered to follow the placeholders.
+  /// A positional placeholder without bound value is returned as [Error::UnboundPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Update::new()
+  ///   .update("users")
+  ///   .where_bind("login = $1", "foo")
+  ///   .set_bind("name = $1", "Foo")
+  ///   .as_query_with(sql::Placeholder::QuestionMark)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "UPDATE users SET name = ? WHERE login = ?");
+  /// assert_eq!(params, vec![sql::Value::from("Foo"), sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query_with(&self, style: Placeholder) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(self.as_query(), &style)
+  }
+
+  /// The same as [as_query_with](Update::as_query_with) method resolving the named placeholders like `:login`,
+  /// each name is replaced by a positional placeholder numbered after the bound values
+  /// in the order they appear, a repeated name reuses the same position. A name not found in the map is returned
+  /// as [Error::UnknownPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use std::collections::HashMap;
+  /// use sql_query_builder as sql;
+  ///
+  /// let named = HashMap::from([("login", sql::Value::from("foo")), ("tenant", sql::Value::from(42))]);
+  /// let (query, params) = sql::Update::new()
+  ///   .update("users")
+  ///   .set("updated_by = :login")
+  ///   .where_clause("login = :login")
+  ///   .and("tenant_id = :tenant")
+  ///   .as_query_named(&named, sql::Placeholder::Dollar)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "UPDATE users SET updated_by = $1 WHERE login = $1 AND tenant_id = $2");
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_named(
+    &self,
+    named: &HashMap<&str, Value>,
+    style: Placeholder,
+  ) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(placeholder::resolve_named(self.as_query(), named)?, &style)
+  }
+
+  /// The same as [as_query](Update::as_query) method checking the clauses in use against the dialect like
+  /// [as_string_for](Update::as_string_for), the placeholders are rendered in the style of the dialect, `$1` for Postgres,
+  /// `?` for SQLite and MySQL and `@p1` for SQL Server, and the values follow the placeholders
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Update::new()
+  ///   .update("users")
+  ///   .where_bind("login = $1", "foo")
+  ///   .set_bind("name = $1", "Foo")
+  ///   .as_query_for(sql::Dialect::Sqlite)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "UPDATE users SET name = ? WHERE login = ?");
+  /// assert_eq!(params, vec![sql::Value::from("Foo"), sql::Value::from("foo")]);
+  /// ```
+  pub fn as_query_for(&self, dialect: Dialect) -> Result<(String, Vec<Value>), Error> {
+    crate::dialect::render_query(self, dialect)
+  }
+
+  /// The same as [as_string](Update::as_string) method checking the clauses in use, the builder and the nested builders,
+  /// against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause].
+  /// The placeholders are rendered in the style of the dialect, see [as_query_for](Update::as_query_for)
+  /// The dialect specific clauses are still enabled by the feature flags, so one binary can target many dialects
+  /// enabling all the feature flags it needs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("login = 'foo'")
+  ///   .as_string_for(sql::Dialect::MySql);
+  ///
+  /// assert_eq!(query, Ok("UPDATE users SET login = 'foo'".to_owned()));
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The same as [as_string](Update::as_string) method rendering the placeholders in the placeholder style
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("name = $1")
+  ///   .where_clause("login = $2")
+  ///   .as_string_with(sql::Placeholder::At);
+  ///
+  /// assert_eq!(query, "UPDATE users SET name = @p1 WHERE login = @p2");
+  /// ```
+  pub fn as_string_with(&self, style: Placeholder) -> String {
+    placeholder::convert(&self.as_string(), &style)
+  }
+
   /// Prints the current state of the Update into console output in a more ease to read version.
   /// This method is useful to debug complex queries or just to print the generated SQL while you type
   ///
@@ -70,18 +222,68 @@ impl<'a> Update<'a> {
     self
   }
 
-  /// The from clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(any(doc, feature = "postgresql"))]
+  /// The from clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
   pub fn from(mut self, tables: &str) -> Self {
     push_unique(&mut self._from, tables.trim().to_owned());
     self
   }
 
+  /// The limit clause, this method can be used enabling the feature flag `mysql`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .order_by("created_at")
+  ///   .limit("10")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "UPDATE users SET active = false ORDER BY created_at LIMIT 10");
+  /// ```
+  #[cfg(any(doc, feature = "mysql"))]
+  pub fn limit(mut self, num: &'a str) -> Self {
+    self._limit = num.trim();
+    self
+  }
+
   /// Create Update's instance
   pub fn new() -> Self {
     Self::default()
   }
 
+  /// The order by clause, this method can be used enabling the feature flag `mysql`
+  #[cfg(any(doc, feature = "mysql"))]
+  pub fn order_by(mut self, column: &str) -> Self {
+    push_unique(&mut self._order_by, column.trim().to_owned());
+    self
+  }
+
+  /// The output clause, this method can be used enabling the feature flag `mssql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .output("deleted.active")
+  ///   .output("inserted.active")
+  ///   .where_clause("login = 'foo'")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "UPDATE users SET active = false OUTPUT deleted.active, inserted.active WHERE login = 'foo'");
+  /// ```
+  #[cfg(any(doc, feature = "mssql"))]
+  pub fn output(mut self, output_name: &str) -> Self {
+    push_unique(&mut self._output, output_name.trim().to_owned());
+    self
+  }
+
   /// Prints the current state of the Update into console output similar to debug method,
   /// the difference is that this method prints in one line.
   pub fn print(self) -> Self {
@@ -162,8 +364,8 @@ impl<'a> Update<'a> {
     self
   }
 
-  /// The returning clause, this method can be used enabling the feature flag `postgresql`
-  #[cfg(any(doc, feature = "postgresql"))]
+  /// The returning clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
   pub fn returning(mut self, output_name: &str) -> Self {
     push_unique(&mut self._returning, output_name.trim().to_owned());
     self
@@ -175,6 +377,48 @@ impl<'a> Update<'a> {
     self
   }
 
+  /// The set clause with a bound value, the placeholder `$1` of the assignment refers to the value
+  /// and is renumbered after the values already bound
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Update::new()
+  ///   .update("users")
+  ///   .where_bind("login = $1", "foo")
+  ///   .set_bind("name = $1", "Foo")
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "UPDATE users SET name = $2 WHERE login = $1");
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from("Foo")]);
+  /// ```
+  pub fn set_bind(mut self, assignment: &str, value: impl Into<Value>) -> Self {
+    let assignment = bind(&mut self._params, assignment, [value.into()]);
+    push_unique(&mut self._set, assignment);
+    self
+  }
+
+  /// The table hint clause, this method can be used enabling the feature flag `mssql`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update("users")
+  ///   .table_hint("ROWLOCK")
+  ///   .set("active = false")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "UPDATE users WITH (ROWLOCK) SET active = false");
+  /// ```
+  #[cfg(any(doc, feature = "mssql"))]
+  pub fn table_hint(mut self, hint: &str) -> Self {
+    push_unique(&mut self._table_hint, hint.trim().to_owned());
+    self
+  }
+
   /// The update clause. This method overrides the previous value
   ///
   /// # Examples
@@ -187,9 +431,31 @@ impl<'a> Update<'a> {
   /// let update = sql::Update::new()
   ///   .update("address")
   ///   .update("orders");
+  ///
+  /// let update = sql::Update::new()
+  ///   .update(sql::quote_ident("Orders", sql::Dialect::Postgres));
   /// ```
-  pub fn update(mut self, table_name: &'a str) -> Self {
-    self._update = table_name.trim();
+  pub fn update(mut self, table_name: impl Into<Cow<'a, str>>) -> Self {
+    self._update = trim(table_name.into());
+    self
+  }
+
+  /// The update or clause, this method can be used enabling the feature flag `sqlite`. This method overrides the previous value
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Update::new()
+  ///   .update_or("IGNORE users")
+  ///   .set("login = 'foo'")
+  ///   .as_string();
+  ///
+  /// assert_eq!(query, "UPDATE OR IGNORE users SET login = 'foo'");
+  /// ```
+  #[cfg(any(doc, feature = "sqlite"))]
+  pub fn update_or(mut self, expression: impl Into<Cow<'a, str>>) -> Self {
+    self._update_or = trim(expression.into());
     self
   }
 
@@ -209,7 +475,25 @@ impl<'a> Update<'a> {
     self
   }
 
-  /// The with clause, this method can be used enabling the feature flag `postgresql`
+  /// The where clause with a bound value, the placeholder `$1` of the condition refers to the value
+  /// and is renumbered after the values already bound
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let update = sql::Update::new()
+  ///   .update("users")
+  ///   .set("active = false")
+  ///   .where_bind("login = $1", "foo");
+  /// ```
+  pub fn where_bind(mut self, condition: &str, value: impl Into<Value>) -> Self {
+    let condition = bind(&mut self._params, condition, [value.into()]);
+    push_unique(&mut self._where, condition);
+    self
+  }
+
+  /// The with clause, this method can be used enabling one of the feature flags `postgresql` or `sqlite`
   ///
   /// # Examples
   /// ```
@@ -239,13 +523,17 @@ impl<'a> Update<'a> {
   /// SET count = count + 1
   /// WHERE id = (select group_id from user)
   /// ```
-  #[cfg(any(doc, feature = "postgresql"))]
-  pub fn with(mut self, name: &'a str, query: impl WithQuery + 'static) -> Self {
-    self._with.push((name.trim(), std::sync::Arc::new(query)));
+  #[cfg(any(doc, feature = "postgresql", feature = "sqlite"))]
+  pub fn with(mut self, name: impl Into<Cte<'a>>, query: impl WithQuery + 'static) -> Self {
+    self._with.push((name.into(), std::sync::Arc::new(query)));
     self
   }
 }
 
+impl ExplainQuery for Update<'_> {}
+
+impl TransactionQuery for Update<'_> {}
+
 impl WithQuery for Update<'_> {}
 
 impl std::fmt::Display for Update<'_> {
@@ -1,7 +1,8 @@
 use crate::{
   behavior::{concat_raw_before_after, Concat, ConcatMethods},
-  fmt,
-  structure::{Update, UpdateClause},
+  fmt, placeholder,
+  structure::{Dialect, Error, Update, UpdateClause},
+  value::Value,
 };
 
 impl<'a> ConcatMethods<'a, UpdateClause> for Update<'_> {}
@@ -10,27 +11,53 @@ impl Concat for Update<'_> {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_owned();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
-    #[cfg(feature = "postgresql")]
+    query = self.concat_raw(query, fmts, &self._raw);
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_with(
         &self._raw_before,
         &self._raw_after,
         query,
-        &fmts,
+        fmts,
         UpdateClause::With,
         &self._with,
       );
     }
-    query = self.concat_update(query, &fmts);
-    query = self.concat_set(query, &fmts);
-    #[cfg(feature = "postgresql")]
+    query = self.concat_update(query, fmts);
+    #[cfg(feature = "sqlite")]
+    {
+      query = self.concat_update_or(query, fmts);
+    }
+    #[cfg(feature = "mssql")]
+    {
+      query = self.concat_table_hint(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        UpdateClause::TableHint,
+        &self._table_hint,
+      );
+    }
+    query = self.concat_set(query, fmts);
+    #[cfg(feature = "mssql")]
+    {
+      query = self.concat_output(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        UpdateClause::Output,
+        &self._output,
+      );
+    }
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_from(
         &self._raw_before,
         &self._raw_after,
         query,
-        &fmts,
+        fmts,
         UpdateClause::From,
         &self._from,
       );
@@ -39,24 +66,96 @@ impl Concat for Update<'_> {
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       UpdateClause::Where,
       &self._where,
     );
+    #[cfg(feature = "mysql")]
+    {
+      query = self.concat_order_by(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        UpdateClause::OrderBy,
+        &self._order_by,
+      );
+      query = self.concat_limit(
+        &self._raw_before,
+        &self._raw_after,
+        query,
+        fmts,
+        UpdateClause::Limit,
+        self._limit,
+      );
+    }
 
-    #[cfg(feature = "postgresql")]
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
     {
       query = self.concat_returning(
         &self._raw_before,
         &self._raw_after,
         query,
-        &fmts,
+        fmts,
         UpdateClause::Returning,
         &self._returning,
       );
     }
 
-    query.trim_end().to_owned()
+    placeholder::resolve_nested(query.trim_end())
+  }
+
+  fn params(&self) -> Vec<Value> {
+    let params = self._params.iter().cloned();
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    let params = params.chain(self._with.iter().flat_map(|(_, query)| query.params()));
+    params.collect()
+  }
+
+  #[cfg(not(any(feature = "postgresql", feature = "sqlite", feature = "mysql", feature = "mssql")))]
+  fn validate(&self, _dialect: Dialect) -> Result<(), Error> {
+    Ok(())
+  }
+
+  #[cfg(any(feature = "postgresql", feature = "sqlite", feature = "mysql", feature = "mssql"))]
+  fn validate(&self, dialect: Dialect) -> Result<(), Error> {
+    use {crate::dialect::check, Dialect::*};
+
+    #[cfg(any(feature = "postgresql", feature = "sqlite"))]
+    {
+      check(
+        dialect,
+        "FROM",
+        self._from.is_empty() == false,
+        &[Postgres, Sqlite, MsSql],
+      )?;
+      check(
+        dialect,
+        "RETURNING",
+        self._returning.is_empty() == false,
+        &[Postgres, Sqlite],
+      )?;
+      for (cte, query) in self._with.iter() {
+        cte.validate(dialect)?;
+        query.validate(dialect)?;
+      }
+    }
+    #[cfg(feature = "sqlite")]
+    {
+      check(dialect, "UPDATE OR", self._update_or.is_empty() == false, &[Sqlite])?;
+    }
+    #[cfg(feature = "mysql")]
+    {
+      check(dialect, "LIMIT", self._limit.is_empty() == false, &[MySql])?;
+      check(dialect, "ORDER BY", self._order_by.is_empty() == false, &[MySql])?;
+    }
+    #[cfg(feature = "mssql")]
+    {
+      check(dialect, "OUTPUT", self._output.is_empty() == false, &[MsSql])?;
+      check(dialect, "TABLE HINT", self._table_hint.is_empty() == false, &[MsSql])?;
+    }
+
+    Ok(())
   }
 }
 
@@ -73,10 +172,30 @@ impl Update<'_> {
     concat_raw_before_after(&self._raw_before, &self._raw_after, query, fmts, UpdateClause::Set, sql)
   }
 
+  #[cfg(feature = "sqlite")]
+  fn concat_update_or(&self, query: String, fmts: &fmt::Formatter) -> String {
+    let fmt::Formatter { lb, space, .. } = fmts;
+    let sql = if self._update_or.is_empty() == false {
+      let update_or = &self._update_or;
+      format!("UPDATE OR{space}{update_or}{space}{lb}")
+    } else {
+      "".to_owned()
+    };
+
+    concat_raw_before_after(
+      &self._raw_before,
+      &self._raw_after,
+      query,
+      fmts,
+      UpdateClause::UpdateOr,
+      sql,
+    )
+  }
+
   fn concat_update(&self, query: String, fmts: &fmt::Formatter) -> String {
     let fmt::Formatter { lb, space, .. } = fmts;
     let sql = if self._update.is_empty() == false {
-      let table_name = self._update;
+      let table_name = &self._update;
       format!("UPDATE{space}{table_name}{space}{lb}")
     } else {
       "".to_owned()
@@ -0,0 +1,112 @@
+/// A value bound to a placeholder of the query, see the `*_bind` methods of the builders
+///
+/// # Examples
+/// ```
+/// use sql_query_builder as sql;
+///
+/// let (query, params) = sql::Select::new()
+///   .select("*")
+///   .from("users")
+///   .where_bind("login = $1", "foo")
+///   .and_bind("age > $1", 18)
+///   .as_query();
+///
+/// assert_eq!(query, "SELECT * FROM users WHERE login = $1 AND age > $2");
+/// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from(18)]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+  Null,
+  Bool(bool),
+  Int(i64),
+  Float(f64),
+  Text(String),
+  Bytes(Vec<u8>),
+}
+
+impl From<bool> for Value {
+  fn from(value: bool) -> Self {
+    Self::Bool(value)
+  }
+}
+
+impl From<i8> for Value {
+  fn from(value: i8) -> Self {
+    Self::Int(value.into())
+  }
+}
+
+impl From<i16> for Value {
+  fn from(value: i16) -> Self {
+    Self::Int(value.into())
+  }
+}
+
+impl From<i32> for Value {
+  fn from(value: i32) -> Self {
+    Self::Int(value.into())
+  }
+}
+
+impl From<i64> for Value {
+  fn from(value: i64) -> Self {
+    Self::Int(value)
+  }
+}
+
+impl From<u8> for Value {
+  fn from(value: u8) -> Self {
+    Self::Int(value.into())
+  }
+}
+
+impl From<u16> for Value {
+  fn from(value: u16) -> Self {
+    Self::Int(value.into())
+  }
+}
+
+impl From<u32> for Value {
+  fn from(value: u32) -> Self {
+    Self::Int(value.into())
+  }
+}
+
+impl From<f32> for Value {
+  fn from(value: f32) -> Self {
+    Self::Float(value.into())
+  }
+}
+
+impl From<f64> for Value {
+  fn from(value: f64) -> Self {
+    Self::Float(value)
+  }
+}
+
+impl From<&str> for Value {
+  fn from(value: &str) -> Self {
+    Self::Text(value.to_owned())
+  }
+}
+
+impl From<String> for Value {
+  fn from(value: String) -> Self {
+    Self::Text(value)
+  }
+}
+
+impl From<Vec<u8>> for Value {
+  fn from(value: Vec<u8>) -> Self {
+    Self::Bytes(value)
+  }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+  fn from(value: Option<T>) -> Self {
+    match value {
+      Some(value) => value.into(),
+      None => Self::Null,
+    }
+  }
+}
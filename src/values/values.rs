@@ -1,8 +1,10 @@
 use crate::{
-  behavior::{push_unique, Concat, WithQuery},
-  fmt,
-  structure::{Values, ValuesClause},
+  behavior::{bind, push_unique, Concat, ExplainQuery, TransactionQuery, UsingQuery, WithQuery},
+  fmt, placeholder,
+  structure::{Dialect, Error, Placeholder, Values, ValuesClause},
+  value::Value,
 };
+use std::collections::HashMap;
 
 impl Values {
   /// Gets the current state of the Values and returns it as string
@@ -25,6 +27,127 @@ impl Values {
     self.concat(&fmts)
   }
 
+  /// Gets the current state of the Values and returns it as string together with the bound values,
+  /// the value at index `n` of the list is the value of the placeholder `$n+1`
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Values::new()
+  ///   .values_bind("($1, $2)", [sql::Value::from(1), sql::Value::from("one")])
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "VALUES ($1, $2)");
+  /// assert_eq!(params, vec![sql::Value::from(1), sql::Value::from("one")]);
+  /// ```
+  pub fn as_query(&self) -> (String, Vec<Value>) {
+    (self.as_string(), self.params())
+  }
+
+  /// The same as [as_query](Values::as_query) method rendering the placeholders in the placeholder style,
+  /// using [Placeholder::QuestionMark] the values are repeated and reordered to follow the placeholders.
+  /// A positional placeholder without bound value is returned as [Error::UnboundPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Values::new()
+  ///   .values_bind("($1, $2)", [sql::Value::from(1), sql::Value::from("one")])
+  ///   .as_query_with(sql::Placeholder::QuestionMark)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "VALUES (?, ?)");
+  /// assert_eq!(params, vec![sql::Value::from(1), sql::Value::from("one")]);
+  /// ```
+  pub fn as_query_with(&self, style: Placeholder) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(self.as_query(), &style)
+  }
+
+  /// The same as [as_query_with](Values::as_query_with) method resolving the named placeholders like `:login`,
+  /// each name is replaced by a positional placeholder numbered after the bound values
+  /// in the order they appear, a repeated name reuses the same position. A name not found in the map is returned
+  /// as [Error::UnknownPlaceholder]
+  ///
+  /// # Examples
+  /// ```
+  /// use std::collections::HashMap;
+  /// use sql_query_builder as sql;
+  ///
+  /// let named = HashMap::from([("login", sql::Value::from("foo")), ("tenant", sql::Value::from(42))]);
+  /// let (query, params) = sql::Values::new()
+  ///   .values("(:login, :tenant)")
+  ///   .as_query_named(&named, sql::Placeholder::Dollar)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "VALUES ($1, $2)");
+  /// assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from(42)]);
+  /// ```
+  pub fn as_query_named(
+    &self,
+    named: &HashMap<&str, Value>,
+    style: Placeholder,
+  ) -> Result<(String, Vec<Value>), Error> {
+    placeholder::convert_query(placeholder::resolve_named(self.as_query(), named)?, &style)
+  }
+
+  /// The same as [as_query](Values::as_query) method checking the clauses in use against the dialect like
+  /// [as_string_for](Values::as_string_for), the placeholders are rendered in the style of the dialect, `$1` for Postgres,
+  /// `?` for SQLite and MySQL and `@p1` for SQL Server, and the values follow the placeholders
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Values::new()
+  ///   .values_bind("($1, $2)", [sql::Value::from(1), sql::Value::from("one")])
+  ///   .as_query_for(sql::Dialect::Postgres)
+  ///   .unwrap();
+  ///
+  /// assert_eq!(query, "VALUES ($1, $2)");
+  /// assert_eq!(params, vec![sql::Value::from(1), sql::Value::from("one")]);
+  /// ```
+  pub fn as_query_for(&self, dialect: Dialect) -> Result<(String, Vec<Value>), Error> {
+    crate::dialect::render_query(self, dialect)
+  }
+
+  /// The same as [as_string](Values::as_string) method checking the clauses in use, the builder and the nested builders,
+  /// against the dialect. A clause the dialect doesn't support is returned as [Error::UnsupportedClause].
+  /// The placeholders are rendered in the style of the dialect, see [as_query_for](Values::as_query_for)
+  /// The dialect specific clauses are still enabled by the feature flags, so one binary can target many dialects
+  /// enabling all the feature flags it needs
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Values::new()
+  ///   .values("(1, 'one')")
+  ///   .as_string_for(sql::Dialect::Sqlite);
+  ///
+  /// assert_eq!(query, Ok("VALUES (1, 'one')".to_owned()));
+  /// ```
+  pub fn as_string_for(&self, dialect: Dialect) -> Result<String, Error> {
+    crate::dialect::render(self, dialect)
+  }
+
+  /// The same as [as_string](Values::as_string) method rendering the placeholders in the placeholder style
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let query = sql::Values::new()
+  ///   .values("($1, $2)")
+  ///   .as_string_with(sql::Placeholder::At);
+  ///
+  /// assert_eq!(query, "VALUES (@p1, @p2)");
+  /// ```
+  pub fn as_string_with(&self, style: Placeholder) -> String {
+    placeholder::convert(&self.as_string(), &style)
+  }
+
   /// Prints the current state of the Values into console output in a more ease to read version.
   /// This method is useful to debug complex queries or just to print the generated SQL while you type
   ///
@@ -147,8 +270,34 @@ impl Values {
     push_unique(&mut self._values, expression.trim().to_owned());
     self
   }
+
+  /// The values clause with bound values, the placeholders `$1`, `$2`, ... of the expression refer to
+  /// the values in the same order and are renumbered after the values already bound
+  ///
+  /// # Examples
+  /// ```
+  /// use sql_query_builder as sql;
+  ///
+  /// let (query, params) = sql::Values::new()
+  ///   .values_bind("($1, $2)", [sql::Value::from("foo"), sql::Value::from("Foo")])
+  ///   .values_bind("($1, $2)", [sql::Value::from("bar"), sql::Value::from("Bar")])
+  ///   .as_query();
+  ///
+  /// assert_eq!(query, "VALUES ($1, $2), ($3, $4)");
+  /// ```
+  pub fn values_bind(mut self, expression: &str, values: impl IntoIterator<Item = Value>) -> Self {
+    let expression = bind(&mut self._params, expression, values);
+    push_unique(&mut self._values, expression);
+    self
+  }
 }
 
+impl ExplainQuery for Values {}
+
+impl TransactionQuery for Values {}
+
+impl UsingQuery for Values {}
+
 impl WithQuery for Values {}
 
 impl std::fmt::Display for Values {
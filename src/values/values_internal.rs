@@ -1,7 +1,8 @@
 use crate::{
   behavior::{Concat, ConcatMethods},
   fmt,
-  structure::{Values, ValuesClause},
+  structure::{Dialect, Error, Values, ValuesClause},
+  value::Value,
 };
 
 impl<'a> ConcatMethods<'a, ValuesClause> for Values {}
@@ -10,16 +11,24 @@ impl Concat for Values {
   fn concat(&self, fmts: &fmt::Formatter) -> String {
     let mut query = "".to_owned();
 
-    query = self.concat_raw(query, &fmts, &self._raw);
+    query = self.concat_raw(query, fmts, &self._raw);
     query = self.concat_values(
       &self._raw_before,
       &self._raw_after,
       query,
-      &fmts,
+      fmts,
       ValuesClause::Values,
       &self._values,
     );
 
     query.trim_end().to_owned()
   }
+
+  fn params(&self) -> Vec<Value> {
+    self._params.clone()
+  }
+
+  fn validate(&self, _dialect: Dialect) -> Result<(), Error> {
+    Ok(())
+  }
 }
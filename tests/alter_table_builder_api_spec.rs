@@ -0,0 +1,677 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::AlterTable::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age int")
+      .debug()
+      .as_string();
+    let expected_query = "ALTER TABLE users ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age int")
+      .print()
+      .as_string();
+    let expected_query = "ALTER TABLE users ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::AlterTable::new()
+      .raw("alter table users")
+      .add_column("age int")
+      .as_string();
+    let expected_query = "alter table users ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .raw("/* the users table */")
+      .as_string();
+    let expected_query = "/* the users table */ ALTER TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::AlterTable::new()
+      .raw_after(sql::AlterTableClause::AlterTable, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::AlterTable::new()
+      .raw_before(sql::AlterTableClause::AlterTable, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_alter_column_action_in_sqlite() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .alter_column_drop_default("active");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "ALTER COLUMN DROP DEFAULT",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(alter_table.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(alter_table.as_string_for(sql::Dialect::MySql).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_constraint_actions_in_sqlite() {
+    let add_constraint = sql::AlterTable::new().add_constraint("users_age_check check (age > 0)");
+    let drop_constraint = sql::AlterTable::new().drop_constraint("users_age_check");
+
+    assert_eq!(
+      add_constraint.as_string_for(sql::Dialect::Sqlite),
+      Err(sql::Error::UnsupportedClause {
+        clause: "ADD CONSTRAINT",
+        dialect: sql::Dialect::Sqlite,
+      })
+    );
+    assert_eq!(
+      drop_constraint.as_string_for(sql::Dialect::Sqlite),
+      Err(sql::Error::UnsupportedClause {
+        clause: "DROP CONSTRAINT",
+        dialect: sql::Dialect::Sqlite,
+      })
+    );
+  }
+
+  #[test]
+  fn method_as_string_for_should_accept_the_column_actions_in_sqlite() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("login", "username")
+      .as_string_for(sql::Dialect::Sqlite);
+    let expected_query = Ok("ALTER TABLE users RENAME COLUMN login TO username".to_owned());
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_multiple_actions_in_sqlite() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age int")
+      .drop_column("birthday");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "MULTIPLE ACTIONS",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(alter_table.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(alter_table.as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_accept_the_alter_column_type_action_only_in_postgres() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .alter_column_type("login", "text");
+
+    assert!(alter_table.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert_eq!(
+      alter_table.as_string_for(sql::Dialect::MySql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "ALTER COLUMN TYPE",
+        dialect: sql::Dialect::MySql,
+      })
+    );
+    assert_eq!(
+      alter_table.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "ALTER COLUMN TYPE",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_default_actions_in_mssql() {
+    let set_default = sql::AlterTable::new().alter_column_set_default("active", "1");
+    let drop_default = sql::AlterTable::new().alter_column_drop_default("active");
+
+    assert_eq!(
+      set_default.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "ALTER COLUMN SET DEFAULT",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+    assert_eq!(
+      drop_default.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "ALTER COLUMN DROP DEFAULT",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_add_column_and_rename_actions_in_mssql() {
+    let add_column = sql::AlterTable::new().add_column("age int");
+    let rename_column = sql::AlterTable::new().rename_column("login", "username");
+    let rename_to = sql::AlterTable::new().rename_to("accounts");
+
+    assert_eq!(
+      add_column.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "ADD COLUMN",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+    assert_eq!(
+      rename_column.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "RENAME COLUMN",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+    assert_eq!(
+      rename_to.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "RENAME TO",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_rename_with_other_actions_in_postgres() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .add_column("age int")
+      .rename_column("login", "username");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "RENAME WITH OTHER ACTIONS",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(alter_table.as_string_for(sql::Dialect::Postgres), expected_error);
+    assert!(alter_table.as_string_for(sql::Dialect::MySql).is_ok());
+  }
+}
+
+mod alter_table_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_alter_table_should_add_the_alter_table_clause() {
+    let query = sql::AlterTable::new().alter_table("users").as_string();
+    let expected_query = "ALTER TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_alter_table_should_override_value_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .alter_table("orders")
+      .as_string();
+    let expected_query = "ALTER TABLE orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_alter_table_should_trim_space_of_the_argument() {
+    let query = sql::AlterTable::new().alter_table("  users  ").as_string();
+    let expected_query = "ALTER TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_alter_table_should_accept_owned_quoted_names() {
+    let query = sql::AlterTable::new()
+      .alter_table(sql::quote_ident("Users", sql::Dialect::MySql))
+      .as_string();
+    let expected_query = "ALTER TABLE `Users`";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_alter_table_clause() {
+    let query = sql::AlterTable::new()
+      .raw_before(sql::AlterTableClause::AlterTable, "/* the users table */")
+      .alter_table("users")
+      .as_string();
+    let expected_query = "/* the users table */ ALTER TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_alter_table_clause() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .raw_after(sql::AlterTableClause::AlterTable, "add column age int")
+      .as_string();
+    let expected_query = "ALTER TABLE users add column age int";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod add_column_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_add_column_should_add_the_add_column_action() {
+    let query = sql::AlterTable::new().add_column("age int").as_string();
+    let expected_query = "ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_add_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .add_column("age int")
+      .add_column("login varchar(40)")
+      .as_string();
+    let expected_query = "ADD COLUMN age int, ADD COLUMN login varchar(40)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_add_column_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::AlterTable::new()
+      .add_column("age int")
+      .add_column("age int")
+      .as_string();
+    let expected_query = "ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_add_column_should_trim_space_of_the_argument() {
+    let query = sql::AlterTable::new().add_column("  age int  ").as_string();
+    let expected_query = "ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_add_column_should_be_after_alter_table_clause() {
+    let query = sql::AlterTable::new()
+      .add_column("age int")
+      .alter_table("users")
+      .as_string();
+    let expected_query = "ALTER TABLE users ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_add_column_clause() {
+    let query = sql::AlterTable::new()
+      .raw_before(sql::AlterTableClause::AddColumn, "/* new columns */")
+      .add_column("age int")
+      .as_string();
+    let expected_query = "/* new columns */ ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_add_column_clause() {
+    let query = sql::AlterTable::new()
+      .add_column("age int")
+      .raw_after(sql::AlterTableClause::AddColumn, "default 18")
+      .as_string();
+    let expected_query = "ADD COLUMN age int default 18";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod alter_column_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_alter_column_type_should_add_the_alter_column_action() {
+    let query = sql::AlterTable::new()
+      .alter_column_type("login", "varchar(80)")
+      .as_string();
+    let expected_query = "ALTER COLUMN login TYPE varchar(80)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_alter_column_set_default_should_add_the_alter_column_action() {
+    let query = sql::AlterTable::new()
+      .alter_column_set_default("active", "true")
+      .as_string();
+    let expected_query = "ALTER COLUMN active SET DEFAULT true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_alter_column_drop_default_should_add_the_alter_column_action() {
+    let query = sql::AlterTable::new().alter_column_drop_default("active").as_string();
+    let expected_query = "ALTER COLUMN active DROP DEFAULT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_alter_column_should_accumulate_values_in_the_call_order() {
+    let query = sql::AlterTable::new()
+      .alter_column_type("age", "bigint")
+      .alter_column_drop_default("active")
+      .alter_column_set_default("login", "'guest'")
+      .as_string();
+    let expected_query = "\
+      ALTER COLUMN age TYPE bigint, \
+      ALTER COLUMN active DROP DEFAULT, \
+      ALTER COLUMN login SET DEFAULT 'guest'\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_alter_column_should_trim_space_of_the_arguments() {
+    let query = sql::AlterTable::new()
+      .alter_column_set_default("  active  ", "  true  ")
+      .as_string();
+    let expected_query = "ALTER COLUMN active SET DEFAULT true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_alter_column_should_follow_the_call_order_of_the_actions() {
+    let query = sql::AlterTable::new()
+      .alter_column_type("age", "bigint")
+      .add_column("login varchar(40)")
+      .as_string();
+    let expected_query = "ALTER COLUMN age TYPE bigint, ADD COLUMN login varchar(40)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_alter_column_clause() {
+    let query = sql::AlterTable::new()
+      .add_column("age int")
+      .raw_before(sql::AlterTableClause::AlterColumn, "/* changes */")
+      .alter_column_type("login", "text")
+      .as_string();
+    let expected_query = "ADD COLUMN age int, /* changes */ ALTER COLUMN login TYPE text";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_alter_column_clause() {
+    let query = sql::AlterTable::new()
+      .alter_column_type("login", "text")
+      .raw_after(sql::AlterTableClause::AlterColumn, "using login::text")
+      .as_string();
+    let expected_query = "ALTER COLUMN login TYPE text using login::text";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod drop_column_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_column_should_add_the_drop_column_action() {
+    let query = sql::AlterTable::new().drop_column("birthday").as_string();
+    let expected_query = "DROP COLUMN birthday";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .drop_column("birthday")
+      .drop_column("nickname")
+      .as_string();
+    let expected_query = "DROP COLUMN birthday, DROP COLUMN nickname";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_drop_column_should_follow_the_call_order_of_the_actions() {
+    let query = sql::AlterTable::new()
+      .drop_column("age")
+      .add_column("age int")
+      .as_string();
+    let expected_query = "DROP COLUMN age, ADD COLUMN age int";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_the_last_drop_column_action() {
+    let query = sql::AlterTable::new()
+      .drop_column("birthday")
+      .add_column("age int")
+      .drop_column("nickname")
+      .raw_after(sql::AlterTableClause::DropColumn, "cascade")
+      .as_string();
+    let expected_query = "DROP COLUMN birthday, ADD COLUMN age int, DROP COLUMN nickname cascade";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_drop_column_clause() {
+    let query = sql::AlterTable::new()
+      .drop_column("birthday")
+      .raw_after(sql::AlterTableClause::DropColumn, "cascade")
+      .as_string();
+    let expected_query = "DROP COLUMN birthday cascade";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod constraint_clauses {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_add_constraint_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .add_constraint("users_login_key unique (login)")
+      .add_constraint("users_age_check check (age > 0)")
+      .as_string();
+    let expected_query = "\
+      ADD CONSTRAINT users_login_key unique (login), \
+      ADD CONSTRAINT users_age_check check (age > 0)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_constraint_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .drop_constraint("users_login_key")
+      .drop_constraint("users_age_check")
+      .as_string();
+    let expected_query = "DROP CONSTRAINT users_login_key, DROP CONSTRAINT users_age_check";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clauses_constraint_should_follow_the_call_order_of_the_actions() {
+    let query = sql::AlterTable::new()
+      .drop_constraint("users_login_key")
+      .drop_column("nickname")
+      .alter_column_type("login", "text")
+      .add_constraint("users_login_key unique (login, tenant)")
+      .as_string();
+    let expected_query = "\
+      DROP CONSTRAINT users_login_key, \
+      DROP COLUMN nickname, \
+      ALTER COLUMN login TYPE text, \
+      ADD CONSTRAINT users_login_key unique (login, tenant)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_drop_constraint_clause() {
+    let query = sql::AlterTable::new()
+      .drop_constraint("users_login_key")
+      .raw_after(sql::AlterTableClause::DropConstraint, "cascade")
+      .as_string();
+    let expected_query = "DROP CONSTRAINT users_login_key cascade";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_add_constraint_clause() {
+    let query = sql::AlterTable::new()
+      .raw_before(sql::AlterTableClause::AddConstraint, "/* keys */")
+      .add_constraint("users_login_key unique (login)")
+      .as_string();
+    let expected_query = "/* keys */ ADD CONSTRAINT users_login_key unique (login)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod rename_clauses {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_rename_column_should_add_the_rename_column_action() {
+    let query = sql::AlterTable::new()
+      .rename_column("  login  ", "  username  ")
+      .as_string();
+    let expected_query = "RENAME COLUMN login TO username";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_rename_column_should_accumulate_values_on_consecutive_calls() {
+    let alter_table = sql::AlterTable::new()
+      .alter_table("users")
+      .rename_column("login", "username")
+      .rename_column("mail", "email");
+    let expected_query =
+      Ok("ALTER TABLE users RENAME COLUMN login TO username, RENAME COLUMN mail TO email".to_owned());
+
+    assert_eq!(alter_table.as_string_for(sql::Dialect::MySql), expected_query);
+    assert_eq!(
+      alter_table.as_string_for(sql::Dialect::Postgres),
+      Err(sql::Error::UnsupportedClause {
+        clause: "RENAME WITH OTHER ACTIONS",
+        dialect: sql::Dialect::Postgres,
+      })
+    );
+  }
+
+  #[test]
+  fn method_rename_to_should_add_the_rename_to_action() {
+    let query = sql::AlterTable::new().rename_to("accounts").as_string();
+    let expected_query = "RENAME TO accounts";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_rename_to_should_override_value_on_consecutive_calls() {
+    let query = sql::AlterTable::new()
+      .rename_to("accounts")
+      .rename_to("members")
+      .as_string();
+    let expected_query = "RENAME TO members";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clauses_rename_should_follow_the_call_order_of_the_actions() {
+    let query = sql::AlterTable::new()
+      .rename_to("accounts")
+      .rename_column("login", "username")
+      .alter_table("users")
+      .as_string();
+    let expected_query = "ALTER TABLE users RENAME TO accounts, RENAME COLUMN login TO username";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_rename_to_clause() {
+    let query = sql::AlterTable::new()
+      .alter_table("users")
+      .raw_before(sql::AlterTableClause::RenameTo, "/* rename */")
+      .rename_to("accounts")
+      .as_string();
+    let expected_query = "ALTER TABLE users /* rename */ RENAME TO accounts";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,108 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn alter_table_builder_should_be_displayable() {
+  let alter_table = sql::AlterTable::new().alter_table("users").add_column("age int");
+
+  println!("{}", alter_table);
+
+  let query = alter_table.as_string();
+  let expected_query = "ALTER TABLE users ADD COLUMN age int";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn alter_table_builder_should_be_debuggable() {
+  let alter_table = sql::AlterTable::new()
+    .alter_table("users")
+    .drop_column("birthday")
+    .add_column("age int");
+
+  println!("{:?}", alter_table);
+
+  let expected_query = "ALTER TABLE users DROP COLUMN birthday, ADD COLUMN age int";
+  let query = alter_table.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn alter_table_builder_should_be_cloneable() {
+  let alter_users = sql::AlterTable::new()
+    .raw("/* test raw */")
+    .raw_before(sql::AlterTableClause::AlterTable, "/* test raw_before */")
+    .alter_table("users")
+    .raw_after(sql::AlterTableClause::AlterTable, "/* test raw_after */")
+    .add_column("age int");
+
+  let alter_users_with_login = alter_users.clone().add_column("login varchar(40)");
+
+  let query_users = alter_users.as_string();
+  let query_users_with_login = alter_users_with_login.as_string();
+
+  let expected_query_users = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    ALTER TABLE users \
+    /* test raw_after */ \
+    ADD COLUMN age int\
+  ";
+  let expected_query_users_with_login = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    ALTER TABLE users \
+    /* test raw_after */ \
+    ADD COLUMN age int, ADD COLUMN login varchar(40)\
+  ";
+
+  assert_eq!(query_users, expected_query_users);
+  assert_eq!(query_users_with_login, expected_query_users_with_login);
+}
+
+#[test]
+fn alter_table_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut alter_table = sql::AlterTable::new().alter_table("users").add_column("age int");
+
+  if true {
+    alter_table = alter_table.alter_column_set_default("age", "18");
+  }
+
+  let query = alter_table.as_string();
+  let expected_query = "ALTER TABLE users ADD COLUMN age int, ALTER COLUMN age SET DEFAULT 18";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn alter_table_builder_should_be_composable() {
+  fn audit_columns(alter_table: sql::AlterTable) -> sql::AlterTable {
+    alter_table
+      .add_column("created_at timestamp")
+      .add_column("updated_at timestamp")
+  }
+
+  fn drop_legacy(alter_table: sql::AlterTable) -> sql::AlterTable {
+    alter_table.drop_column("inserted_at")
+  }
+
+  fn as_string(alter_table: sql::AlterTable) -> String {
+    alter_table.as_string()
+  }
+
+  let query = Some(sql::AlterTable::new().alter_table("users"))
+    .map(drop_legacy)
+    .map(audit_columns)
+    .map(as_string)
+    .unwrap();
+
+  let expected_query = "\
+    ALTER TABLE users \
+    DROP COLUMN inserted_at, \
+    ADD COLUMN created_at timestamp, \
+    ADD COLUMN updated_at timestamp\
+  ";
+
+  assert_eq!(query, expected_query);
+}
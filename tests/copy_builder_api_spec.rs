@@ -0,0 +1,483 @@
+#![cfg(feature = "postgresql")]
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_as_string_should_convert_the_current_state_into_string() {
+    let query = sql::Copy::new().copy("users").from_stdin().as_string();
+    let expected_query = "COPY users FROM STDIN";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::Copy::new().copy("users").to_stdout().debug().as_string();
+    let expected_query = "COPY users TO STDOUT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::Copy::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::Copy::new().copy("users").to_stdout().print().as_string();
+    let expected_query = "COPY users TO STDOUT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::Copy::new().raw("copy users to stdout").as_string();
+    let expected_query = "copy users to stdout";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Copy::new().raw("copy users").raw("to stdout").as_string();
+    let expected_query = "copy users to stdout";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::Copy::new().to_stdout().raw("copy users").as_string();
+    let expected_query = "copy users TO STDOUT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::Copy::new().raw("  copy users to stdout  ").as_string();
+    let expected_query = "copy users to stdout";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::Copy::new()
+      .raw_after(sql::CopyClause::Copy, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::Copy::new()
+      .raw_before(sql::CopyClause::Copy, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_outside_postgres() {
+    let copy = sql::Copy::new().copy("users").from_stdin();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "COPY",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(copy.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(copy.as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_query_form_outside_postgres() {
+    let copy = sql::Copy::new()
+      .select(sql::Select::new().select("*").from("users"))
+      .to_stdout();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "COPY",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(copy.as_string_for(sql::Dialect::MsSql), expected_error);
+  }
+}
+
+mod copy_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_copy_should_add_a_copy_clause() {
+    let query = sql::Copy::new().copy("users (login, name)").as_string();
+    let expected_query = "COPY users (login, name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_copy_should_override_value_on_consecutive_calls() {
+    let query = sql::Copy::new().copy("users").copy("orders").as_string();
+    let expected_query = "COPY orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_copy_should_trim_space_of_the_argument() {
+    let query = sql::Copy::new().copy("  users  ").as_string();
+    let expected_query = "COPY users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_select_should_add_the_query_form_of_the_copy_clause() {
+    let query = sql::Copy::new()
+      .select(sql::Select::new().select("login").from("users"))
+      .as_string();
+    let expected_query = "COPY (SELECT login FROM users)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_select_should_override_the_copy_table() {
+    let query = sql::Copy::new()
+      .copy("users")
+      .select(sql::Select::new().select("login").from("users"))
+      .as_string();
+    let expected_query = "COPY (SELECT login FROM users)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_copy_should_override_the_select() {
+    let query = sql::Copy::new()
+      .select(sql::Select::new().select("login").from("users"))
+      .copy("users")
+      .as_string();
+    let expected_query = "COPY users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_select_should_keep_the_placeholders_of_the_select() {
+    let copy = sql::Copy::new()
+      .select(
+        sql::Select::new()
+          .select("login")
+          .from("users")
+          .where_bind("tenant_id = $1", 42),
+      )
+      .to_stdout();
+    let expected_query = "COPY (SELECT login FROM users WHERE tenant_id = $1) TO STDOUT";
+
+    assert_eq!(copy.as_string(), expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_query_with_bound_values() {
+    let copy = sql::Copy::new()
+      .select(
+        sql::Select::new()
+          .select("login")
+          .from("users")
+          .where_bind("tenant_id = $1", 42),
+      )
+      .to_stdout();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "SELECT WITH BOUND VALUES",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(copy.as_string_for(sql::Dialect::Postgres), expected_error);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_copy_clause() {
+    let query = sql::Copy::new()
+      .raw_before(sql::CopyClause::Copy, "/* export */")
+      .copy("users")
+      .as_string();
+    let expected_query = "/* export */ COPY users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_copy_clause() {
+    let query = sql::Copy::new()
+      .copy("users")
+      .raw_after(sql::CopyClause::Copy, "(login, name)")
+      .as_string();
+    let expected_query = "COPY users (login, name)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod from_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_from_stdin_should_add_the_from_stdin_clause() {
+    let query = sql::Copy::new().from_stdin().as_string();
+    let expected_query = "FROM STDIN";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_should_add_the_quoted_file_name() {
+    let query = sql::Copy::new().from("/tmp/o'neil.csv").as_string();
+    let expected_query = "FROM '/tmp/o''neil.csv'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_from_should_override_the_to_clause() {
+    let query = sql::Copy::new().to_stdout().from_stdin().as_string();
+    let expected_query = "FROM STDIN";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_from_should_be_after_copy_clause() {
+    let query = sql::Copy::new().from_stdin().copy("users").as_string();
+    let expected_query = "COPY users FROM STDIN";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_from_clause() {
+    let query = sql::Copy::new()
+      .raw_before(sql::CopyClause::From, "copy users")
+      .from_stdin()
+      .as_string();
+    let expected_query = "copy users FROM STDIN";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_from_clause() {
+    let query = sql::Copy::new()
+      .from_stdin()
+      .raw_after(sql::CopyClause::From, "where active = true")
+      .as_string();
+    let expected_query = "FROM STDIN where active = true";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod to_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_to_stdout_should_add_the_to_stdout_clause() {
+    let query = sql::Copy::new().to_stdout().as_string();
+    let expected_query = "TO STDOUT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_to_should_add_the_quoted_file_name() {
+    let query = sql::Copy::new().to("/tmp/users.csv").as_string();
+    let expected_query = "TO '/tmp/users.csv'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_to_should_override_the_from_clause() {
+    let query = sql::Copy::new().from_stdin().to("/tmp/users.csv").as_string();
+    let expected_query = "TO '/tmp/users.csv'";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_to_should_be_after_copy_clause() {
+    let query = sql::Copy::new().to_stdout().copy("users").as_string();
+    let expected_query = "COPY users TO STDOUT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_to_clause() {
+    let query = sql::Copy::new()
+      .raw_before(sql::CopyClause::To, "copy users")
+      .to_stdout()
+      .as_string();
+    let expected_query = "copy users TO STDOUT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_to_clause() {
+    let query = sql::Copy::new()
+      .to_stdout()
+      .raw_after(sql::CopyClause::To, "with (format csv)")
+      .as_string();
+    let expected_query = "TO STDOUT with (format csv)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod with_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_format_should_add_the_format_option() {
+    let csv = sql::Copy::new().format(sql::CopyFormat::Csv).as_string();
+    let binary = sql::Copy::new().format(sql::CopyFormat::Binary).as_string();
+    let text = sql::Copy::new().format(sql::CopyFormat::Text).as_string();
+
+    assert_eq!(csv, "WITH (FORMAT csv)");
+    assert_eq!(binary, "WITH (FORMAT binary)");
+    assert_eq!(text, "WITH (FORMAT text)");
+  }
+
+  #[test]
+  fn method_format_should_override_value_on_consecutive_calls() {
+    let query = sql::Copy::new()
+      .format(sql::CopyFormat::Csv)
+      .format(sql::CopyFormat::Binary)
+      .as_string();
+    let expected_query = "WITH (FORMAT binary)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_header_should_add_the_header_option() {
+    let query = sql::Copy::new().header().as_string();
+    let expected_query = "WITH (HEADER)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_delimiter_should_add_the_quoted_delimiter_option() {
+    let query = sql::Copy::new().delimiter("|").as_string();
+    let expected_query = "WITH (DELIMITER '|')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_delimiter_should_override_value_on_consecutive_calls() {
+    let query = sql::Copy::new().delimiter("|").delimiter(";").as_string();
+    let expected_query = "WITH (DELIMITER ';')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_null_should_add_the_quoted_null_option() {
+    let query = sql::Copy::new().null("\\N").as_string();
+    let expected_query = "WITH (NULL '\\N')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_null_should_accept_the_empty_string() {
+    let query = sql::Copy::new().null("").as_string();
+    let expected_query = "WITH (NULL '')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn options_should_be_rendered_in_a_fixed_order() {
+    let query = sql::Copy::new()
+      .null("")
+      .delimiter(";")
+      .header()
+      .format(sql::CopyFormat::Csv)
+      .as_string();
+    let expected_query = "WITH (FORMAT csv, HEADER, DELIMITER ';', NULL '')";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_binary_format_with_the_text_options() {
+    let binary = sql::Copy::new()
+      .copy("users")
+      .to_stdout()
+      .format(sql::CopyFormat::Binary);
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "BINARY FORMAT WITH TEXT OPTIONS",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert!(binary.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert_eq!(
+      binary.clone().header().as_string_for(sql::Dialect::Postgres),
+      expected_error
+    );
+    assert_eq!(
+      binary.clone().delimiter(";").as_string_for(sql::Dialect::Postgres),
+      expected_error
+    );
+    assert_eq!(binary.null("").as_string_for(sql::Dialect::Postgres), expected_error);
+  }
+
+  #[test]
+  fn clause_with_should_be_after_from_and_to_clauses() {
+    let copy_from = sql::Copy::new().header().copy("users").from_stdin().as_string();
+    let copy_to = sql::Copy::new().header().copy("users").to_stdout().as_string();
+
+    assert_eq!(copy_from, "COPY users FROM STDIN WITH (HEADER)");
+    assert_eq!(copy_to, "COPY users TO STDOUT WITH (HEADER)");
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_with_clause() {
+    let query = sql::Copy::new()
+      .raw_before(sql::CopyClause::With, "to stdout")
+      .header()
+      .as_string();
+    let expected_query = "to stdout WITH (HEADER)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_with_clause() {
+    let query = sql::Copy::new()
+      .header()
+      .raw_after(sql::CopyClause::With, "/* export */")
+      .as_string();
+    let expected_query = "WITH (HEADER) /* export */";
+
+    assert_eq!(query, expected_query);
+  }
+}
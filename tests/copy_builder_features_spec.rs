@@ -0,0 +1,107 @@
+#![cfg(feature = "postgresql")]
+
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn copy_builder_should_be_displayable() {
+  let copy = sql::Copy::new().copy("users (login, name)").from_stdin();
+
+  println!("{}", copy);
+
+  let query = copy.as_string();
+  let expected_query = "COPY users (login, name) FROM STDIN";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn copy_builder_should_be_debuggable() {
+  let copy = sql::Copy::new()
+    .select(sql::Select::new().select("login, name").from("users"))
+    .to_stdout()
+    .format(sql::CopyFormat::Csv)
+    .header();
+
+  println!("{:?}", copy);
+
+  let expected_query = "COPY (SELECT login, name FROM users) TO STDOUT WITH (FORMAT csv, HEADER)";
+  let query = copy.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn copy_builder_should_be_cloneable() {
+  let copy_csv = sql::Copy::new()
+    .raw("/* test raw */")
+    .raw_before(sql::CopyClause::Copy, "/* test raw_before */")
+    .raw_after(sql::CopyClause::With, "/* test raw_after */")
+    .copy("users")
+    .to_stdout()
+    .format(sql::CopyFormat::Csv);
+
+  let copy_csv_with_header = copy_csv.clone().header();
+
+  let query_copy_csv = copy_csv.as_string();
+  let query_copy_csv_with_header = copy_csv_with_header.as_string();
+
+  let expected_query_copy_csv = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    COPY users \
+    TO STDOUT \
+    WITH (FORMAT csv) \
+    /* test raw_after */\
+  ";
+  let expected_query_copy_csv_with_header = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    COPY users \
+    TO STDOUT \
+    WITH (FORMAT csv, HEADER) \
+    /* test raw_after */\
+  ";
+
+  assert_eq!(query_copy_csv, expected_query_copy_csv);
+  assert_eq!(query_copy_csv_with_header, expected_query_copy_csv_with_header);
+}
+
+#[test]
+fn copy_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut copy = sql::Copy::new().copy("users").from_stdin().format(sql::CopyFormat::Csv);
+
+  if true {
+    copy = copy.header();
+  }
+
+  let query = copy.as_string();
+  let expected_query = "COPY users FROM STDIN WITH (FORMAT csv, HEADER)";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn copy_builder_should_be_composable() {
+  fn csv_options(copy: sql::Copy) -> sql::Copy {
+    copy.format(sql::CopyFormat::Csv).header().delimiter(";")
+  }
+
+  fn export(copy: sql::Copy) -> sql::Copy {
+    copy.to_stdout()
+  }
+
+  fn as_string(copy: sql::Copy) -> String {
+    copy.as_string()
+  }
+
+  let query = Some(sql::Copy::new().copy("users (login, name)"))
+    .map(export)
+    .map(csv_options)
+    .map(as_string)
+    .unwrap();
+
+  let expected_query = "COPY users (login, name) TO STDOUT WITH (FORMAT csv, HEADER, DELIMITER ';')";
+
+  assert_eq!(query, expected_query);
+}
@@ -0,0 +1,555 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::CreateIndex::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login")
+      .debug()
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx ON users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login")
+      .print()
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx ON users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::CreateIndex::new()
+      .raw("create index users_login_idx")
+      .on("users")
+      .as_string();
+    let expected_query = "create index users_login_idx ON users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateIndex::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .raw("/* the login index */")
+      .as_string();
+    let expected_query = "/* the login index */ CREATE INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::CreateIndex::new()
+      .raw_after(sql::CreateIndexClause::CreateIndex, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::CreateIndex::new()
+      .raw_before(sql::CreateIndexClause::CreateIndex, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_clauses_the_dialect_does_not_support() {
+    let create_index = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .on("users")
+      .column("login");
+
+    assert_eq!(
+      create_index.clone().using("btree").as_string_for(sql::Dialect::MySql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "USING",
+        dialect: sql::Dialect::MySql,
+      })
+    );
+    assert_eq!(
+      create_index.clone().include("name").as_string_for(sql::Dialect::Sqlite),
+      Err(sql::Error::UnsupportedClause {
+        clause: "INCLUDE",
+        dialect: sql::Dialect::Sqlite,
+      })
+    );
+    assert_eq!(
+      create_index
+        .clone()
+        .where_clause("active = true")
+        .as_string_for(sql::Dialect::MySql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "WHERE",
+        dialect: sql::Dialect::MySql,
+      })
+    );
+    assert_eq!(
+      create_index
+        .create_index_if_not_exists("users_login_idx")
+        .as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "IF NOT EXISTS",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+  }
+
+  #[test]
+  fn method_as_string_for_should_accept_the_unique_option_in_all_dialects() {
+    let create_index = sql::CreateIndex::new()
+      .create_index("users_login_key")
+      .unique()
+      .on("users")
+      .column("login");
+    let expected_query = Ok("CREATE UNIQUE INDEX users_login_key ON users (login)".to_owned());
+
+    assert_eq!(create_index.as_string_for(sql::Dialect::Postgres), expected_query);
+    assert_eq!(create_index.as_string_for(sql::Dialect::Sqlite), expected_query);
+    assert_eq!(create_index.as_string_for(sql::Dialect::MySql), expected_query);
+    assert_eq!(create_index.as_string_for(sql::Dialect::MsSql), expected_query);
+  }
+}
+
+mod create_index_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_create_index_should_add_the_create_index_clause() {
+    let query = sql::CreateIndex::new().create_index("users_login_idx").as_string();
+    let expected_query = "CREATE INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_index_should_override_value_on_consecutive_calls() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .create_index("users_name_idx")
+      .as_string();
+    let expected_query = "CREATE INDEX users_name_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_index_should_trim_space_of_the_argument() {
+    let query = sql::CreateIndex::new().create_index("  users_login_idx  ").as_string();
+    let expected_query = "CREATE INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_index_if_not_exists_should_add_the_if_not_exists_option() {
+    let query = sql::CreateIndex::new()
+      .create_index_if_not_exists("users_login_idx")
+      .as_string();
+    let expected_query = "CREATE INDEX IF NOT EXISTS users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_index_should_override_the_create_index_if_not_exists_clause() {
+    let query = sql::CreateIndex::new()
+      .create_index_if_not_exists("users_login_idx")
+      .create_index("users_login_idx")
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_unique_should_add_the_unique_option() {
+    let query = sql::CreateIndex::new()
+      .unique()
+      .create_index_if_not_exists("users_login_key")
+      .as_string();
+    let expected_query = "CREATE UNIQUE INDEX IF NOT EXISTS users_login_key";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_create_index_clause() {
+    let query = sql::CreateIndex::new()
+      .raw_before(sql::CreateIndexClause::CreateIndex, "/* the login index */")
+      .create_index("users_login_idx")
+      .as_string();
+    let expected_query = "/* the login index */ CREATE INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_create_index_clause() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .raw_after(sql::CreateIndexClause::CreateIndex, "on users (login)")
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx on users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod on_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_on_should_add_the_on_clause() {
+    let query = sql::CreateIndex::new().on("users").as_string();
+    let expected_query = "ON users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_override_value_on_consecutive_calls() {
+    let query = sql::CreateIndex::new().on("users").on("orders").as_string();
+    let expected_query = "ON orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_accept_owned_quoted_names() {
+    let query = sql::CreateIndex::new()
+      .on(sql::quote_ident("Users", sql::Dialect::MsSql))
+      .as_string();
+    let expected_query = "ON [Users]";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_on_should_be_after_create_index_clause() {
+    let query = sql::CreateIndex::new()
+      .on("users")
+      .create_index("users_login_idx")
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx ON users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_on_clause() {
+    let query = sql::CreateIndex::new()
+      .create_index("users_login_idx")
+      .raw_before(sql::CreateIndexClause::On, "/* table */")
+      .on("users")
+      .as_string();
+    let expected_query = "CREATE INDEX users_login_idx /* table */ ON users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_on_clause() {
+    let query = sql::CreateIndex::new()
+      .on("users")
+      .raw_after(sql::CreateIndexClause::On, "(login)")
+      .as_string();
+    let expected_query = "ON users (login)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod using_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_using_should_add_the_using_clause() {
+    let query = sql::CreateIndex::new().using("gin").as_string();
+    let expected_query = "USING gin";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_override_value_on_consecutive_calls() {
+    let query = sql::CreateIndex::new().using("gin").using("btree").as_string();
+    let expected_query = "USING btree";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_using_should_be_after_on_clause() {
+    let query = sql::CreateIndex::new().using("gin").on("documents").as_string();
+    let expected_query = "ON documents USING gin";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_using_clause() {
+    let query = sql::CreateIndex::new()
+      .using("gin")
+      .raw_after(sql::CreateIndexClause::Using, "(tags)")
+      .as_string();
+    let expected_query = "USING gin (tags)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod column_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_column_should_add_the_columns_inside_parentheses() {
+    let query = sql::CreateIndex::new().column("login").as_string();
+    let expected_query = "(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateIndex::new()
+      .column("user_id")
+      .column("created_at DESC NULLS LAST")
+      .column("lower(login) ASC NULLS FIRST")
+      .as_string();
+    let expected_query = "(user_id, created_at DESC NULLS LAST, lower(login) ASC NULLS FIRST)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateIndex::new().column("login").column("login").as_string();
+    let expected_query = "(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_trim_space_of_the_argument() {
+    let query = sql::CreateIndex::new().column("  login  ").as_string();
+    let expected_query = "(login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_column_should_be_after_using_clause() {
+    let query = sql::CreateIndex::new()
+      .column("tags")
+      .using("gin")
+      .on("documents")
+      .as_string();
+    let expected_query = "ON documents USING gin (tags)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_column_clause() {
+    let query = sql::CreateIndex::new()
+      .on("users")
+      .raw_before(sql::CreateIndexClause::Column, "/* columns */")
+      .column("login")
+      .as_string();
+    let expected_query = "ON users /* columns */ (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_column_clause() {
+    let query = sql::CreateIndex::new()
+      .column("login")
+      .raw_after(sql::CreateIndexClause::Column, "with (fillfactor = 70)")
+      .as_string();
+    let expected_query = "(login) with (fillfactor = 70)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod include_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_include_should_add_the_include_clause() {
+    let query = sql::CreateIndex::new().include("name").as_string();
+    let expected_query = "INCLUDE (name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_include_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateIndex::new().include("name").include("email").as_string();
+    let expected_query = "INCLUDE (name, email)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_include_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateIndex::new().include("name").include("name").as_string();
+    let expected_query = "INCLUDE (name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_include_should_be_after_column_clause() {
+    let query = sql::CreateIndex::new().include("name").column("login").as_string();
+    let expected_query = "(login) INCLUDE (name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_include_clause() {
+    let query = sql::CreateIndex::new()
+      .raw_before(sql::CreateIndexClause::Include, "/* covering */")
+      .include("name")
+      .as_string();
+    let expected_query = "/* covering */ INCLUDE (name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_include_clause() {
+    let query = sql::CreateIndex::new()
+      .include("name")
+      .raw_after(sql::CreateIndexClause::Include, "with (fillfactor = 70)")
+      .as_string();
+    let expected_query = "INCLUDE (name) with (fillfactor = 70)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod where_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_where_clause_should_add_the_where_clause() {
+    let query = sql::CreateIndex::new().where_clause("active = true").as_string();
+    let expected_query = "WHERE active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_where_clause_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateIndex::new()
+      .where_clause("active = true")
+      .where_clause("deleted_at is null")
+      .as_string();
+    let expected_query = "WHERE active = true AND deleted_at is null";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_where_clause_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateIndex::new()
+      .where_clause("active = true")
+      .where_clause("active = true")
+      .as_string();
+    let expected_query = "WHERE active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_where_clause_should_trim_space_of_the_argument() {
+    let query = sql::CreateIndex::new().where_clause("  active = true  ").as_string();
+    let expected_query = "WHERE active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_where_should_be_after_include_clause() {
+    let query = sql::CreateIndex::new()
+      .where_clause("active = true")
+      .include("name")
+      .column("login")
+      .on("users")
+      .unique()
+      .create_index("users_login_key")
+      .as_string();
+    let expected_query = "CREATE UNIQUE INDEX users_login_key ON users (login) INCLUDE (name) WHERE active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_where_clause() {
+    let query = sql::CreateIndex::new()
+      .column("login")
+      .raw_before(sql::CreateIndexClause::Where, "/* partial */")
+      .where_clause("active = true")
+      .as_string();
+    let expected_query = "(login) /* partial */ WHERE active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_where_clause() {
+    let query = sql::CreateIndex::new()
+      .where_clause("active = true")
+      .raw_after(sql::CreateIndexClause::Where, "or admin = true")
+      .as_string();
+    let expected_query = "WHERE active = true or admin = true";
+
+    assert_eq!(query, expected_query);
+  }
+}
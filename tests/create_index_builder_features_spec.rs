@@ -0,0 +1,116 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn create_index_builder_should_be_displayable() {
+  let create_index = sql::CreateIndex::new()
+    .create_index("users_login_idx")
+    .on("users")
+    .column("login");
+
+  println!("{}", create_index);
+
+  let query = create_index.as_string();
+  let expected_query = "CREATE INDEX users_login_idx ON users (login)";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_index_builder_should_be_debuggable() {
+  let create_index = sql::CreateIndex::new()
+    .create_index("users_login_idx")
+    .on("users")
+    .column("login")
+    .where_clause("active = true");
+
+  println!("{:?}", create_index);
+
+  let expected_query = "CREATE INDEX users_login_idx ON users (login) WHERE active = true";
+  let query = create_index.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_index_builder_should_be_cloneable() {
+  let index_login = sql::CreateIndex::new()
+    .raw("/* test raw */")
+    .raw_before(sql::CreateIndexClause::CreateIndex, "/* test raw_before */")
+    .create_index("users_login_idx")
+    .raw_after(sql::CreateIndexClause::CreateIndex, "/* test raw_after */")
+    .on("users")
+    .column("login");
+
+  let index_login_partial = index_login.clone().where_clause("active = true");
+
+  let query_login = index_login.as_string();
+  let query_login_partial = index_login_partial.as_string();
+
+  let expected_query_login = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    CREATE INDEX users_login_idx \
+    /* test raw_after */ \
+    ON users (login)\
+  ";
+  let expected_query_login_partial = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    CREATE INDEX users_login_idx \
+    /* test raw_after */ \
+    ON users (login) \
+    WHERE active = true\
+  ";
+
+  assert_eq!(query_login, expected_query_login);
+  assert_eq!(query_login_partial, expected_query_login_partial);
+}
+
+#[test]
+fn create_index_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut create_index = sql::CreateIndex::new()
+    .create_index("users_login_idx")
+    .on("users")
+    .column("login");
+
+  if true {
+    create_index = create_index.unique();
+  }
+
+  let query = create_index.as_string();
+  let expected_query = "CREATE UNIQUE INDEX users_login_idx ON users (login)";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_index_builder_should_be_composable() {
+  fn only_active(create_index: sql::CreateIndex) -> sql::CreateIndex {
+    create_index
+      .where_clause("active = true")
+      .where_clause("deleted_at is null")
+  }
+
+  fn login_key(create_index: sql::CreateIndex) -> sql::CreateIndex {
+    create_index.unique().on("users").column("lower(login)")
+  }
+
+  fn as_string(create_index: sql::CreateIndex) -> String {
+    create_index.as_string()
+  }
+
+  let query = Some(sql::CreateIndex::new().create_index("users_login_key"))
+    .map(login_key)
+    .map(only_active)
+    .map(as_string)
+    .unwrap();
+
+  let expected_query = "\
+    CREATE UNIQUE INDEX users_login_key \
+    ON users (lower(login)) \
+    WHERE active = true AND deleted_at is null\
+  ";
+
+  assert_eq!(query, expected_query);
+}
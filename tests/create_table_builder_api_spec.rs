@@ -0,0 +1,385 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::CreateTable::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial")
+      .debug()
+      .as_string();
+    let expected_query = "CREATE TABLE users (id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .column("id serial")
+      .print()
+      .as_string();
+    let expected_query = "CREATE TABLE users (id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::CreateTable::new()
+      .raw("create table users")
+      .column("id serial")
+      .as_string();
+    let expected_query = "create table users (id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .raw("/* the users table */")
+      .as_string();
+    let expected_query = "/* the users table */ CREATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::CreateTable::new()
+      .raw_after(sql::CreateTableClause::CreateTable, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::CreateTable::new()
+      .raw_before(sql::CreateTableClause::CreateTable, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_if_not_exists_option_in_mssql() {
+    let create_table = sql::CreateTable::new().create_table_if_not_exists("users");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "IF NOT EXISTS",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(create_table.as_string_for(sql::Dialect::MsSql), expected_error);
+    assert!(create_table.as_string_for(sql::Dialect::MySql).is_ok());
+  }
+}
+
+mod create_table_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_create_table_should_add_the_create_table_clause() {
+    let query = sql::CreateTable::new().create_table("users").as_string();
+    let expected_query = "CREATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_table_should_override_value_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .create_table("orders")
+      .as_string();
+    let expected_query = "CREATE TABLE orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_table_should_trim_space_of_the_argument() {
+    let query = sql::CreateTable::new().create_table("  users  ").as_string();
+    let expected_query = "CREATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_table_if_not_exists_should_add_the_if_not_exists_option() {
+    let query = sql::CreateTable::new().create_table_if_not_exists("users").as_string();
+    let expected_query = "CREATE TABLE IF NOT EXISTS users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_table_should_override_the_create_table_if_not_exists_clause() {
+    let query = sql::CreateTable::new()
+      .create_table_if_not_exists("users")
+      .create_table("orders")
+      .as_string();
+    let expected_query = "CREATE TABLE orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_table_should_accept_owned_quoted_names() {
+    let query = sql::CreateTable::new()
+      .create_table(sql::quote_ident("Users", sql::Dialect::Postgres))
+      .as_string();
+    let expected_query = "CREATE TABLE \"Users\"";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_create_table_clause() {
+    let query = sql::CreateTable::new()
+      .raw_before(sql::CreateTableClause::CreateTable, "/* the users table */")
+      .create_table("users")
+      .as_string();
+    let expected_query = "/* the users table */ CREATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_create_table_clause() {
+    let query = sql::CreateTable::new()
+      .create_table("users")
+      .raw_after(sql::CreateTableClause::CreateTable, "(id serial)")
+      .as_string();
+    let expected_query = "CREATE TABLE users (id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod column_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_column_should_add_the_column_definition_inside_parentheses() {
+    let query = sql::CreateTable::new().column("id serial").as_string();
+    let expected_query = "(id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .column("id serial")
+      .column("login varchar(40) not null")
+      .as_string();
+    let expected_query = "(id serial, login varchar(40) not null)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateTable::new()
+      .column("id serial")
+      .column("id serial")
+      .as_string();
+    let expected_query = "(id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_trim_space_of_the_argument() {
+    let query = sql::CreateTable::new().column("  id serial  ").as_string();
+    let expected_query = "(id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_column_should_be_after_create_table_clause() {
+    let query = sql::CreateTable::new()
+      .column("id serial")
+      .create_table("users")
+      .as_string();
+    let expected_query = "CREATE TABLE users (id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_column_clause() {
+    let query = sql::CreateTable::new()
+      .raw_before(sql::CreateTableClause::Column, "uuid uuid,")
+      .column("id serial")
+      .as_string();
+    let expected_query = "(uuid uuid, id serial)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_column_clause() {
+    let query = sql::CreateTable::new()
+      .column("id serial")
+      .raw_after(sql::CreateTableClause::Column, "/* the id column */")
+      .as_string();
+    let expected_query = "(id serial /* the id column */)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod constraints {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_primary_key_should_add_the_primary_key_constraint() {
+    let query = sql::CreateTable::new().primary_key("(id)").as_string();
+    let expected_query = "(PRIMARY KEY(id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_primary_key_should_override_value_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .primary_key("(id)")
+      .primary_key("(user_id, group_id)")
+      .as_string();
+    let expected_query = "(PRIMARY KEY(user_id, group_id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_unique_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateTable::new().unique("(login)").unique("(email)").as_string();
+    let expected_query = "(UNIQUE(login), UNIQUE(email))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_check_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .check("(age >= 18)")
+      .check("(age < 150)")
+      .as_string();
+    let expected_query = "(CHECK(age >= 18), CHECK(age < 150))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_foreign_key_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateTable::new()
+      .foreign_key("(user_id) references users (id)")
+      .foreign_key("(group_id) references groups (id)")
+      .as_string();
+    let expected_query = "(FOREIGN KEY(user_id) references users (id), FOREIGN KEY(group_id) references groups (id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_constraint_should_add_a_named_constraint() {
+    let query = sql::CreateTable::new()
+      .constraint("users_login_key unique (login)")
+      .as_string();
+    let expected_query = "(CONSTRAINT users_login_key unique (login))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn constraints_should_be_after_the_columns_regardless_of_the_call_order() {
+    let query = sql::CreateTable::new()
+      .constraint("orders_amount_check check (amount > 0)")
+      .foreign_key("(user_id) references users (id)")
+      .check("(amount < 1000)")
+      .unique("(code)")
+      .primary_key("(id)")
+      .column("id serial")
+      .column("user_id int")
+      .create_table("orders")
+      .as_string();
+    let expected_query = "\
+      CREATE TABLE orders (\
+        id serial, \
+        user_id int, \
+        PRIMARY KEY(id), \
+        UNIQUE(code), \
+        CHECK(amount < 1000), \
+        FOREIGN KEY(user_id) references users (id), \
+        CONSTRAINT orders_amount_check check (amount > 0)\
+      )\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_primary_key_clause() {
+    let query = sql::CreateTable::new()
+      .column("id serial")
+      .raw_before(sql::CreateTableClause::PrimaryKey, "login text,")
+      .primary_key("(id)")
+      .as_string();
+    let expected_query = "(id serial, login text, PRIMARY KEY(id))";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_foreign_key_clause() {
+    let query = sql::CreateTable::new()
+      .foreign_key("(user_id) references users (id)")
+      .raw_after(sql::CreateTableClause::ForeignKey, "on delete cascade")
+      .as_string();
+    let expected_query = "(FOREIGN KEY(user_id) references users (id) on delete cascade)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_unique_clause_without_values() {
+    let query = sql::CreateTable::new()
+      .column("id serial")
+      .raw_after(sql::CreateTableClause::Unique, "unique (id)")
+      .as_string();
+    let expected_query = "(id serial, unique (id))";
+
+    assert_eq!(query, expected_query);
+  }
+}
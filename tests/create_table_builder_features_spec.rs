@@ -0,0 +1,110 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn create_table_builder_should_be_displayable() {
+  let create_table = sql::CreateTable::new().create_table("users").column("id serial");
+
+  println!("{}", create_table);
+
+  let query = create_table.as_string();
+  let expected_query = "CREATE TABLE users (id serial)";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_table_builder_should_be_debuggable() {
+  let create_table = sql::CreateTable::new()
+    .create_table("users")
+    .column("id serial")
+    .primary_key("(id)");
+
+  println!("{:?}", create_table);
+
+  let expected_query = "CREATE TABLE users (id serial, PRIMARY KEY(id))";
+  let query = create_table.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_table_builder_should_be_cloneable() {
+  let create_users = sql::CreateTable::new()
+    .raw("/* test raw */")
+    .raw_before(sql::CreateTableClause::CreateTable, "/* test raw_before */")
+    .create_table("users")
+    .raw_after(sql::CreateTableClause::CreateTable, "/* test raw_after */")
+    .column("id serial");
+
+  let create_users_with_login = create_users.clone().column("login varchar(40)");
+
+  let query_users = create_users.as_string();
+  let query_users_with_login = create_users_with_login.as_string();
+
+  let expected_query_users = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    CREATE TABLE users \
+    /* test raw_after */ \
+    (id serial)\
+  ";
+  let expected_query_users_with_login = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    CREATE TABLE users \
+    /* test raw_after */ \
+    (id serial, login varchar(40))\
+  ";
+
+  assert_eq!(query_users, expected_query_users);
+  assert_eq!(query_users_with_login, expected_query_users_with_login);
+}
+
+#[test]
+fn create_table_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut create_table = sql::CreateTable::new().create_table("users").column("id serial");
+
+  if true {
+    create_table = create_table.primary_key("(id)");
+  }
+
+  let query = create_table.as_string();
+  let expected_query = "CREATE TABLE users (id serial, PRIMARY KEY(id))";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_table_builder_should_be_composable() {
+  fn audit_columns(create_table: sql::CreateTable) -> sql::CreateTable {
+    create_table
+      .column("created_at timestamp not null")
+      .column("updated_at timestamp")
+  }
+
+  fn identity(create_table: sql::CreateTable) -> sql::CreateTable {
+    create_table.column("id serial").primary_key("(id)")
+  }
+
+  fn as_string(create_table: sql::CreateTable) -> String {
+    create_table.as_string()
+  }
+
+  let query = Some(sql::CreateTable::new().create_table("users"))
+    .map(identity)
+    .map(audit_columns)
+    .map(as_string)
+    .unwrap();
+
+  let expected_query = "\
+    CREATE TABLE users (\
+      id serial, \
+      created_at timestamp not null, \
+      updated_at timestamp, \
+      PRIMARY KEY(id)\
+    )\
+  ";
+
+  assert_eq!(query, expected_query);
+}
@@ -0,0 +1,443 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_as_string_should_convert_the_current_state_into_string() {
+    let query = sql::CreateView::new().create_view("active_users").as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .select(sql::Select::new().select("*").from("users"))
+      .debug()
+      .as_string();
+    let expected_query = "CREATE VIEW active_users AS SELECT * FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::CreateView::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .select(sql::Select::new().select("*").from("users"))
+      .print()
+      .as_string();
+    let expected_query = "CREATE VIEW active_users AS SELECT * FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::CreateView::new().raw("create view active_users").as_string();
+    let expected_query = "create view active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateView::new()
+      .raw("create view active_users")
+      .raw("as select * from users")
+      .as_string();
+    let expected_query = "create view active_users as select * from users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::CreateView::new()
+      .select(sql::Select::new().select("*").from("users"))
+      .raw("create view active_users")
+      .as_string();
+    let expected_query = "create view active_users AS SELECT * FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::CreateView::new().raw("  create view active_users  ").as_string();
+    let expected_query = "create view active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::CreateView::new()
+      .raw_after(sql::CreateViewClause::CreateView, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::CreateView::new()
+      .raw_before(sql::CreateViewClause::Select, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_the_query_when_the_dialect_supports_the_clauses() {
+    let create_view = sql::CreateView::new()
+      .create_or_replace_view("active_users")
+      .select(sql::Select::new().select("*").from("users"));
+    let expected_query = Ok("CREATE OR REPLACE VIEW active_users AS SELECT * FROM users".to_owned());
+
+    assert_eq!(create_view.as_string_for(sql::Dialect::Postgres), expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_validate_the_nested_select() {
+    let create_view = sql::CreateView::new()
+      .create_view("first_users")
+      .select(sql::Select::new().select("*").from("users").limit("10"));
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "LIMIT",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(create_view.as_string_for(sql::Dialect::MsSql), expected_error);
+  }
+}
+
+mod create_view_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_create_view_should_add_the_create_view_clause() {
+    let query = sql::CreateView::new().create_view("active_users").as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_view_should_override_the_current_value() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .create_view("inactive_users")
+      .as_string();
+    let expected_query = "CREATE VIEW inactive_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_view_should_trim_space_of_the_argument() {
+    let query = sql::CreateView::new().create_view("  active_users  ").as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_or_replace_view_should_add_the_or_replace_option() {
+    let query = sql::CreateView::new()
+      .create_or_replace_view("active_users")
+      .as_string();
+    let expected_query = "CREATE OR REPLACE VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_create_view_should_override_the_create_or_replace_view() {
+    let query = sql::CreateView::new()
+      .create_or_replace_view("active_users")
+      .create_view("active_users")
+      .as_string();
+    let expected_query = "CREATE VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_or_replace_option_in_sqlite() {
+    let create_view = sql::CreateView::new().create_or_replace_view("active_users");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "OR REPLACE",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(create_view.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(create_view.as_string_for(sql::Dialect::MySql).is_ok());
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_create_view_clause() {
+    let query = sql::CreateView::new()
+      .raw_before(sql::CreateViewClause::CreateView, "/* the view */")
+      .create_view("active_users")
+      .as_string();
+    let expected_query = "/* the view */ CREATE VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_create_view_clause() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .raw_after(sql::CreateViewClause::CreateView, "with (security_barrier)")
+      .as_string();
+    let expected_query = "CREATE VIEW active_users with (security_barrier)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod column_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_column_should_add_the_column_aliases_in_parentheses() {
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .column("user_login")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins (user_login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .column("user_login")
+      .column("user_name")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins (user_login, user_name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::CreateView::new()
+      .column("user_login")
+      .column("user_login")
+      .as_string();
+    let expected_query = "(user_login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_column_should_trim_space_of_the_argument() {
+    let query = sql::CreateView::new().column("  user_login  ").as_string();
+    let expected_query = "(user_login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_column_should_be_after_create_view_clause() {
+    let query = sql::CreateView::new()
+      .column("user_login")
+      .create_view("user_logins")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins (user_login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_column_clause() {
+    let query = sql::CreateView::new()
+      .create_view("user_logins")
+      .raw_before(sql::CreateViewClause::Column, "/* aliases */")
+      .column("user_login")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins /* aliases */ (user_login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_column_clause() {
+    let query = sql::CreateView::new()
+      .column("user_login")
+      .raw_after(sql::CreateViewClause::Column, "/* aliases */")
+      .as_string();
+    let expected_query = "(user_login) /* aliases */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod select_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_select_should_add_the_query_after_the_as_keyword() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .select(
+        sql::Select::new()
+          .select("*")
+          .from("users")
+          .where_clause("active = true"),
+      )
+      .as_string();
+    let expected_query = "CREATE VIEW active_users AS SELECT * FROM users WHERE active = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_select_should_override_the_current_value() {
+    let query = sql::CreateView::new()
+      .select(sql::Select::new().select("*").from("users"))
+      .select(sql::Select::new().select("*").from("orders"))
+      .as_string();
+    let expected_query = "AS SELECT * FROM orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_query_with_bound_values() {
+    let create_view = sql::CreateView::new().create_view("tenant_users").select(
+      sql::Select::new()
+        .select("*")
+        .from("users")
+        .where_bind("tenant_id = $1", 42),
+    );
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "SELECT WITH BOUND VALUES",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(create_view.as_string_for(sql::Dialect::Postgres), expected_error);
+  }
+
+  #[test]
+  fn clause_select_should_be_after_column_clause() {
+    let query = sql::CreateView::new()
+      .select(sql::Select::new().select("login").from("users"))
+      .column("user_login")
+      .create_view("user_logins")
+      .as_string();
+    let expected_query = "CREATE VIEW user_logins (user_login) AS SELECT login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_select_clause() {
+    let query = sql::CreateView::new()
+      .create_view("active_users")
+      .raw_before(sql::CreateViewClause::Select, "/* the query */")
+      .select(sql::Select::new().select("*").from("users"))
+      .as_string();
+    let expected_query = "CREATE VIEW active_users /* the query */ AS SELECT * FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_select_clause() {
+    let query = sql::CreateView::new()
+      .select(sql::Select::new().select("*").from("users"))
+      .raw_after(sql::CreateViewClause::Select, "with local check option")
+      .as_string();
+    let expected_query = "AS SELECT * FROM users with local check option";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod with_check_option_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_with_check_option_should_add_the_with_check_option_clause() {
+    let query = sql::CreateView::new().with_check_option().as_string();
+    let expected_query = "WITH CHECK OPTION";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_with_check_option_should_be_after_select_clause() {
+    let query = sql::CreateView::new()
+      .with_check_option()
+      .create_view("active_users")
+      .select(
+        sql::Select::new()
+          .select("*")
+          .from("users")
+          .where_clause("active = true"),
+      )
+      .as_string();
+    let expected_query = "CREATE VIEW active_users AS SELECT * FROM users WHERE active = true WITH CHECK OPTION";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_with_check_option_in_sqlite() {
+    let create_view = sql::CreateView::new()
+      .create_view("active_users")
+      .select(sql::Select::new().select("*").from("users"))
+      .with_check_option();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "WITH CHECK OPTION",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(create_view.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(create_view.as_string_for(sql::Dialect::MsSql).is_ok());
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_with_check_option_clause() {
+    let query = sql::CreateView::new()
+      .raw_before(sql::CreateViewClause::WithCheckOption, "/* check */")
+      .with_check_option()
+      .as_string();
+    let expected_query = "/* check */ WITH CHECK OPTION";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_with_check_option_clause() {
+    let query = sql::CreateView::new()
+      .with_check_option()
+      .raw_after(sql::CreateViewClause::WithCheckOption, "/* check */")
+      .as_string();
+    let expected_query = "WITH CHECK OPTION /* check */";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,123 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn create_view_builder_should_be_displayable() {
+  let create_view = sql::CreateView::new().create_view("active_users").select(
+    sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause("active = true"),
+  );
+
+  println!("{}", create_view);
+
+  let query = create_view.as_string();
+  let expected_query = "CREATE VIEW active_users AS SELECT * FROM users WHERE active = true";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_view_builder_should_be_debuggable() {
+  let create_view = sql::CreateView::new()
+    .create_or_replace_view("user_logins")
+    .column("user_login")
+    .select(sql::Select::new().select("login").from("users"))
+    .with_check_option();
+
+  println!("{:?}", create_view);
+
+  let expected_query = "CREATE OR REPLACE VIEW user_logins (user_login) AS SELECT login FROM users WITH CHECK OPTION";
+  let query = create_view.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_view_builder_should_be_cloneable() {
+  let active_users = sql::CreateView::new()
+    .raw("/* test raw */")
+    .raw_before(sql::CreateViewClause::CreateView, "/* test raw_before */")
+    .raw_after(sql::CreateViewClause::Select, "/* test raw_after */")
+    .create_view("active_users")
+    .select(
+      sql::Select::new()
+        .select("*")
+        .from("users")
+        .where_clause("active = true"),
+    );
+
+  let active_users_checked = active_users.clone().with_check_option();
+
+  let query_active_users = active_users.as_string();
+  let query_active_users_checked = active_users_checked.as_string();
+
+  let expected_query_active_users = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    CREATE VIEW active_users \
+    AS SELECT * FROM users WHERE active = true \
+    /* test raw_after */\
+  ";
+  let expected_query_active_users_checked = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    CREATE VIEW active_users \
+    AS SELECT * FROM users WHERE active = true \
+    /* test raw_after */ \
+    WITH CHECK OPTION\
+  ";
+
+  assert_eq!(query_active_users, expected_query_active_users);
+  assert_eq!(query_active_users_checked, expected_query_active_users_checked);
+}
+
+#[test]
+fn create_view_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut create_view = sql::CreateView::new().create_view("active_users").select(
+    sql::Select::new()
+      .select("*")
+      .from("users")
+      .where_clause("active = true"),
+  );
+
+  if true {
+    create_view = create_view.with_check_option();
+  }
+
+  let query = create_view.as_string();
+  let expected_query = "CREATE VIEW active_users AS SELECT * FROM users WHERE active = true WITH CHECK OPTION";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn create_view_builder_should_be_composable() {
+  fn active_users(create_view: sql::CreateView) -> sql::CreateView {
+    create_view.select(
+      sql::Select::new()
+        .select("*")
+        .from("users")
+        .where_clause("active = true"),
+    )
+  }
+
+  fn checked(create_view: sql::CreateView) -> sql::CreateView {
+    create_view.with_check_option()
+  }
+
+  fn as_string(create_view: sql::CreateView) -> String {
+    create_view.as_string()
+  }
+
+  let query = Some(sql::CreateView::new().create_view("active_users"))
+    .map(active_users)
+    .map(checked)
+    .map(as_string)
+    .unwrap();
+
+  let expected_query = "CREATE VIEW active_users AS SELECT * FROM users WHERE active = true WITH CHECK OPTION";
+
+  assert_eq!(query, expected_query);
+}
@@ -248,3 +248,22 @@ mod where_clause {
     assert_eq!(query, expected_query);
   }
 }
+
+mod where_bind_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_where_bind_should_renumber_the_placeholder_after_the_values_already_bound() {
+    let (query, params) = sql::Delete::new()
+      .delete_from("users")
+      .where_bind("login = $1", "foo")
+      .and_bind("created_at < $1", "2023-01-01")
+      .as_query();
+    let expected_query = "DELETE FROM users WHERE login = $1 AND created_at < $2";
+    let expected_params = vec![sql::Value::from("foo"), sql::Value::from("2023-01-01")];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+}
@@ -0,0 +1,198 @@
+use sql_query_builder as sql;
+
+mod as_string_for {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_as_string_for_should_return_the_query_when_the_dialect_supports_all_clauses() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .limit("10")
+      .as_string_for(sql::Dialect::Sqlite);
+    let expected_query = Ok("SELECT * FROM users LIMIT 10".to_owned());
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_when_the_dialect_does_not_support_a_clause() {
+    let query = sql::Select::new()
+      .select("*")
+      .from("users")
+      .limit("10")
+      .as_string_for(sql::Dialect::MsSql);
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "LIMIT",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(query, expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_validate_the_clauses_of_the_nested_builders() {
+    let query = sql::Insert::new()
+      .insert_into("users_bk (login)")
+      .select(sql::Select::new().select("login").from("users").limit("10"))
+      .as_string_for(sql::Dialect::MsSql);
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "LIMIT",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(query, expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_on_conflict_clause_outside_postgres_and_sqlite() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .on_conflict("do nothing");
+
+    assert!(insert.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert!(insert.as_string_for(sql::Dialect::Sqlite).is_ok());
+    assert!(insert.as_string_for(sql::Dialect::MySql).is_err());
+    assert!(insert.as_string_for(sql::Dialect::MsSql).is_err());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_structured_on_conflict_clause_outside_postgres_and_sqlite() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .on_conflict_column("login")
+      .do_update_excluded();
+
+    assert!(insert.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert!(insert.as_string_for(sql::Dialect::Sqlite).is_ok());
+    assert!(insert.as_string_for(sql::Dialect::MySql).is_err());
+    assert!(insert.as_string_for(sql::Dialect::MsSql).is_err());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_on_constraint_target_outside_postgres() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .on_conflict_on_constraint("users_login_key")
+      .do_nothing();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "ON CONSTRAINT",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert!(insert.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert_eq!(insert.as_string_for(sql::Dialect::Sqlite), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_overriding_clause_outside_postgres() {
+    let insert = sql::Insert::new().insert_into("users (id)").overriding("system value");
+
+    assert!(insert.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert!(insert.as_string_for(sql::Dialect::Sqlite).is_err());
+  }
+
+  #[test]
+  fn method_as_string_for_should_not_validate_raw_sql() {
+    let query = sql::Delete::new()
+      .delete_from("users")
+      .raw_after(sql::DeleteClause::DeleteFrom, "limit 10")
+      .as_string_for(sql::Dialect::MsSql);
+    let expected_query = Ok("DELETE FROM users limit 10".to_owned());
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_accept_all_dialects_on_values_builder() {
+    let values = sql::Values::new().values("(1, 'one')");
+
+    assert!(values.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert!(values.as_string_for(sql::Dialect::MsSql).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_render_the_placeholders_in_the_style_of_the_dialect() {
+    let delete = sql::Delete::new()
+      .delete_from("users")
+      .where_clause("login = $1")
+      .and("tenant_id = $2");
+
+    assert_eq!(
+      delete.as_string_for(sql::Dialect::Postgres),
+      Ok("DELETE FROM users WHERE login = $1 AND tenant_id = $2".to_owned())
+    );
+    assert_eq!(
+      delete.as_string_for(sql::Dialect::MySql),
+      Ok("DELETE FROM users WHERE login = ? AND tenant_id = ?".to_owned())
+    );
+    assert_eq!(
+      delete.as_string_for(sql::Dialect::MsSql),
+      Ok("DELETE FROM users WHERE login = @p1 AND tenant_id = @p2".to_owned())
+    );
+  }
+}
+
+mod as_query_for {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_as_query_for_should_reorder_the_values_to_follow_the_question_mark_placeholders() {
+    let update = sql::Update::new()
+      .update("users")
+      .where_bind("login = $1", "foo")
+      .set_bind("name = $1", "Foo");
+    let expected_params = vec![sql::Value::from("Foo"), sql::Value::from("foo")];
+
+    assert_eq!(
+      update.as_query_for(sql::Dialect::Sqlite),
+      Ok(("UPDATE users SET name = ? WHERE login = ?".to_owned(), expected_params))
+    );
+  }
+
+  #[test]
+  fn method_as_query_for_should_keep_the_values_order_in_the_numbered_placeholder_styles() {
+    let update = sql::Update::new()
+      .update("users")
+      .where_bind("login = $1", "foo")
+      .set_bind("name = $1", "Foo");
+    let expected_params = vec![sql::Value::from("foo"), sql::Value::from("Foo")];
+
+    assert_eq!(
+      update.as_query_for(sql::Dialect::MsSql),
+      Ok((
+        "UPDATE users SET name = @p2 WHERE login = @p1".to_owned(),
+        expected_params
+      ))
+    );
+  }
+
+  #[test]
+  fn method_as_query_for_should_return_an_error_when_the_dialect_does_not_support_a_clause() {
+    let select = sql::Select::new().select("*").from("users").limit("10");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "LIMIT",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(select.as_query_for(sql::Dialect::MsSql), expected_error);
+  }
+}
+
+mod error {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn error_unsupported_clause_should_display_the_clause_and_the_dialect() {
+    let error = sql::Error::UnsupportedClause {
+      clause: "LIMIT",
+      dialect: sql::Dialect::MsSql,
+    };
+    let expected_message = "the clause LIMIT is not supported by the dialect MsSql";
+
+    assert_eq!(error.to_string(), expected_message);
+  }
+}
@@ -0,0 +1,348 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::DropIndex::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::DropIndex::new().drop_index("users_login_idx").debug().as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::DropIndex::new().drop_index("users_login_idx").print().as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::DropIndex::new().raw("drop index users_login_idx").as_string();
+    let expected_query = "drop index users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropIndex::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .raw("/* teardown */")
+      .as_string();
+    let expected_query = "/* teardown */ DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::DropIndex::new()
+      .raw_after(sql::DropIndexClause::DropIndex, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::DropIndex::new()
+      .raw_before(sql::DropIndexClause::DropIndex, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_drop_behavior_outside_postgres() {
+    let cascade = sql::DropIndex::new().drop_index("users_login_idx").cascade();
+    let restrict = sql::DropIndex::new().drop_index("users_login_idx").restrict();
+
+    assert_eq!(
+      cascade.as_string_for(sql::Dialect::Sqlite),
+      Err(sql::Error::UnsupportedClause {
+        clause: "CASCADE",
+        dialect: sql::Dialect::Sqlite,
+      })
+    );
+    assert_eq!(
+      restrict.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "RESTRICT",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+    assert_eq!(
+      restrict.clone().on("users").as_string_for(sql::Dialect::MySql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "RESTRICT",
+        dialect: sql::Dialect::MySql,
+      })
+    );
+    assert!(restrict.as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_require_the_on_clause_in_mysql_and_mssql() {
+    let drop_index = sql::DropIndex::new().drop_index("users_login_idx");
+
+    assert_eq!(
+      drop_index.as_string_for(sql::Dialect::MySql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "DROP INDEX WITHOUT ON",
+        dialect: sql::Dialect::MySql,
+      })
+    );
+    assert_eq!(
+      drop_index.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "DROP INDEX WITHOUT ON",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+
+    let query = drop_index.on("users").as_string_for(sql::Dialect::MsSql);
+    let expected_query = Ok("DROP INDEX users_login_idx ON users".to_owned());
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_on_clause_in_postgres_and_sqlite() {
+    let drop_index = sql::DropIndex::new().drop_index("users_login_idx").on("users");
+
+    assert_eq!(
+      drop_index.as_string_for(sql::Dialect::Postgres),
+      Err(sql::Error::UnsupportedClause {
+        clause: "ON",
+        dialect: sql::Dialect::Postgres,
+      })
+    );
+    assert_eq!(
+      drop_index.as_string_for(sql::Dialect::Sqlite),
+      Err(sql::Error::UnsupportedClause {
+        clause: "ON",
+        dialect: sql::Dialect::Sqlite,
+      })
+    );
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_multiple_indexes_outside_postgres() {
+    let drop_index = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .drop_index("orders_user_id_idx");
+
+    assert!(drop_index.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert_eq!(
+      drop_index.as_string_for(sql::Dialect::Sqlite),
+      Err(sql::Error::UnsupportedClause {
+        clause: "MULTIPLE INDEXES",
+        dialect: sql::Dialect::Sqlite,
+      })
+    );
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_if_exists_option_in_mysql() {
+    let drop_index = sql::DropIndex::new()
+      .drop_index_if_exists("users_login_idx")
+      .on("users");
+
+    assert_eq!(
+      drop_index.as_string_for(sql::Dialect::MySql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "IF EXISTS",
+        dialect: sql::Dialect::MySql,
+      })
+    );
+    assert!(drop_index.as_string_for(sql::Dialect::MsSql).is_ok());
+  }
+}
+
+mod drop_index_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_index_should_add_the_drop_index_clause() {
+    let query = sql::DropIndex::new().drop_index("users_login_idx").as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_index_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .drop_index("orders_user_id_idx")
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx, orders_user_id_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_index_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .drop_index("users_login_idx")
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_index_should_trim_space_of_the_argument() {
+    let query = sql::DropIndex::new().drop_index("  users_login_idx  ").as_string();
+    let expected_query = "DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_index_if_exists_should_add_the_if_exists_option() {
+    let query = sql::DropIndex::new()
+      .drop_index_if_exists("users_login_idx")
+      .as_string();
+    let expected_query = "DROP INDEX IF EXISTS users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_index_if_exists_should_apply_the_option_to_all_indexes() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .drop_index_if_exists("orders_user_id_idx")
+      .as_string();
+    let expected_query = "DROP INDEX IF EXISTS users_login_idx, orders_user_id_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_add_the_table_of_the_index() {
+    let query = sql::DropIndex::new()
+      .drop_index_if_exists("users_login_idx")
+      .on("  users  ")
+      .as_string();
+    let expected_query = "DROP INDEX IF EXISTS users_login_idx ON users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_override_value_on_consecutive_calls() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .on("users")
+      .on("accounts")
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx ON accounts";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_add_the_cascade_option() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .cascade()
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx CASCADE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_restrict_should_add_the_restrict_option() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .restrict()
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx RESTRICT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_override_the_restrict_option() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .restrict()
+      .cascade()
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx CASCADE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_restrict_should_override_the_cascade_option() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .cascade()
+      .restrict()
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx RESTRICT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_not_be_rendered_without_the_drop_index_clause() {
+    let query = sql::DropIndex::new().cascade().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_drop_index_clause() {
+    let query = sql::DropIndex::new()
+      .raw_before(sql::DropIndexClause::DropIndex, "/* teardown */")
+      .drop_index("users_login_idx")
+      .as_string();
+    let expected_query = "/* teardown */ DROP INDEX users_login_idx";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_drop_index_clause() {
+    let query = sql::DropIndex::new()
+      .drop_index("users_login_idx")
+      .cascade()
+      .raw_after(sql::DropIndexClause::DropIndex, "/* teardown */")
+      .as_string();
+    let expected_query = "DROP INDEX users_login_idx CASCADE /* teardown */";
+
+    assert_eq!(query, expected_query);
+  }
+}
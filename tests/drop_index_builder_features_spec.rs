@@ -0,0 +1,86 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn drop_index_builder_should_be_displayable() {
+  let drop_index = sql::DropIndex::new().drop_index("users_login_idx");
+
+  println!("{}", drop_index);
+
+  let query = drop_index.as_string();
+  let expected_query = "DROP INDEX users_login_idx";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_index_builder_should_be_debuggable() {
+  let drop_index = sql::DropIndex::new().drop_index("users_login_idx").cascade();
+
+  println!("{:?}", drop_index);
+
+  let expected_query = "DROP INDEX users_login_idx CASCADE";
+  let query = drop_index.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_index_builder_should_be_cloneable() {
+  let drop_one = sql::DropIndex::new()
+    .raw("/* test raw */")
+    .raw_before(sql::DropIndexClause::DropIndex, "/* test raw_before */")
+    .drop_index("users_login_idx")
+    .raw_after(sql::DropIndexClause::DropIndex, "/* test raw_after */");
+
+  let drop_two = drop_one.clone().drop_index("orders_user_id_idx");
+
+  let query_one = drop_one.as_string();
+  let query_two = drop_two.as_string();
+
+  let expected_query_one = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    DROP INDEX users_login_idx \
+    /* test raw_after */\
+  ";
+  let expected_query_two = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    DROP INDEX users_login_idx, orders_user_id_idx \
+    /* test raw_after */\
+  ";
+
+  assert_eq!(query_one, expected_query_one);
+  assert_eq!(query_two, expected_query_two);
+}
+
+#[test]
+fn drop_index_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut drop_index = sql::DropIndex::new().drop_index("users_login_idx");
+
+  if true {
+    drop_index = drop_index.cascade();
+  }
+
+  let query = drop_index.as_string();
+  let expected_query = "DROP INDEX users_login_idx CASCADE";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_index_builder_should_be_composable() {
+  fn teardown(names: &[&str]) -> sql::DropIndex {
+    names.iter().fold(sql::DropIndex::new(), |drop_index, name| {
+      drop_index.drop_index_if_exists(name)
+    })
+  }
+
+  let query = teardown(&["users_login_idx", "orders_user_id_idx"])
+    .cascade()
+    .as_string();
+  let expected_query = "DROP INDEX IF EXISTS users_login_idx, orders_user_id_idx CASCADE";
+
+  assert_eq!(query, expected_query);
+}
@@ -0,0 +1,245 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::DropTable::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::DropTable::new().drop_table("users").debug().as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::DropTable::new().drop_table("users").print().as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::DropTable::new().raw("drop table users").as_string();
+    let expected_query = "drop table users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropTable::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .raw("/* teardown */")
+      .as_string();
+    let expected_query = "/* teardown */ DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::DropTable::new()
+      .raw_after(sql::DropTableClause::DropTable, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::DropTable::new()
+      .raw_before(sql::DropTableClause::DropTable, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_drop_behavior_in_sqlite_and_mssql() {
+    let cascade = sql::DropTable::new().drop_table("users").cascade();
+    let restrict = sql::DropTable::new().drop_table("users").restrict();
+
+    assert_eq!(
+      cascade.as_string_for(sql::Dialect::Sqlite),
+      Err(sql::Error::UnsupportedClause {
+        clause: "CASCADE",
+        dialect: sql::Dialect::Sqlite,
+      })
+    );
+    assert_eq!(
+      restrict.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "RESTRICT",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+    assert!(restrict.as_string_for(sql::Dialect::MySql).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_multiple_tables_in_sqlite() {
+    let drop_table = sql::DropTable::new().drop_table("users").drop_table("orders");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "MULTIPLE TABLES",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(drop_table.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(drop_table.as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+}
+
+mod drop_table_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_table_should_add_the_drop_table_clause() {
+    let query = sql::DropTable::new().drop_table("users").as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_table_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .drop_table("orders")
+      .as_string();
+    let expected_query = "DROP TABLE users, orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_table_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .drop_table("users")
+      .as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_table_should_trim_space_of_the_argument() {
+    let query = sql::DropTable::new().drop_table("  users  ").as_string();
+    let expected_query = "DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_table_if_exists_should_add_the_if_exists_option() {
+    let query = sql::DropTable::new().drop_table_if_exists("users").as_string();
+    let expected_query = "DROP TABLE IF EXISTS users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_table_if_exists_should_apply_the_option_to_all_tables() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .drop_table_if_exists("orders")
+      .as_string();
+    let expected_query = "DROP TABLE IF EXISTS users, orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_add_the_cascade_option() {
+    let query = sql::DropTable::new().drop_table("users").cascade().as_string();
+    let expected_query = "DROP TABLE users CASCADE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_restrict_should_add_the_restrict_option() {
+    let query = sql::DropTable::new().drop_table("users").restrict().as_string();
+    let expected_query = "DROP TABLE users RESTRICT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_override_the_restrict_option() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .restrict()
+      .cascade()
+      .as_string();
+    let expected_query = "DROP TABLE users CASCADE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_restrict_should_override_the_cascade_option() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .cascade()
+      .restrict()
+      .as_string();
+    let expected_query = "DROP TABLE users RESTRICT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_not_be_rendered_without_the_drop_table_clause() {
+    let query = sql::DropTable::new().cascade().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_drop_table_clause() {
+    let query = sql::DropTable::new()
+      .raw_before(sql::DropTableClause::DropTable, "/* teardown */")
+      .drop_table("users")
+      .as_string();
+    let expected_query = "/* teardown */ DROP TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_drop_table_clause() {
+    let query = sql::DropTable::new()
+      .drop_table("users")
+      .cascade()
+      .raw_after(sql::DropTableClause::DropTable, "/* teardown */")
+      .as_string();
+    let expected_query = "DROP TABLE users CASCADE /* teardown */";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,84 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn drop_table_builder_should_be_displayable() {
+  let drop_table = sql::DropTable::new().drop_table("users");
+
+  println!("{}", drop_table);
+
+  let query = drop_table.as_string();
+  let expected_query = "DROP TABLE users";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_table_builder_should_be_debuggable() {
+  let drop_table = sql::DropTable::new().drop_table("users").cascade();
+
+  println!("{:?}", drop_table);
+
+  let expected_query = "DROP TABLE users CASCADE";
+  let query = drop_table.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_table_builder_should_be_cloneable() {
+  let drop_one = sql::DropTable::new()
+    .raw("/* test raw */")
+    .raw_before(sql::DropTableClause::DropTable, "/* test raw_before */")
+    .drop_table("users")
+    .raw_after(sql::DropTableClause::DropTable, "/* test raw_after */");
+
+  let drop_two = drop_one.clone().drop_table("orders");
+
+  let query_one = drop_one.as_string();
+  let query_two = drop_two.as_string();
+
+  let expected_query_one = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    DROP TABLE users \
+    /* test raw_after */\
+  ";
+  let expected_query_two = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    DROP TABLE users, orders \
+    /* test raw_after */\
+  ";
+
+  assert_eq!(query_one, expected_query_one);
+  assert_eq!(query_two, expected_query_two);
+}
+
+#[test]
+fn drop_table_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut drop_table = sql::DropTable::new().drop_table("users");
+
+  if true {
+    drop_table = drop_table.cascade();
+  }
+
+  let query = drop_table.as_string();
+  let expected_query = "DROP TABLE users CASCADE";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_table_builder_should_be_composable() {
+  fn teardown(names: &[&str]) -> sql::DropTable {
+    names.iter().fold(sql::DropTable::new(), |drop_table, name| {
+      drop_table.drop_table_if_exists(name)
+    })
+  }
+
+  let query = teardown(&["users", "orders"]).cascade().as_string();
+  let expected_query = "DROP TABLE IF EXISTS users, orders CASCADE";
+
+  assert_eq!(query, expected_query);
+}
@@ -0,0 +1,247 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::DropView::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::DropView::new().drop_view("active_users").debug().as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::DropView::new().drop_view("active_users").print().as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::DropView::new().raw("drop view active_users").as_string();
+    let expected_query = "drop view active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropView::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .raw("/* teardown */")
+      .as_string();
+    let expected_query = "/* teardown */ DROP VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::DropView::new()
+      .raw_after(sql::DropViewClause::DropView, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::DropView::new()
+      .raw_before(sql::DropViewClause::DropView, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_drop_behavior_in_sqlite_and_mssql() {
+    let cascade = sql::DropView::new().drop_view("active_users").cascade();
+    let restrict = sql::DropView::new().drop_view("active_users").restrict();
+
+    assert_eq!(
+      cascade.as_string_for(sql::Dialect::Sqlite),
+      Err(sql::Error::UnsupportedClause {
+        clause: "CASCADE",
+        dialect: sql::Dialect::Sqlite,
+      })
+    );
+    assert_eq!(
+      restrict.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "RESTRICT",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+    assert!(restrict.as_string_for(sql::Dialect::MySql).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_multiple_views_in_sqlite() {
+    let drop_view = sql::DropView::new()
+      .drop_view("active_users")
+      .drop_view("inactive_users");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "MULTIPLE VIEWS",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(drop_view.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(drop_view.as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+}
+
+mod drop_view_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_drop_view_should_add_the_drop_view_clause() {
+    let query = sql::DropView::new().drop_view("active_users").as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_view_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .drop_view("open_orders")
+      .as_string();
+    let expected_query = "DROP VIEW active_users, open_orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_view_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .drop_view("active_users")
+      .as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_view_should_trim_space_of_the_argument() {
+    let query = sql::DropView::new().drop_view("  active_users  ").as_string();
+    let expected_query = "DROP VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_view_if_exists_should_add_the_if_exists_option() {
+    let query = sql::DropView::new().drop_view_if_exists("active_users").as_string();
+    let expected_query = "DROP VIEW IF EXISTS active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_drop_view_if_exists_should_apply_the_option_to_all_views() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .drop_view_if_exists("open_orders")
+      .as_string();
+    let expected_query = "DROP VIEW IF EXISTS active_users, open_orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_add_the_cascade_option() {
+    let query = sql::DropView::new().drop_view("active_users").cascade().as_string();
+    let expected_query = "DROP VIEW active_users CASCADE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_restrict_should_add_the_restrict_option() {
+    let query = sql::DropView::new().drop_view("active_users").restrict().as_string();
+    let expected_query = "DROP VIEW active_users RESTRICT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_override_the_restrict_option() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .restrict()
+      .cascade()
+      .as_string();
+    let expected_query = "DROP VIEW active_users CASCADE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_restrict_should_override_the_cascade_option() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .cascade()
+      .restrict()
+      .as_string();
+    let expected_query = "DROP VIEW active_users RESTRICT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_not_be_rendered_without_the_drop_view_clause() {
+    let query = sql::DropView::new().cascade().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_drop_view_clause() {
+    let query = sql::DropView::new()
+      .raw_before(sql::DropViewClause::DropView, "/* teardown */")
+      .drop_view("active_users")
+      .as_string();
+    let expected_query = "/* teardown */ DROP VIEW active_users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_drop_view_clause() {
+    let query = sql::DropView::new()
+      .drop_view("active_users")
+      .cascade()
+      .raw_after(sql::DropViewClause::DropView, "/* teardown */")
+      .as_string();
+    let expected_query = "DROP VIEW active_users CASCADE /* teardown */";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,84 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn drop_view_builder_should_be_displayable() {
+  let drop_view = sql::DropView::new().drop_view("active_users");
+
+  println!("{}", drop_view);
+
+  let query = drop_view.as_string();
+  let expected_query = "DROP VIEW active_users";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_view_builder_should_be_debuggable() {
+  let drop_view = sql::DropView::new().drop_view("active_users").cascade();
+
+  println!("{:?}", drop_view);
+
+  let expected_query = "DROP VIEW active_users CASCADE";
+  let query = drop_view.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_view_builder_should_be_cloneable() {
+  let drop_one = sql::DropView::new()
+    .raw("/* test raw */")
+    .raw_before(sql::DropViewClause::DropView, "/* test raw_before */")
+    .drop_view("active_users")
+    .raw_after(sql::DropViewClause::DropView, "/* test raw_after */");
+
+  let drop_two = drop_one.clone().drop_view("open_orders");
+
+  let query_one = drop_one.as_string();
+  let query_two = drop_two.as_string();
+
+  let expected_query_one = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    DROP VIEW active_users \
+    /* test raw_after */\
+  ";
+  let expected_query_two = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    DROP VIEW active_users, open_orders \
+    /* test raw_after */\
+  ";
+
+  assert_eq!(query_one, expected_query_one);
+  assert_eq!(query_two, expected_query_two);
+}
+
+#[test]
+fn drop_view_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut drop_view = sql::DropView::new().drop_view("active_users");
+
+  if true {
+    drop_view = drop_view.cascade();
+  }
+
+  let query = drop_view.as_string();
+  let expected_query = "DROP VIEW active_users CASCADE";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn drop_view_builder_should_be_composable() {
+  fn teardown(names: &[&str]) -> sql::DropView {
+    names.iter().fold(sql::DropView::new(), |drop_view, name| {
+      drop_view.drop_view_if_exists(name)
+    })
+  }
+
+  let query = teardown(&["active_users", "open_orders"]).cascade().as_string();
+  let expected_query = "DROP VIEW IF EXISTS active_users, open_orders CASCADE";
+
+  assert_eq!(query, expected_query);
+}
@@ -0,0 +1,296 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_as_string_should_convert_the_current_state_into_string() {
+    let query = sql::Explain::new()
+      .explain(sql::Select::new().select("*").from("orders"))
+      .as_string();
+    let expected_query = "EXPLAIN SELECT * FROM orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::Explain::new()
+      .explain(sql::Select::new().select("*").from("orders"))
+      .debug()
+      .as_string();
+    let expected_query = "EXPLAIN SELECT * FROM orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::Explain::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::Explain::new()
+      .explain(sql::Select::new().select("*").from("orders"))
+      .print()
+      .as_string();
+    let expected_query = "EXPLAIN SELECT * FROM orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::Explain::new().raw("explain select 1").as_string();
+    let expected_query = "explain select 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Explain::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::Explain::new()
+      .explain(sql::Select::new().select("1"))
+      .raw("/* the slow query */")
+      .as_string();
+    let expected_query = "/* the slow query */ EXPLAIN SELECT 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::Explain::new().raw("  explain select 1  ").as_string();
+    let expected_query = "explain select 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::Explain::new()
+      .raw_after(sql::ExplainClause::Statement, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::Explain::new()
+      .raw_before(sql::ExplainClause::Explain, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_explain_in_mssql() {
+    let explain = sql::Explain::new().explain(sql::Select::new().select("1"));
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "EXPLAIN",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(explain.as_string_for(sql::Dialect::MsSql), expected_error);
+    assert!(explain.as_string_for(sql::Dialect::Sqlite).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_analyze_option_in_sqlite() {
+    let explain = sql::Explain::new().analyze().explain(sql::Select::new().select("1"));
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "ANALYZE",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(explain.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(explain.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert!(explain.as_string_for(sql::Dialect::MySql).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_validate_the_explained_statement() {
+    let explain = sql::Explain::new().explain(
+      sql::Merge::new()
+        .merge_into("orders o")
+        .using("orders_bk b")
+        .on("o.id = b.id")
+        .delete(),
+    );
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "MERGE",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(explain.as_string_for(sql::Dialect::Sqlite), expected_error);
+  }
+}
+
+mod explain_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_explain_should_accept_the_data_modification_builders() {
+    let insert = sql::Explain::new()
+      .explain(sql::Insert::new().insert_into("orders (id)").values("(1)"))
+      .as_string();
+    let update = sql::Explain::new()
+      .explain(sql::Update::new().update("orders").set("paid = true"))
+      .as_string();
+    let delete = sql::Explain::new()
+      .explain(sql::Delete::new().delete_from("orders"))
+      .as_string();
+    let merge = sql::Explain::new()
+      .explain(
+        sql::Merge::new()
+          .merge_into("orders o")
+          .using("orders_bk b")
+          .on("o.id = b.id")
+          .delete(),
+      )
+      .as_string();
+    let values = sql::Explain::new()
+      .explain(sql::Values::new().values("(1)"))
+      .as_string();
+
+    assert_eq!(insert, "EXPLAIN INSERT INTO orders (id) VALUES (1)");
+    assert_eq!(update, "EXPLAIN UPDATE orders SET paid = true");
+    assert_eq!(delete, "EXPLAIN DELETE FROM orders");
+    assert_eq!(
+      merge,
+      "EXPLAIN MERGE INTO orders o USING orders_bk b ON o.id = b.id WHEN MATCHED THEN DELETE"
+    );
+    assert_eq!(values, "EXPLAIN VALUES (1)");
+  }
+
+  #[test]
+  fn method_explain_should_override_value_on_consecutive_calls() {
+    let query = sql::Explain::new()
+      .explain(sql::Select::new().select("1"))
+      .explain(sql::Select::new().select("2"))
+      .as_string();
+    let expected_query = "EXPLAIN SELECT 2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_analyze_should_add_the_analyze_option() {
+    let query = sql::Explain::new()
+      .analyze()
+      .explain(sql::Select::new().select("1"))
+      .as_string();
+    let expected_query = "EXPLAIN ANALYZE SELECT 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_analyze_should_not_be_rendered_without_the_statement() {
+    let query = sql::Explain::new().analyze().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_explain_clause() {
+    let query = sql::Explain::new()
+      .raw_before(sql::ExplainClause::Explain, "/* the slow query */")
+      .explain(sql::Select::new().select("1"))
+      .as_string();
+    let expected_query = "/* the slow query */ EXPLAIN SELECT 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_explain_clause() {
+    let query = sql::Explain::new()
+      .explain(sql::Select::new().select("1"))
+      .raw_after(sql::ExplainClause::Explain, "analyze")
+      .as_string();
+    let expected_query = "EXPLAIN analyze SELECT 1";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod statement_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_statement_clause() {
+    let query = sql::Explain::new()
+      .raw_before(sql::ExplainClause::Statement, "analyze")
+      .explain(sql::Select::new().select("1"))
+      .as_string();
+    let expected_query = "EXPLAIN analyze SELECT 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_statement_clause() {
+    let query = sql::Explain::new()
+      .explain(sql::Select::new().select("1"))
+      .raw_after(sql::ExplainClause::Statement, "/* the slow query */")
+      .as_string();
+    let expected_query = "EXPLAIN SELECT 1 /* the slow query */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod bind_values {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_as_query_should_return_the_values_of_the_explained_statement() {
+    let (query, params) = sql::Explain::new()
+      .explain(
+        sql::Select::new()
+          .select("*")
+          .from("orders")
+          .where_bind("user_id = $1", 42)
+          .and_bind("status = $1", "paid"),
+      )
+      .as_query();
+    let expected_query = "EXPLAIN SELECT * FROM orders WHERE user_id = $1 AND status = $2";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from(42), sql::Value::from("paid")]);
+  }
+
+  #[test]
+  fn method_as_query_with_should_render_the_placeholders_in_the_placeholder_style() {
+    let (query, params) = sql::Explain::new()
+      .explain(sql::Delete::new().delete_from("orders").where_bind("id = $1", 42))
+      .as_query_with(sql::Placeholder::At)
+      .unwrap();
+    let expected_query = "EXPLAIN DELETE FROM orders WHERE id = @p1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from(42)]);
+  }
+}
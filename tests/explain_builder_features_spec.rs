@@ -0,0 +1,102 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn explain_builder_should_be_displayable() {
+  let explain = sql::Explain::new().explain(sql::Select::new().select("*").from("orders"));
+
+  println!("{}", explain);
+
+  let query = explain.as_string();
+  let expected_query = "EXPLAIN SELECT * FROM orders";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn explain_builder_should_be_debuggable() {
+  let explain = sql::Explain::new().analyze().explain(
+    sql::Select::new()
+      .select("*")
+      .from("orders")
+      .where_clause("user_id = 42"),
+  );
+
+  println!("{:?}", explain);
+
+  let expected_query = "EXPLAIN ANALYZE SELECT * FROM orders WHERE user_id = 42";
+  let query = explain.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn explain_builder_should_be_cloneable() {
+  let explain = sql::Explain::new()
+    .raw("/* test raw */")
+    .raw_before(sql::ExplainClause::Explain, "/* test raw_before */")
+    .raw_after(sql::ExplainClause::Statement, "/* test raw_after */")
+    .explain(sql::Select::new().select("*").from("orders"));
+
+  let explain_analyze = explain.clone().analyze();
+
+  let query_explain = explain.as_string();
+  let query_explain_analyze = explain_analyze.as_string();
+
+  let expected_query_explain = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    EXPLAIN SELECT * FROM orders \
+    /* test raw_after */\
+  ";
+  let expected_query_explain_analyze = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    EXPLAIN ANALYZE SELECT * FROM orders \
+    /* test raw_after */\
+  ";
+
+  assert_eq!(query_explain, expected_query_explain);
+  assert_eq!(query_explain_analyze, expected_query_explain_analyze);
+}
+
+#[test]
+fn explain_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut explain = sql::Explain::new().explain(sql::Select::new().select("*").from("orders"));
+
+  if true {
+    explain = explain.analyze();
+  }
+
+  let query = explain.as_string();
+  let expected_query = "EXPLAIN ANALYZE SELECT * FROM orders";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn explain_builder_should_be_composable() {
+  fn slow_query() -> sql::Select<'static> {
+    sql::Select::new()
+      .select("*")
+      .from("orders")
+      .where_clause("created_at > now() - interval '1 day'")
+  }
+
+  fn analyzed(explain: sql::Explain) -> sql::Explain {
+    explain.analyze()
+  }
+
+  fn as_string(explain: sql::Explain) -> String {
+    explain.as_string()
+  }
+
+  let query = Some(sql::Explain::new().explain(slow_query()))
+    .map(analyzed)
+    .map(as_string)
+    .unwrap();
+
+  let expected_query = "EXPLAIN ANALYZE SELECT * FROM orders WHERE created_at > now() - interval '1 day'";
+
+  assert_eq!(query, expected_query);
+}
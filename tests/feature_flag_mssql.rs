@@ -0,0 +1,588 @@
+#[cfg(feature = "mssql")]
+mod fetch_next_clause {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_fetch_next_should_add_the_fetch_next_clause() {
+      let query = sql::Select::new().fetch_next("10").as_string();
+      let expected_query = "FETCH NEXT 10 ROWS ONLY";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_fetch_next_should_override_value_on_consecutive_calls() {
+      let query = sql::Select::new().fetch_next("10").fetch_next("20").as_string();
+      let expected_query = "FETCH NEXT 20 ROWS ONLY";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_fetch_next_should_trim_space_of_the_argument() {
+      let query = sql::Select::new().fetch_next("  10  ").as_string();
+      let expected_query = "FETCH NEXT 10 ROWS ONLY";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_fetch_next_should_render_the_offset_clause_with_the_rows_keyword() {
+      let query = sql::Select::new().offset("20").fetch_next("10").as_string();
+      let expected_query = "OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_fetch_next_should_not_repeat_the_rows_keyword_of_the_offset_clause() {
+      let query = sql::Select::new().offset("1 row").fetch_next("10").as_string();
+      let expected_query = "OFFSET 1 row FETCH NEXT 10 ROWS ONLY";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_offset_should_not_add_the_rows_keyword_without_the_fetch_next_clause() {
+      let query = sql::Select::new().offset("20").as_string();
+      let expected_query = "OFFSET 20";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_fetch_next_should_be_after_offset_clause() {
+      let query = sql::Select::new()
+        .fetch_next("10")
+        .offset("20 ROWS")
+        .order_by("id")
+        .select("*")
+        .from("users")
+        .as_string();
+      let expected_query = "SELECT * FROM users ORDER BY id OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_fetch_next_clause() {
+      let query = sql::Select::new()
+        .raw_before(sql::SelectClause::FetchNext, "offset 0 rows")
+        .fetch_next("10")
+        .as_string();
+      let expected_query = "offset 0 rows FETCH NEXT 10 ROWS ONLY";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_fetch_next_clause() {
+      let query = sql::Select::new()
+        .fetch_next("10")
+        .raw_after(sql::SelectClause::FetchNext, "option (recompile)")
+        .as_string();
+      let expected_query = "FETCH NEXT 10 ROWS ONLY option (recompile)";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mssql")]
+mod output_clause {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_output_should_add_the_output_clause() {
+      let query = sql::Delete::new().output("deleted.*").as_string();
+      let expected_query = "OUTPUT deleted.*";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_output_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Delete::new()
+        .output("deleted.id")
+        .output("deleted.login")
+        .as_string();
+      let expected_query = "OUTPUT deleted.id, deleted.login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_output_should_be_after_delete_from_clause() {
+      let query = sql::Delete::new()
+        .where_clause("active = false")
+        .output("deleted.id")
+        .delete_from("users")
+        .as_string();
+      let expected_query = "DELETE FROM users OUTPUT deleted.id WHERE active = false";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_output_clause() {
+      let query = sql::Delete::new()
+        .raw_before(sql::DeleteClause::Output, "delete from users")
+        .output("deleted.id")
+        .as_string();
+      let expected_query = "delete from users OUTPUT deleted.id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_output_clause() {
+      let query = sql::Delete::new()
+        .output("deleted.id")
+        .raw_after(sql::DeleteClause::Output, "where id = 1")
+        .as_string();
+      let expected_query = "OUTPUT deleted.id where id = 1";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_output_should_add_the_output_clause() {
+      let query = sql::Insert::new().output("inserted.*").as_string();
+      let expected_query = "OUTPUT inserted.*";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_output_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Insert::new()
+        .output("inserted.id")
+        .output("inserted.login")
+        .as_string();
+      let expected_query = "OUTPUT inserted.id, inserted.login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_output_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Insert::new()
+        .output("inserted.id")
+        .output("inserted.id")
+        .as_string();
+      let expected_query = "OUTPUT inserted.id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_output_should_be_before_values_clause() {
+      let query = sql::Insert::new()
+        .values("('foo')")
+        .output("inserted.id")
+        .insert_into("users (login)")
+        .as_string();
+      let expected_query = "INSERT INTO users (login) OUTPUT inserted.id VALUES ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_output_should_be_before_select_clause() {
+      let query = sql::Insert::new()
+        .insert_into("users (login)")
+        .select(sql::Select::new().select("login").from("users_bk"))
+        .output("inserted.id")
+        .as_string();
+      let expected_query = "INSERT INTO users (login) OUTPUT inserted.id SELECT login FROM users_bk";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_output_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::Output, "insert into users (login)")
+        .output("inserted.id")
+        .as_string();
+      let expected_query = "insert into users (login) OUTPUT inserted.id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_output_clause() {
+      let query = sql::Insert::new()
+        .output("inserted.id")
+        .raw_after(sql::InsertClause::Output, "values ('foo')")
+        .as_string();
+      let expected_query = "OUTPUT inserted.id values ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_output_should_add_the_output_clause() {
+      let query = sql::Update::new().output("inserted.*").as_string();
+      let expected_query = "OUTPUT inserted.*";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_output_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Update::new()
+        .output("deleted.name")
+        .output("inserted.name")
+        .as_string();
+      let expected_query = "OUTPUT deleted.name, inserted.name";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_output_should_be_after_set_clause() {
+      let query = sql::Update::new()
+        .where_clause("login = 'foo'")
+        .output("inserted.name")
+        .set("name = 'Foo'")
+        .update("users")
+        .as_string();
+      let expected_query = "UPDATE users SET name = 'Foo' OUTPUT inserted.name WHERE login = 'foo'";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_output_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::Output, "set name = 'Foo'")
+        .output("inserted.name")
+        .as_string();
+      let expected_query = "set name = 'Foo' OUTPUT inserted.name";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_output_clause() {
+      let query = sql::Update::new()
+        .output("inserted.name")
+        .raw_after(sql::UpdateClause::Output, "where id = 1")
+        .as_string();
+      let expected_query = "OUTPUT inserted.name where id = 1";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mssql")]
+mod table_hint_clause {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_table_hint_should_add_the_table_hint_clause() {
+      let query = sql::Delete::new()
+        .delete_from("users")
+        .table_hint("ROWLOCK")
+        .as_string();
+      let expected_query = "DELETE FROM users WITH (ROWLOCK)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_table_hint_should_be_before_output_clause() {
+      let query = sql::Delete::new()
+        .output("deleted.id")
+        .table_hint("ROWLOCK")
+        .delete_from("users")
+        .as_string();
+      let expected_query = "DELETE FROM users WITH (ROWLOCK) OUTPUT deleted.id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_table_hint_clause() {
+      let query = sql::Delete::new()
+        .table_hint("ROWLOCK")
+        .raw_after(sql::DeleteClause::TableHint, "where id = 1")
+        .as_string();
+      let expected_query = "WITH (ROWLOCK) where id = 1";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_table_hint_should_add_the_table_hint_clause() {
+      let query = sql::Select::new().table_hint("NOLOCK").as_string();
+      let expected_query = "WITH (NOLOCK)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_table_hint_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Select::new()
+        .table_hint("NOLOCK")
+        .table_hint("INDEX(0)")
+        .as_string();
+      let expected_query = "WITH (NOLOCK, INDEX(0))";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_table_hint_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Select::new().table_hint("NOLOCK").table_hint("NOLOCK").as_string();
+      let expected_query = "WITH (NOLOCK)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_table_hint_should_trim_space_of_the_argument() {
+      let query = sql::Select::new().table_hint("  NOLOCK  ").as_string();
+      let expected_query = "WITH (NOLOCK)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_table_hint_should_be_after_from_clause() {
+      let query = sql::Select::new()
+        .inner_join("orders o on o.user_id = u.id")
+        .table_hint("NOLOCK")
+        .from("users u")
+        .select("*")
+        .as_string();
+      let expected_query = "SELECT * FROM users u WITH (NOLOCK) INNER JOIN orders o on o.user_id = u.id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_table_hint_clause() {
+      let query = sql::Select::new()
+        .raw_before(sql::SelectClause::TableHint, "from users")
+        .table_hint("NOLOCK")
+        .as_string();
+      let expected_query = "from users WITH (NOLOCK)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_table_hint_clause() {
+      let query = sql::Select::new()
+        .table_hint("NOLOCK")
+        .raw_after(sql::SelectClause::TableHint, "where id = 1")
+        .as_string();
+      let expected_query = "WITH (NOLOCK) where id = 1";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_table_hint_should_add_the_table_hint_clause() {
+      let query = sql::Update::new().update("users").table_hint("ROWLOCK").as_string();
+      let expected_query = "UPDATE users WITH (ROWLOCK)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_table_hint_should_be_before_set_clause() {
+      let query = sql::Update::new()
+        .set("active = false")
+        .table_hint("ROWLOCK")
+        .update("users")
+        .as_string();
+      let expected_query = "UPDATE users WITH (ROWLOCK) SET active = false";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_table_hint_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::TableHint, "update users")
+        .table_hint("ROWLOCK")
+        .as_string();
+      let expected_query = "update users WITH (ROWLOCK)";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mssql")]
+mod top_clause {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_top_should_add_the_top_option_to_the_select_clause() {
+      let query = sql::Select::new().select("id").top("10").as_string();
+      let expected_query = "SELECT TOP 10 id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_top_should_override_value_on_consecutive_calls() {
+      let query = sql::Select::new().select("id").top("10").top("(5) PERCENT").as_string();
+      let expected_query = "SELECT TOP (5) PERCENT id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_top_should_render_before_all_columns_regardless_of_the_call_order() {
+      let query = sql::Select::new()
+        .select("id")
+        .top("10")
+        .select("login")
+        .from("users")
+        .as_string();
+      let expected_query = "SELECT TOP 10 id, login FROM users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_top_should_not_render_without_the_select_clause() {
+      let query = sql::Select::new().top("10").from("users").as_string();
+      let expected_query = "FROM users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_top_should_render_after_the_distinct_option() {
+      let query = sql::Select::new().top("10").select("login").distinct().as_string();
+      let expected_query = "SELECT DISTINCT TOP 10 login";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mssql")]
+mod dialect_validation {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_top_clause_outside_mssql() {
+      let select = sql::Select::new().select("*").top("10").from("users");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "TOP",
+        dialect: sql::Dialect::MySql,
+      });
+
+      assert_eq!(select.as_string_for(sql::Dialect::MySql), expected_error);
+      assert!(select.as_string_for(sql::Dialect::MsSql).is_ok());
+    }
+
+    #[test]
+    fn method_as_string_for_should_accept_the_fetch_next_clause_in_postgres_and_mssql() {
+      let select = sql::Select::new().offset("10 ROWS").fetch_next("10");
+
+      assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+      assert!(select.as_string_for(sql::Dialect::MsSql).is_ok());
+      assert!(select.as_string_for(sql::Dialect::Sqlite).is_err());
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_fetch_next_clause_without_offset_in_mssql() {
+      let select = sql::Select::new().select("*").from("users").fetch_next("10");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "FETCH NEXT WITHOUT OFFSET",
+        dialect: sql::Dialect::MsSql,
+      });
+
+      assert_eq!(select.as_string_for(sql::Dialect::MsSql), expected_error);
+      assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_limit_clause_with_fetch_next() {
+      let select = sql::Select::new().select("*").from("users").limit("5").fetch_next("10");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "LIMIT WITH FETCH NEXT",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(select.as_string_for(sql::Dialect::Postgres), expected_error);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_top_clause_with_offset_or_fetch_next() {
+      let with_offset = sql::Select::new().select("*").top("10").from("users").offset("5");
+      let with_fetch_next = sql::Select::new()
+        .select("*")
+        .top("10")
+        .from("users")
+        .offset("5")
+        .fetch_next("10");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "TOP WITH OFFSET",
+        dialect: sql::Dialect::MsSql,
+      });
+
+      assert_eq!(with_offset.as_string_for(sql::Dialect::MsSql), expected_error);
+      assert_eq!(with_fetch_next.as_string_for(sql::Dialect::MsSql), expected_error);
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_output_clause_outside_mssql() {
+      let update = sql::Update::new()
+        .update("users")
+        .set("active = false")
+        .output("inserted.id");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "OUTPUT",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(update.as_string_for(sql::Dialect::Postgres), expected_error);
+      assert!(update.as_string_for(sql::Dialect::MsSql).is_ok());
+    }
+  }
+}
@@ -0,0 +1,558 @@
+#[cfg(feature = "mysql")]
+mod insert_ignore_into_clause {
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_insert_ignore_into_should_add_the_insert_ignore_into_clause() {
+      let query = sql::Insert::new().insert_ignore_into("users (login)").as_string();
+      let expected_query = "INSERT IGNORE INTO users (login)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_insert_ignore_into_should_override_value_on_consecutive_calls() {
+      let query = sql::Insert::new()
+        .insert_ignore_into("users (login)")
+        .insert_ignore_into("orders (id)")
+        .as_string();
+      let expected_query = "INSERT IGNORE INTO orders (id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_insert_ignore_into_should_trim_space_of_the_argument() {
+      let query = sql::Insert::new().insert_ignore_into("  users (name)  ").as_string();
+      let expected_query = "INSERT IGNORE INTO users (name)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_insert_ignore_into_should_be_before_values_clause() {
+      let query = sql::Insert::new()
+        .values("('foo')")
+        .insert_ignore_into("users (login)")
+        .as_string();
+      let expected_query = "INSERT IGNORE INTO users (login) VALUES ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_insert_ignore_into_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::InsertIgnoreInto, "/* insert ignore */")
+        .insert_ignore_into("users")
+        .as_string();
+      let expected_query = "/* insert ignore */ INSERT IGNORE INTO users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_insert_ignore_into_clause() {
+      let query = sql::Insert::new()
+        .insert_ignore_into("users (name)")
+        .raw_after(sql::InsertClause::InsertIgnoreInto, "values ('foo')")
+        .as_string();
+      let expected_query = "INSERT IGNORE INTO users (name) values ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod limit_clause {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_limit_should_add_the_limit_clause() {
+      let query = sql::Delete::new().limit("10").as_string();
+      let expected_query = "LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_limit_should_override_value_on_consecutive_calls() {
+      let query = sql::Delete::new().limit("10").limit("100").as_string();
+      let expected_query = "LIMIT 100";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_limit_should_be_after_order_by_clause() {
+      let query = sql::Delete::new()
+        .limit("10")
+        .delete_from("sessions")
+        .where_clause("expired = true")
+        .order_by("created_at")
+        .as_string();
+      let expected_query = "DELETE FROM sessions WHERE expired = true ORDER BY created_at LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_limit_clause() {
+      let query = sql::Delete::new()
+        .raw_before(sql::DeleteClause::Limit, "order by id")
+        .limit("10")
+        .as_string();
+      let expected_query = "order by id LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_limit_clause() {
+      let query = sql::Delete::new()
+        .limit("10")
+        .raw_after(sql::DeleteClause::Limit, "/* end */")
+        .as_string();
+      let expected_query = "LIMIT 10 /* end */";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_limit_should_accept_the_offset_and_count_form() {
+      let query = sql::Select::new().select("*").from("users").limit("20, 10").as_string();
+      let expected_query = "SELECT * FROM users LIMIT 20, 10";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_limit_should_add_the_limit_clause() {
+      let query = sql::Update::new().limit("10").as_string();
+      let expected_query = "LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_limit_should_override_value_on_consecutive_calls() {
+      let query = sql::Update::new().limit("10").limit("100").as_string();
+      let expected_query = "LIMIT 100";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_limit_should_be_after_order_by_clause() {
+      let query = sql::Update::new()
+        .limit("10")
+        .update("users")
+        .set("active = false")
+        .where_clause("login = 'foo'")
+        .order_by("created_at")
+        .as_string();
+      let expected_query = "UPDATE users SET active = false WHERE login = 'foo' ORDER BY created_at LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_limit_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::Limit, "order by id")
+        .limit("10")
+        .as_string();
+      let expected_query = "order by id LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_limit_clause() {
+      let query = sql::Update::new()
+        .limit("10")
+        .raw_after(sql::UpdateClause::Limit, "/* end */")
+        .as_string();
+      let expected_query = "LIMIT 10 /* end */";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod on_duplicate_key_update_clause {
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_on_duplicate_key_update_should_add_the_on_duplicate_key_update_clause() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("name = VALUES(name)")
+        .as_string();
+      let expected_query = "ON DUPLICATE KEY UPDATE name = VALUES(name)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_on_duplicate_key_update_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("name = VALUES(name)")
+        .on_duplicate_key_update("updated_at = now()")
+        .as_string();
+      let expected_query = "ON DUPLICATE KEY UPDATE name = VALUES(name), updated_at = now()";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_on_duplicate_key_update_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("name = 'Foo'")
+        .on_duplicate_key_update("name = 'Foo'")
+        .as_string();
+      let expected_query = "ON DUPLICATE KEY UPDATE name = 'Foo'";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_on_duplicate_key_update_should_trim_space_of_the_argument() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("  name = 'Foo'  ")
+        .as_string();
+      let expected_query = "ON DUPLICATE KEY UPDATE name = 'Foo'";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_on_duplicate_key_update_should_be_after_values_clause() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("name = VALUES(name)")
+        .insert_into("users (login, name)")
+        .values("('foo', 'Foo')")
+        .as_string();
+      let expected_query =
+        "INSERT INTO users (login, name) VALUES ('foo', 'Foo') ON DUPLICATE KEY UPDATE name = VALUES(name)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_on_duplicate_key_update_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::OnDuplicateKeyUpdate, "values ('foo')")
+        .on_duplicate_key_update("login = 'bar'")
+        .as_string();
+      let expected_query = "values ('foo') ON DUPLICATE KEY UPDATE login = 'bar'";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_on_duplicate_key_update_clause() {
+      let query = sql::Insert::new()
+        .on_duplicate_key_update("login = 'bar'")
+        .raw_after(sql::InsertClause::OnDuplicateKeyUpdate, "/* end */")
+        .as_string();
+      let expected_query = "ON DUPLICATE KEY UPDATE login = 'bar' /* end */";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod order_by_clause {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_order_by_should_add_the_order_by_clause() {
+      let query = sql::Delete::new().order_by("id desc").as_string();
+      let expected_query = "ORDER BY id desc";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_order_by_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Delete::new().order_by("login asc").order_by("id desc").as_string();
+      let expected_query = "ORDER BY login asc, id desc";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_order_by_should_be_after_where_clause() {
+      let query = sql::Delete::new()
+        .order_by("id")
+        .delete_from("users")
+        .where_clause("active = false")
+        .as_string();
+      let expected_query = "DELETE FROM users WHERE active = false ORDER BY id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_order_by_clause() {
+      let query = sql::Delete::new()
+        .raw_before(sql::DeleteClause::OrderBy, "where active = false")
+        .order_by("id")
+        .as_string();
+      let expected_query = "where active = false ORDER BY id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_order_by_clause() {
+      let query = sql::Delete::new()
+        .order_by("id")
+        .raw_after(sql::DeleteClause::OrderBy, "limit 10")
+        .as_string();
+      let expected_query = "ORDER BY id limit 10";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_order_by_should_add_the_order_by_clause() {
+      let query = sql::Update::new().order_by("id desc").as_string();
+      let expected_query = "ORDER BY id desc";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_order_by_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Update::new().order_by("login asc").order_by("id desc").as_string();
+      let expected_query = "ORDER BY login asc, id desc";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_order_by_should_be_after_where_clause() {
+      let query = sql::Update::new()
+        .order_by("id")
+        .update("users")
+        .set("active = false")
+        .where_clause("login = 'foo'")
+        .as_string();
+      let expected_query = "UPDATE users SET active = false WHERE login = 'foo' ORDER BY id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_order_by_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::OrderBy, "where active = false")
+        .order_by("id")
+        .as_string();
+      let expected_query = "where active = false ORDER BY id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_order_by_clause() {
+      let query = sql::Update::new()
+        .order_by("id")
+        .raw_after(sql::UpdateClause::OrderBy, "limit 10")
+        .as_string();
+      let expected_query = "ORDER BY id limit 10";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod replace_into_clause {
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_replace_into_should_add_the_replace_into_clause() {
+      let query = sql::Insert::new()
+        .replace_into("users (login, name)")
+        .values("('foo', 'Foo')")
+        .as_string();
+      let expected_query = "REPLACE INTO users (login, name) VALUES ('foo', 'Foo')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_replace_into_clause() {
+      let query = sql::Insert::new()
+        .replace_into("users (name)")
+        .raw_after(sql::InsertClause::ReplaceInto, "values ('foo')")
+        .as_string();
+      let expected_query = "REPLACE INTO users (name) values ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod dialect_validation {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_limit_clause_outside_mysql() {
+      let delete = sql::Delete::new().delete_from("sessions").limit("10");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "LIMIT",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(delete.as_string_for(sql::Dialect::Postgres), expected_error);
+      assert!(delete.as_string_for(sql::Dialect::MySql).is_ok());
+    }
+  }
+
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_on_duplicate_key_update_clause_outside_mysql() {
+      let insert = sql::Insert::new()
+        .insert_into("users (login)")
+        .on_duplicate_key_update("login = 'foo'");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "ON DUPLICATE KEY UPDATE",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert_eq!(insert.as_string_for(sql::Dialect::Sqlite), expected_error);
+      assert!(insert.as_string_for(sql::Dialect::MySql).is_ok());
+    }
+
+    #[test]
+    fn method_as_string_for_should_accept_the_replace_into_clause_in_mysql() {
+      let insert = sql::Insert::new().replace_into("users (login)");
+
+      assert!(insert.as_string_for(sql::Dialect::MySql).is_ok());
+      assert!(insert.as_string_for(sql::Dialect::Postgres).is_err());
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_more_than_one_insert_clause() {
+      let insert = sql::Insert::new()
+        .insert_ignore_into("users (login)")
+        .replace_into("users (login)");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "MULTIPLE INSERT CLAUSES",
+        dialect: sql::Dialect::MySql,
+      });
+
+      assert_eq!(insert.as_string_for(sql::Dialect::MySql), expected_error);
+    }
+  }
+}
+
+#[cfg(feature = "mysql")]
+mod explain_format {
+  mod explain_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_format_should_add_the_format_option() {
+      let query = sql::Explain::new()
+        .format(sql::ExplainFormat::Json)
+        .explain(sql::Select::new().select("1"))
+        .as_string();
+      let expected_query = "EXPLAIN FORMAT=JSON SELECT 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_format_should_override_value_on_consecutive_calls() {
+      let query = sql::Explain::new()
+        .format(sql::ExplainFormat::Json)
+        .format(sql::ExplainFormat::Traditional)
+        .explain(sql::Select::new().select("1"))
+        .as_string();
+      let expected_query = "EXPLAIN FORMAT=TRADITIONAL SELECT 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn option_format_should_be_after_analyze() {
+      let query = sql::Explain::new()
+        .format(sql::ExplainFormat::Tree)
+        .analyze()
+        .explain(sql::Select::new().select("1"))
+        .as_string();
+      let expected_query = "EXPLAIN ANALYZE FORMAT=TREE SELECT 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_format_outside_postgres_and_mysql() {
+      let explain = sql::Explain::new()
+        .format(sql::ExplainFormat::Json)
+        .explain(sql::Select::new().select("1"));
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "FORMAT JSON",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert_eq!(explain.as_string_for(sql::Dialect::Sqlite), expected_error);
+      assert_eq!(
+        explain.as_string_for(sql::Dialect::MySql),
+        Ok("EXPLAIN FORMAT=JSON SELECT 1".to_owned())
+      );
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_postgres_formats_in_mysql() {
+      let explain = sql::Explain::new()
+        .format(sql::ExplainFormat::Yaml)
+        .explain(sql::Select::new().select("1"));
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "FORMAT YAML",
+        dialect: sql::Dialect::MySql,
+      });
+
+      assert_eq!(explain.as_string_for(sql::Dialect::MySql), expected_error);
+    }
+  }
+}
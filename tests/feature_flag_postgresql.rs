@@ -657,6 +657,104 @@ mod with_clause {
 
       assert_eq!(query, expected_query);
     }
+
+    #[test]
+    fn method_with_should_renumber_the_placeholders_of_the_query_argument_past_the_placeholders_of_the_builder() {
+      let select_users = sql::Select::new().select("login").from("users").where_clause("id = $1");
+      let query = sql::Select::new()
+        .with("user_list", select_users)
+        .select("*")
+        .from("orders")
+        .where_clause("owner_login in (select login from user_list)")
+        .and("status = $1")
+        .as_string();
+      let expected_query = "\
+        WITH user_list AS (SELECT login FROM users WHERE id = $2) \
+        SELECT * \
+        FROM orders \
+        WHERE owner_login in (select login from user_list) AND status = $1\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_renumber_each_query_argument_past_the_previous_one() {
+      let (query, params) = sql::Select::new()
+        .with(
+          "a",
+          sql::Select::new()
+            .select("1")
+            .where_bind("x = $1", "a1")
+            .and_bind("y = $1", "a2"),
+        )
+        .with("b", sql::Select::new().select("2").where_bind("x = $1", "b1"))
+        .select("*")
+        .where_bind("z = $1", "outer")
+        .as_query();
+      let expected_query = "\
+        WITH a AS (SELECT 1 WHERE x = $2 AND y = $3), b AS (SELECT 2 WHERE x = $4) \
+        SELECT * \
+        WHERE z = $1\
+      ";
+      let expected_params = vec![
+        sql::Value::from("outer"),
+        sql::Value::from("a1"),
+        sql::Value::from("a2"),
+        sql::Value::from("b1"),
+      ];
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, expected_params);
+    }
+
+    #[test]
+    fn method_as_query_with_should_follow_the_question_mark_placeholders_across_the_with_queries() {
+      let (query, params) = sql::Select::new()
+        .with(
+          "owners",
+          sql::Select::new().select("login").where_bind("active = $1", true),
+        )
+        .select("*")
+        .from("orders")
+        .where_bind("status = $1", "paid")
+        .as_query_with(sql::Placeholder::QuestionMark)
+        .unwrap();
+      let expected_query = "\
+        WITH owners AS (SELECT login WHERE active = ?) \
+        SELECT * \
+        FROM orders \
+        WHERE status = ?\
+      ";
+      let expected_params = vec![sql::Value::from(true), sql::Value::from("paid")];
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, expected_params);
+    }
+
+    #[test]
+    fn method_as_query_named_should_resolve_the_names_across_the_with_queries() {
+      let named = std::collections::HashMap::from([("tenant", sql::Value::from(42))]);
+      let (query, params) = sql::Select::new()
+        .with(
+          "owners",
+          sql::Select::new().select("login").where_clause("tenant_id = :tenant"),
+        )
+        .select("*")
+        .from("orders")
+        .where_clause("tenant_id = :tenant")
+        .as_query_named(&named, sql::Placeholder::Dollar)
+        .unwrap();
+      let expected_query = "\
+        WITH owners AS (SELECT login WHERE tenant_id = $1) \
+        SELECT * \
+        FROM orders \
+        WHERE tenant_id = $1\
+      ";
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, vec![sql::Value::from(42)]);
+    }
   }
 
   mod update_builder {
@@ -883,6 +981,37 @@ mod except_clause {
 
       assert_eq!(query, expected_query);
     }
+
+    #[test]
+    fn method_except_all_should_add_the_except_all_clause() {
+      let query = sql::Select::new()
+        .select("login")
+        .from("users")
+        .except_all(sql::Select::new().select("login").from("address"))
+        .as_string();
+      let expected_query = "(SELECT login FROM users) EXCEPT ALL (SELECT login FROM address)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_except_all_should_keep_the_call_order_with_the_except_method() {
+      let query = sql::Select::new()
+        .select("login")
+        .from("users")
+        .except_all(sql::Select::new().select("login").from("address"))
+        .except(sql::Select::new().select("login").from("orders"))
+        .as_string();
+      let expected_query = "\
+        (SELECT login FROM users) \
+        EXCEPT ALL \
+        (SELECT login FROM address) \
+        EXCEPT \
+        (SELECT login FROM orders)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
   }
 }
 
@@ -968,6 +1097,18 @@ mod intersect_clause {
 
       assert_eq!(query, expected_query);
     }
+
+    #[test]
+    fn method_intersect_all_should_add_the_intersect_all_clause() {
+      let query = sql::Select::new()
+        .select("login")
+        .from("users")
+        .intersect_all(sql::Select::new().select("login").from("address"))
+        .as_string();
+      let expected_query = "(SELECT login FROM users) INTERSECT ALL (SELECT login FROM address)";
+
+      assert_eq!(query, expected_query);
+    }
   }
 }
 
@@ -1050,5 +1191,1001 @@ mod union_clause {
 
       assert_eq!(query, expected_query);
     }
+
+    #[test]
+    fn method_union_should_renumber_the_placeholders_of_the_select_argument() {
+      let (query, params) = sql::Select::new()
+        .select("login")
+        .from("users")
+        .where_bind("login = $1", "foo")
+        .union(
+          sql::Select::new()
+            .select("login")
+            .from("users_bk")
+            .where_bind("login = $1", "bar")
+            .with("ids", sql::Select::new().select("id").where_bind("id > $1", 10)),
+        )
+        .as_query();
+      let expected_query = "\
+        (SELECT login FROM users WHERE login = $1) \
+        UNION \
+        (WITH ids AS (SELECT id WHERE id > $3) SELECT login FROM users_bk WHERE login = $2)\
+      ";
+      let expected_params = vec![sql::Value::from("foo"), sql::Value::from("bar"), sql::Value::from(10)];
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, expected_params);
+    }
+
+    #[test]
+    fn method_union_all_should_add_the_union_all_clause() {
+      let query = sql::Select::new()
+        .select("login")
+        .from("users")
+        .union_all(sql::Select::new().select("login").from("address"))
+        .as_string();
+      let expected_query = "(SELECT login FROM users) UNION ALL (SELECT login FROM address)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_union_all_should_keep_the_call_order_with_the_union_method() {
+      let query = sql::Select::new()
+        .select("login")
+        .from("users")
+        .union(sql::Select::new().select("login").from("address"))
+        .union_all(sql::Select::new().select("login").from("orders"))
+        .as_string();
+      let expected_query = "\
+        (SELECT login FROM users) \
+        UNION \
+        (SELECT login FROM address) \
+        UNION ALL \
+        (SELECT login FROM orders)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_union_all_should_renumber_the_placeholders_of_the_select_argument() {
+      let (query, params) = sql::Select::new()
+        .select("login")
+        .from("users")
+        .where_bind("login = $1", "foo")
+        .union_all(
+          sql::Select::new()
+            .select("login")
+            .from("users_bk")
+            .where_bind("login = $1", "bar"),
+        )
+        .as_query();
+      let expected_query = "\
+        (SELECT login FROM users WHERE login = $1) \
+        UNION ALL \
+        (SELECT login FROM users_bk WHERE login = $2)\
+      ";
+      let expected_params = vec![sql::Value::from("foo"), sql::Value::from("bar")];
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, expected_params);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_lock_clause_with_the_union_clause() {
+      let locked_left = sql::Select::new()
+        .select("id")
+        .from("jobs")
+        .for_update()
+        .union(sql::Select::new().select("id").from("jobs_bk"));
+      let locked_right = sql::Select::new()
+        .select("id")
+        .from("jobs")
+        .union(sql::Select::new().select("id").from("jobs_bk").for_share());
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "LOCK WITH COMPOUND QUERY",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(locked_left.as_string_for(sql::Dialect::Postgres), expected_error);
+      assert_eq!(locked_right.as_string_for(sql::Dialect::Postgres), expected_error);
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod compound_clause {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_compound_order_by_should_order_the_result_of_the_combinators() {
+      let query = sql::Select::new()
+        .select("login")
+        .from("users")
+        .order_by("created_at")
+        .limit("5")
+        .union_all(sql::Select::new().select("login").from("users_bk"))
+        .compound_order_by("login")
+        .as_string();
+      let expected_query = "\
+        (SELECT login FROM users ORDER BY created_at LIMIT 5) \
+        UNION ALL \
+        (SELECT login FROM users_bk) \
+        ORDER BY login\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_compound_order_by_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Select::new()
+        .select("login, name")
+        .union(sql::Select::new().select("login, name"))
+        .compound_order_by("login")
+        .compound_order_by("name desc")
+        .compound_order_by("login")
+        .as_string();
+      let expected_query = "(SELECT login, name) UNION (SELECT login, name) ORDER BY login, name desc";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_compound_limit_should_override_the_previous_value() {
+      let query = sql::Select::new()
+        .select("login")
+        .intersect(sql::Select::new().select("login"))
+        .compound_limit("10")
+        .compound_limit("  20  ")
+        .as_string();
+      let expected_query = "(SELECT login) INTERSECT (SELECT login) LIMIT 20";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_compound_limit_should_be_after_compound_order_by_clause() {
+      let query = sql::Select::new()
+        .select("login")
+        .compound_limit("10")
+        .except(sql::Select::new().select("login").from("banned_users"))
+        .compound_order_by("login")
+        .as_string();
+      let expected_query = "(SELECT login) EXCEPT (SELECT login FROM banned_users) ORDER BY login LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_compound_order_by_clause() {
+      let query = sql::Select::new()
+        .select("login")
+        .union(sql::Select::new().select("login"))
+        .compound_order_by("login")
+        .raw_before(sql::SelectClause::CompoundOrderBy, "/* the logins */")
+        .as_string();
+      let expected_query = "(SELECT login) UNION (SELECT login) /* the logins */ ORDER BY login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_compound_limit_clause() {
+      let query = sql::Select::new()
+        .select("login")
+        .union(sql::Select::new().select("login"))
+        .compound_limit("10")
+        .raw_after(sql::SelectClause::CompoundLimit, "offset 20")
+        .as_string();
+      let expected_query = "(SELECT login) UNION (SELECT login) LIMIT 10 offset 20";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_compound_limit_in_mssql() {
+      let select = sql::Select::new()
+        .select("login")
+        .union_all(sql::Select::new().select("login"))
+        .compound_limit("10");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "LIMIT",
+        dialect: sql::Dialect::MsSql,
+      });
+
+      assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+      assert_eq!(select.as_string_for(sql::Dialect::MsSql), expected_error);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_except_all_and_intersect_all_in_sqlite() {
+      let except_all = sql::Select::new()
+        .select("login")
+        .except_all(sql::Select::new().select("login"));
+      let intersect_all = sql::Select::new()
+        .select("login")
+        .intersect_all(sql::Select::new().select("login"));
+
+      assert!(except_all.as_string_for(sql::Dialect::MySql).is_ok());
+      assert_eq!(
+        except_all.as_string_for(sql::Dialect::Sqlite),
+        Err(sql::Error::UnsupportedClause {
+          clause: "EXCEPT ALL",
+          dialect: sql::Dialect::Sqlite,
+        })
+      );
+      assert_eq!(
+        intersect_all.as_string_for(sql::Dialect::Sqlite),
+        Err(sql::Error::UnsupportedClause {
+          clause: "INTERSECT ALL",
+          dialect: sql::Dialect::Sqlite,
+        })
+      );
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod dialect_validation {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_returning_clause_in_mysql() {
+      let delete = sql::Delete::new().delete_from("users").returning("id");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "RETURNING",
+        dialect: sql::Dialect::MySql,
+      });
+
+      assert_eq!(delete.as_string_for(sql::Dialect::MySql), expected_error);
+      assert!(delete.as_string_for(sql::Dialect::Postgres).is_ok());
+    }
+  }
+
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_as_string_for_should_validate_the_with_clause_queries() {
+      let users = sql::Select::new().select("*").from("users").limit("10");
+      let select = sql::Select::new().with("users", users).select("*").from("users");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "LIMIT",
+        dialect: sql::Dialect::MsSql,
+      });
+
+      assert_eq!(select.as_string_for(sql::Dialect::MsSql), expected_error);
+    }
+
+    #[test]
+    fn method_as_string_for_should_validate_the_union_queries() {
+      let select = sql::Select::new()
+        .select("login")
+        .from("users")
+        .union(sql::Select::new().select("login").from("users_bk").limit("1"));
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "LIMIT",
+        dialect: sql::Dialect::MsSql,
+      });
+
+      assert_eq!(select.as_string_for(sql::Dialect::MsSql), expected_error);
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod create_table_options {
+  mod create_table_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_inherits_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::CreateTable::new().inherits("users").inherits("audit").as_string();
+      let expected_query = "INHERITS (users, audit)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_partition_by_should_override_value_on_consecutive_calls() {
+      let query = sql::CreateTable::new()
+        .partition_by("list (kind)")
+        .partition_by("range (created_at)")
+        .as_string();
+      let expected_query = "PARTITION BY range (created_at)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_tablespace_should_override_value_on_consecutive_calls() {
+      let query = sql::CreateTable::new()
+        .tablespace("slow_storage")
+        .tablespace("fast_storage")
+        .as_string();
+      let expected_query = "TABLESPACE fast_storage";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn options_should_be_after_the_table_definitions() {
+      let query = sql::CreateTable::new()
+        .tablespace("fast_storage")
+        .partition_by("range (created_at)")
+        .inherits("events")
+        .column("created_at date")
+        .create_table("logs")
+        .as_string();
+      let expected_query = "\
+        CREATE TABLE logs (created_at date) \
+        INHERITS (events) \
+        PARTITION BY range (created_at) \
+        TABLESPACE fast_storage\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_partition_by_clause() {
+      let query = sql::CreateTable::new()
+        .raw_before(sql::CreateTableClause::PartitionBy, "/* partitioned */")
+        .partition_by("range (created_at)")
+        .as_string();
+      let expected_query = "/* partitioned */ PARTITION BY range (created_at)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_tablespace_clause() {
+      let query = sql::CreateTable::new()
+        .tablespace("fast_storage")
+        .raw_after(sql::CreateTableClause::Tablespace, "/* end */")
+        .as_string();
+      let expected_query = "TABLESPACE fast_storage /* end */";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_postgres_options_outside_postgres() {
+      let create_table = sql::CreateTable::new().create_table("admins").inherits("users");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "INHERITS",
+        dialect: sql::Dialect::MySql,
+      });
+
+      assert_eq!(create_table.as_string_for(sql::Dialect::MySql), expected_error);
+      assert!(create_table.as_string_for(sql::Dialect::Postgres).is_ok());
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod restart_identity_option {
+  mod truncate_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_restart_identity_should_add_the_restart_identity_option() {
+      let query = sql::Truncate::new().truncate("users").restart_identity().as_string();
+      let expected_query = "TRUNCATE TABLE users RESTART IDENTITY";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn option_restart_identity_should_be_before_the_cascade_option() {
+      let query = sql::Truncate::new()
+        .cascade()
+        .restart_identity()
+        .truncate("users")
+        .truncate("orders")
+        .as_string();
+      let expected_query = "TRUNCATE TABLE users, orders RESTART IDENTITY CASCADE";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_the_restart_identity_option() {
+      let query = sql::Truncate::new()
+        .truncate("users")
+        .restart_identity()
+        .raw_after(sql::TruncateClause::Truncate, "/* teardown */")
+        .as_string();
+      let expected_query = "TRUNCATE TABLE users RESTART IDENTITY /* teardown */";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_restart_identity_option_outside_postgres() {
+      let truncate = sql::Truncate::new().truncate("users").restart_identity();
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "RESTART IDENTITY",
+        dialect: sql::Dialect::MySql,
+      });
+
+      assert_eq!(truncate.as_string_for(sql::Dialect::MySql), expected_error);
+      assert!(truncate.as_string_for(sql::Dialect::Postgres).is_ok());
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod concurrently_option {
+  mod create_index_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_concurrently_should_add_the_concurrently_option() {
+      let query = sql::CreateIndex::new()
+        .create_index("users_login_idx")
+        .concurrently()
+        .as_string();
+      let expected_query = "CREATE INDEX CONCURRENTLY users_login_idx";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn option_concurrently_should_be_after_unique_and_before_if_not_exists() {
+      let query = sql::CreateIndex::new()
+        .concurrently()
+        .create_index_if_not_exists("users_login_key")
+        .unique()
+        .on("users")
+        .using("btree")
+        .column("login")
+        .as_string();
+      let expected_query =
+        "CREATE UNIQUE INDEX CONCURRENTLY IF NOT EXISTS users_login_key ON users USING btree (login)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_concurrently_option_outside_postgres() {
+      let create_index = sql::CreateIndex::new()
+        .create_index("users_login_idx")
+        .concurrently()
+        .on("users")
+        .column("login");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "CONCURRENTLY",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert_eq!(create_index.as_string_for(sql::Dialect::Sqlite), expected_error);
+      assert!(create_index.as_string_for(sql::Dialect::Postgres).is_ok());
+    }
+  }
+
+  mod transaction_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_concurrently_option_inside_the_transaction() {
+      let create_index = sql::CreateIndex::new()
+        .create_index("users_login_idx")
+        .on("users")
+        .column("login");
+      let transaction = sql::Transaction::new().statement(create_index.clone().concurrently());
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "CONCURRENTLY IN TRANSACTION",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(transaction.as_string_for(sql::Dialect::Postgres), expected_error);
+      assert!(sql::Transaction::new()
+        .statement(create_index)
+        .as_string_for(sql::Dialect::Postgres)
+        .is_ok());
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod explain_option {
+  mod explain_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_option_should_add_the_options_in_parentheses() {
+      let query = sql::Explain::new()
+        .option(sql::ExplainOption::Analyze)
+        .explain(sql::Select::new().select("1"))
+        .as_string();
+      let expected_query = "EXPLAIN (ANALYZE) SELECT 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_option_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Explain::new()
+        .option(sql::ExplainOption::Analyze)
+        .option(sql::ExplainOption::Buffers)
+        .option(sql::ExplainOption::Verbose)
+        .explain(sql::Select::new().select("1"))
+        .as_string();
+      let expected_query = "EXPLAIN (ANALYZE, BUFFERS, VERBOSE) SELECT 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_option_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Explain::new()
+        .option(sql::ExplainOption::Verbose)
+        .option(sql::ExplainOption::Verbose)
+        .explain(sql::Select::new().select("1"))
+        .as_string();
+      let expected_query = "EXPLAIN (VERBOSE) SELECT 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_option_should_render_every_option() {
+      let query = sql::Explain::new()
+        .option(sql::ExplainOption::Analyze)
+        .option(sql::ExplainOption::Verbose)
+        .option(sql::ExplainOption::Costs(false))
+        .option(sql::ExplainOption::Settings)
+        .option(sql::ExplainOption::Buffers)
+        .option(sql::ExplainOption::Wal)
+        .option(sql::ExplainOption::Timing(false))
+        .option(sql::ExplainOption::Summary)
+        .format(sql::ExplainFormat::Yaml)
+        .explain(sql::Select::new().select("1"))
+        .as_string();
+      let expected_query =
+        "EXPLAIN (ANALYZE, VERBOSE, COSTS FALSE, SETTINGS, BUFFERS, WAL, TIMING FALSE, SUMMARY, FORMAT YAML) SELECT 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_option_should_render_the_true_value_as_the_bare_option() {
+      let query = sql::Explain::new()
+        .option(sql::ExplainOption::Costs(true))
+        .option(sql::ExplainOption::Timing(true))
+        .explain(sql::Select::new().select("1"))
+        .as_string();
+      let expected_query = "EXPLAIN (COSTS, TIMING) SELECT 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_analyze_should_be_the_first_option_of_the_list_together_with_the_options() {
+      let query = sql::Explain::new()
+        .option(sql::ExplainOption::Buffers)
+        .analyze()
+        .explain(sql::Select::new().select("1"))
+        .as_string();
+      let expected_query = "EXPLAIN (ANALYZE, BUFFERS) SELECT 1";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_analyze_should_not_repeat_the_analyze_option() {
+      let query = sql::Explain::new()
+        .analyze()
+        .option(sql::ExplainOption::Verbose)
+        .option(sql::ExplainOption::Analyze)
+        .explain(sql::Select::new().select("1"))
+        .as_string_for(sql::Dialect::Postgres);
+      let expected_query = Ok("EXPLAIN (VERBOSE, ANALYZE) SELECT 1".to_owned());
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_debug_should_keep_the_options_in_the_explain_line() {
+      let query = sql::Explain::new()
+        .option(sql::ExplainOption::Analyze)
+        .explain(sql::Select::new().select("*").from("orders"))
+        .debug()
+        .as_string();
+      let expected_query = "EXPLAIN (ANALYZE) SELECT * FROM orders";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_options_outside_postgres() {
+      let explain = sql::Explain::new()
+        .option(sql::ExplainOption::Analyze)
+        .explain(sql::Select::new().select("1"));
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "EXPLAIN OPTIONS",
+        dialect: sql::Dialect::MySql,
+      });
+
+      assert_eq!(explain.as_string_for(sql::Dialect::MySql), expected_error);
+      assert!(explain.as_string_for(sql::Dialect::Postgres).is_ok());
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_mysql_formats_in_postgres() {
+      let explain = sql::Explain::new()
+        .format(sql::ExplainFormat::Tree)
+        .explain(sql::Select::new().select("1"));
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "FORMAT TREE",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(explain.as_string_for(sql::Dialect::Postgres), expected_error);
+    }
+
+    #[test]
+    fn method_format_should_render_the_format_as_the_last_option_in_postgres() {
+      let json = sql::Explain::new()
+        .format(sql::ExplainFormat::Json)
+        .explain(sql::Select::new().select("1"));
+      let with_options = sql::Explain::new()
+        .analyze()
+        .option(sql::ExplainOption::Buffers)
+        .format(sql::ExplainFormat::Json)
+        .explain(sql::Select::new().select("1"));
+
+      assert_eq!(
+        json.as_string_for(sql::Dialect::Postgres),
+        Ok("EXPLAIN (FORMAT JSON) SELECT 1".to_owned())
+      );
+      assert_eq!(
+        with_options.as_string_for(sql::Dialect::Postgres),
+        Ok("EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON) SELECT 1".to_owned())
+      );
+      assert_eq!(
+        with_options.as_string(),
+        "EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON) SELECT 1"
+      );
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod materialized_view_option {
+  mod create_view_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_materialized_should_add_the_materialized_option() {
+      let query = sql::CreateView::new()
+        .create_view("sales_summary")
+        .materialized()
+        .as_string();
+      let expected_query = "CREATE MATERIALIZED VIEW sales_summary";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn option_materialized_should_be_after_or_replace() {
+      let query = sql::CreateView::new()
+        .materialized()
+        .create_or_replace_view("sales_summary")
+        .as_string();
+      let expected_query = "CREATE OR REPLACE MATERIALIZED VIEW sales_summary";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_data_should_add_the_with_data_clause() {
+      let query = sql::CreateView::new().with_data().as_string();
+      let expected_query = "WITH DATA";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_no_data_should_add_the_with_no_data_clause() {
+      let query = sql::CreateView::new().with_no_data().as_string();
+      let expected_query = "WITH NO DATA";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_data_should_override_the_with_no_data_clause() {
+      let query = sql::CreateView::new().with_no_data().with_data().as_string();
+      let expected_query = "WITH DATA";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_with_data_should_be_after_select_clause() {
+      let query = sql::CreateView::new()
+        .with_no_data()
+        .create_view("sales_summary")
+        .materialized()
+        .select(
+          sql::Select::new()
+            .select("seller_id, sum(amount)")
+            .from("sales")
+            .group_by("seller_id"),
+        )
+        .as_string();
+      let expected_query = "\
+        CREATE MATERIALIZED VIEW sales_summary \
+        AS SELECT seller_id, sum(amount) FROM sales GROUP BY seller_id \
+        WITH NO DATA\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_with_data_clause() {
+      let query = sql::CreateView::new()
+        .raw_before(sql::CreateViewClause::WithData, "/* populate */")
+        .with_data()
+        .as_string();
+      let expected_query = "/* populate */ WITH DATA";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_with_data_clause() {
+      let query = sql::CreateView::new()
+        .with_data()
+        .raw_after(sql::CreateViewClause::WithData, "/* populate */")
+        .as_string();
+      let expected_query = "WITH DATA /* populate */";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_materialized_option_outside_postgres() {
+      let create_view = sql::CreateView::new()
+        .create_view("sales_summary")
+        .materialized()
+        .select(sql::Select::new().select("*").from("sales"));
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "MATERIALIZED",
+        dialect: sql::Dialect::MySql,
+      });
+
+      assert_eq!(create_view.as_string_for(sql::Dialect::MySql), expected_error);
+      assert!(create_view.as_string_for(sql::Dialect::Postgres).is_ok());
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_with_data_clause_outside_postgres() {
+      let create_view = sql::CreateView::new()
+        .create_view("sales_summary")
+        .select(sql::Select::new().select("*").from("sales"))
+        .with_no_data();
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "WITH DATA",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert_eq!(create_view.as_string_for(sql::Dialect::Sqlite), expected_error);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_materialized_option_with_or_replace() {
+      let create_view = sql::CreateView::new()
+        .create_or_replace_view("sales_summary")
+        .materialized()
+        .select(sql::Select::new().select("*").from("sales"));
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "MATERIALIZED OR REPLACE",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(create_view.as_string_for(sql::Dialect::Postgres), expected_error);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_materialized_option_with_check_option() {
+      let create_view = sql::CreateView::new()
+        .create_view("sales_summary")
+        .materialized()
+        .select(sql::Select::new().select("*").from("sales"))
+        .with_check_option();
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "MATERIALIZED WITH CHECK OPTION",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(create_view.as_string_for(sql::Dialect::Postgres), expected_error);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_with_data_clause_without_materialized_option() {
+      let with_data = sql::CreateView::new()
+        .create_view("sales_summary")
+        .select(sql::Select::new().select("*").from("sales"))
+        .with_data();
+      let with_no_data = with_data.clone().with_no_data();
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "WITH DATA WITHOUT MATERIALIZED",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(with_data.as_string_for(sql::Dialect::Postgres), expected_error);
+      assert_eq!(with_no_data.as_string_for(sql::Dialect::Postgres), expected_error);
+      assert!(with_data.materialized().as_string_for(sql::Dialect::Postgres).is_ok());
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod distinct_on_option {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_distinct_on_should_add_the_distinct_on_option_to_the_select_clause() {
+      let query = sql::Select::new()
+        .select("user_id, created_at")
+        .distinct_on("user_id")
+        .as_string();
+      let expected_query = "SELECT DISTINCT ON (user_id) user_id, created_at";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_distinct_on_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Select::new()
+        .distinct_on("tenant_id")
+        .distinct_on("user_id")
+        .select("*")
+        .as_string();
+      let expected_query = "SELECT DISTINCT ON (tenant_id, user_id) *";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_distinct_on_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Select::new()
+        .distinct_on("user_id")
+        .distinct_on("user_id")
+        .select("*")
+        .as_string();
+      let expected_query = "SELECT DISTINCT ON (user_id) *";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_distinct_on_should_trim_space_of_the_argument() {
+      let query = sql::Select::new().distinct_on("  user_id  ").select("*").as_string();
+      let expected_query = "SELECT DISTINCT ON (user_id) *";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_distinct_on_should_take_precedence_over_the_distinct_option() {
+      let query = sql::Select::new()
+        .distinct()
+        .select("*")
+        .distinct_on("user_id")
+        .as_string();
+      let expected_query = "SELECT DISTINCT ON (user_id) *";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_distinct_on_option_outside_postgres() {
+      let select = sql::Select::new().distinct_on("user_id").select("*").from("orders");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "DISTINCT ON",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+      assert_eq!(select.as_string_for(sql::Dialect::Sqlite), expected_error);
+    }
+  }
+}
+
+#[cfg(feature = "postgresql")]
+mod cte_options {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_materialized_should_add_the_materialized_hint() {
+      let query = sql::Select::new()
+        .with(
+          sql::Cte::new("users_list").materialized(),
+          sql::Select::new().select("id"),
+        )
+        .as_string();
+      let expected_query = "WITH users_list AS MATERIALIZED (SELECT id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_not_materialized_should_add_the_not_materialized_hint() {
+      let query = sql::Select::new()
+        .with(
+          sql::Cte::new("users_list").not_materialized(),
+          sql::Select::new().select("id"),
+        )
+        .as_string();
+      let expected_query = "WITH users_list AS NOT MATERIALIZED (SELECT id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_materialized_should_override_the_not_materialized_hint() {
+      let query = sql::Select::new()
+        .with(
+          sql::Cte::new("users_list").not_materialized().materialized(),
+          sql::Select::new().select("id"),
+        )
+        .as_string();
+      let expected_query = "WITH users_list AS MATERIALIZED (SELECT id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_materialized_should_be_after_the_column_list() {
+      let query = sql::Select::new()
+        .with(
+          sql::Cte::new("tree").materialized().column("id").recursive(),
+          sql::Select::new().select("id").from("nodes"),
+        )
+        .as_string();
+      let expected_query = "WITH RECURSIVE tree(id) AS MATERIALIZED (SELECT id FROM nodes)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_materialized_hint_in_mssql() {
+      let select = sql::Select::new()
+        .with(
+          sql::Cte::new("users_list").materialized(),
+          sql::Select::new().select("id"),
+        )
+        .select("*")
+        .from("users_list");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "MATERIALIZED",
+        dialect: sql::Dialect::MsSql,
+      });
+
+      assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+      assert_eq!(select.as_string_for(sql::Dialect::MsSql), expected_error);
+    }
   }
 }
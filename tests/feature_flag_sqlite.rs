@@ -0,0 +1,1735 @@
+#[cfg(feature = "sqlite")]
+mod from_clause {
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_from_should_add_the_from_clause() {
+      let query = sql::Update::new().from("users").as_string();
+      let expected_query = "FROM users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_from_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Update::new().from("users").from("address").as_string();
+      let expected_query = "FROM users, address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_from_should_trim_space_of_the_argument() {
+      let query = sql::Update::new().from("  users  ").as_string();
+      let expected_query = "FROM users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_from_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Update::new().from("address").from("address").as_string();
+      let expected_query = "FROM address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_from_should_be_after_set_clause() {
+      let query = sql::Update::new().set("country = 'Bar'").from("address").as_string();
+      let expected_query = "SET country = 'Bar' FROM address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_from_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::From, "set country = 'Bar'")
+        .from("address")
+        .as_string();
+      let expected_query = "set country = 'Bar' FROM address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_from_clause() {
+      let query = sql::Update::new()
+        .from("users")
+        .raw_after(sql::UpdateClause::From, "where login = $1")
+        .as_string();
+      let expected_query = "FROM users where login = $1";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod returning_clause {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_returning_should_add_the_returning_clause() {
+      let query = sql::Delete::new().returning("*").as_string();
+      let expected_query = "RETURNING *";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_returning_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Delete::new().returning("login").returning("name").as_string();
+      let expected_query = "RETURNING login, name";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_returning_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Delete::new().returning("id").returning("id").as_string();
+      let expected_query = "RETURNING id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_returning_should_trim_space_of_the_argument() {
+      let query = sql::Delete::new().returning("  login  ").as_string();
+      let expected_query = "RETURNING login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_returning_should_be_after_where_clause() {
+      let query = sql::Delete::new().returning("id").where_clause("name = $1").as_string();
+      let expected_query = "WHERE name = $1 RETURNING id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_returning_clause() {
+      let query = sql::Delete::new()
+        .raw_before(sql::DeleteClause::Returning, "delete from users")
+        .returning("login")
+        .as_string();
+      let expected_query = "delete from users RETURNING login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_returning_clause() {
+      let query = sql::Delete::new()
+        .returning("id")
+        .raw_after(sql::DeleteClause::Returning, ", login, name")
+        .as_string();
+      let expected_query = "RETURNING id , login, name";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_returning_should_add_the_returning_clause() {
+      let query = sql::Insert::new().returning("*").as_string();
+      let expected_query = "RETURNING *";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_returning_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Insert::new().returning("login").returning("name").as_string();
+      let expected_query = "RETURNING login, name";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_returning_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Insert::new().returning("id").returning("id").as_string();
+      let expected_query = "RETURNING id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_returning_should_trim_space_of_the_argument() {
+      let query = sql::Insert::new().returning("  login  ").as_string();
+      let expected_query = "RETURNING login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_returning_should_be_after_values_clause() {
+      let query = sql::Insert::new()
+        .insert_into("(login, name)")
+        .returning("login")
+        .values("('foo', 'Foo')")
+        .as_string();
+      let expected_query = "INSERT INTO (login, name) VALUES ('foo', 'Foo') RETURNING login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_returning_should_be_after_on_conflict_clause() {
+      let query = sql::Insert::new()
+        .insert_into("(login, name)")
+        .values("('foo', 'Foo')")
+        .on_conflict("do nothing")
+        .returning("login")
+        .as_string();
+      let expected_query = "INSERT INTO (login, name) VALUES ('foo', 'Foo') ON CONFLICT do nothing RETURNING login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_returning_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::Returning, "values ('foo')")
+        .returning("login")
+        .as_string();
+      let expected_query = "values ('foo') RETURNING login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_returning_clause() {
+      let query = sql::Insert::new()
+        .returning("id")
+        .raw_after(sql::InsertClause::Returning, ", login, name")
+        .as_string();
+      let expected_query = "RETURNING id , login, name";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_returning_should_add_the_returning_clause() {
+      let query = sql::Update::new().returning("*").as_string();
+      let expected_query = "RETURNING *";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_returning_should_accumulate_values_on_consecutive_calls() {
+      let query = sql::Update::new().returning("login").returning("name").as_string();
+      let expected_query = "RETURNING login, name";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_returning_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Update::new().returning("id").returning("id").as_string();
+      let expected_query = "RETURNING id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_returning_should_trim_space_of_the_argument() {
+      let query = sql::Update::new().returning("  login  ").as_string();
+      let expected_query = "RETURNING login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_returning_should_be_after_where_clause() {
+      let query = sql::Update::new().returning("id").where_clause("name = $1").as_string();
+      let expected_query = "WHERE name = $1 RETURNING id";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_returning_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::Returning, "where login = $1")
+        .returning("login")
+        .as_string();
+      let expected_query = "where login = $1 RETURNING login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_returning_clause() {
+      let query = sql::Update::new()
+        .returning("id")
+        .raw_after(sql::UpdateClause::Returning, ", login, name")
+        .as_string();
+      let expected_query = "RETURNING id , login, name";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod with_clause {
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_with_should_accept_delete_builder_as_query_argument() {
+      let query = sql::Delete::new()
+        .with("deleted_address", sql::Delete::new().delete_from("address"))
+        .delete_from("orders")
+        .as_string();
+      let expected_query = "\
+        WITH deleted_address AS (DELETE FROM address) \
+        DELETE FROM orders\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_add_the_with_clause() {
+      let deleted_users = sql::Delete::new()
+        .delete_from("users")
+        .where_clause("ative = false")
+        .returning("id");
+      let query = sql::Delete::new().with("id_list", deleted_users).as_string();
+      let expected_query = "WITH id_list AS (DELETE FROM users WHERE ative = false RETURNING id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_accept_inline_argument() {
+      let query = sql::Delete::new()
+        .with(
+          "id_list",
+          sql::Delete::new()
+            .delete_from("users")
+            .where_clause("ative = false")
+            .returning("id"),
+        )
+        .as_string();
+      let expected_query = "WITH id_list AS (DELETE FROM users WHERE ative = false RETURNING id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_accumulate_values_on_consecutive_calls() {
+      let deleted_users = sql::Delete::new().delete_from("users");
+      let deleted_orders = sql::Delete::new().delete_from("orders");
+      let query = sql::Delete::new()
+        .with("deleted_users", deleted_users)
+        .with("deleted_orders", deleted_orders)
+        .as_string();
+      let expected_query = "\
+        WITH deleted_users AS (DELETE FROM users), \
+             deleted_orders AS (DELETE FROM orders)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_trim_space_of_the_argument() {
+      let query = sql::Delete::new()
+        .with("  deleted_users  ", sql::Delete::new().delete_from("users"))
+        .as_string();
+      let expected_query = "WITH deleted_users AS (DELETE FROM users)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_with_should_be_after_raw() {
+      let query = sql::Delete::new()
+        .raw("/* the with clause */")
+        .with("deleted_users", sql::Delete::new().delete_from("users"))
+        .as_string();
+      let expected_query = "\
+        /* the with clause */ \
+        WITH deleted_users AS (DELETE FROM users)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_with_clause() {
+      let query = sql::Delete::new()
+        .raw_before(sql::DeleteClause::With, "/* the with clause */")
+        .with("deleted_orders", sql::Delete::new().delete_from("orders"))
+        .as_string();
+      let expected_query = "\
+        /* the with clause */ \
+        WITH deleted_orders AS (DELETE FROM orders)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_with_clause() {
+      let query = sql::Delete::new()
+        .with("deleted_address", sql::Delete::new().delete_from("address"))
+        .raw_after(sql::DeleteClause::With, "select name, login")
+        .as_string();
+      let expected_query = "\
+        WITH deleted_address AS (DELETE FROM address) \
+        select name, login\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_delete_from_should_be_after_with_clause() {
+      let query = sql::Delete::new()
+        .with("deleted_address", sql::Delete::new().delete_from("address"))
+        .delete_from("orders")
+        .as_string();
+      let expected_query = "\
+        WITH deleted_address AS (DELETE FROM address) \
+        DELETE FROM orders\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_with_should_accept_insert_builder_as_query_argument() {
+      let query = sql::Insert::new()
+        .with("address", sql::Insert::new().insert_into("address"))
+        .as_string();
+      let expected_query = "\
+        WITH address AS (INSERT INTO address)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_add_the_with_clause() {
+      let inserted_users = sql::Insert::new()
+        .insert_into("users(login)")
+        .values("('foo')")
+        .returning("id");
+      let query = sql::Insert::new().with("id_list", inserted_users).as_string();
+      let expected_query = "WITH id_list AS (INSERT INTO users(login) VALUES ('foo') RETURNING id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_accept_inline_argument() {
+      let query = sql::Insert::new()
+        .with(
+          "id_list",
+          sql::Insert::new()
+            .insert_into("users(login)")
+            .values("('foo')")
+            .returning("id"),
+        )
+        .as_string();
+      let expected_query = "WITH id_list AS (INSERT INTO users(login) VALUES ('foo') RETURNING id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_accumulate_values_on_consecutive_calls() {
+      let inserted_users = sql::Insert::new().insert_into("users");
+      let inserted_orders = sql::Insert::new().insert_into("orders");
+      let query = sql::Insert::new()
+        .with("inserted_users", inserted_users)
+        .with("inserted_orders", inserted_orders)
+        .as_string();
+      let expected_query = "\
+        WITH inserted_users AS (INSERT INTO users), \
+             inserted_orders AS (INSERT INTO orders)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_trim_space_of_the_argument() {
+      let query = sql::Insert::new()
+        .with("  inserted_users  ", sql::Insert::new().insert_into("users"))
+        .as_string();
+      let expected_query = "WITH inserted_users AS (INSERT INTO users)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_with_should_be_after_raw() {
+      let query = sql::Insert::new()
+        .raw("/* the with clause */")
+        .with("inserted_users", sql::Insert::new().insert_into("users"))
+        .as_string();
+      let expected_query = "\
+        /* the with clause */ \
+        WITH inserted_users AS (INSERT INTO users)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_with_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::With, "/* the with clause */")
+        .with("inserted_orders", sql::Insert::new().insert_into("orders"))
+        .as_string();
+      let expected_query = "\
+        /* the with clause */ \
+        WITH inserted_orders AS (INSERT INTO orders)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_with_clause() {
+      let query = sql::Insert::new()
+        .with("inserted_address", sql::Insert::new().insert_into("address"))
+        .raw_after(sql::InsertClause::With, "select name, login")
+        .as_string();
+      let expected_query = "\
+        WITH inserted_address AS (INSERT INTO address) \
+        select name, login\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_insert_into_should_be_after_with_clause() {
+      let query = sql::Insert::new()
+        .with("inserted_address", sql::Insert::new().insert_into("address"))
+        .insert_into("orders")
+        .as_string();
+      let expected_query = "\
+        WITH inserted_address AS (INSERT INTO address) \
+        INSERT INTO orders\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod select_builder_with_clause {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_with_should_accept_select_builder_as_query_argument() {
+      let query = sql::Select::new()
+        .with("address", sql::Select::new().select("city"))
+        .as_string();
+      let expected_query = "\
+        WITH address AS (SELECT city)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_add_the_with_clause() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let query = sql::Select::new().with("user_list", select_users).as_string();
+      let expected_query = "WITH user_list AS (SELECT login FROM users)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_accept_inline_argument() {
+      let query = sql::Select::new()
+        .with("user_list", sql::Select::new().select("login").from("users"))
+        .as_string();
+      let expected_query = "WITH user_list AS (SELECT login FROM users)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_accumulate_values_on_consecutive_calls() {
+      let select_users = sql::Select::new().select("id, login").from("users");
+      let select_users_id = sql::Select::new().select("id").from("user_list");
+      let query = sql::Select::new()
+        .with("user_list", select_users)
+        .with("user_ids", select_users_id)
+        .as_string();
+      let expected_query = "\
+      WITH user_list AS (SELECT id, login FROM users), user_ids AS (SELECT id FROM user_list)\
+    ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_trim_space_of_the_argument() {
+      let query = sql::Select::new()
+        .with("  date  ", sql::Select::new().select("current_date"))
+        .as_string();
+      let expected_query = "WITH date AS (SELECT current_date)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_with_should_be_after_raw() {
+      let select_base = sql::Select::new()
+        .raw("select 123 as id union")
+        .with("user_list", sql::Select::new().select("*").from("users"))
+        .select("id");
+      let query = select_base.as_string();
+      let expected_query = "\
+        select 123 as id union \
+        WITH user_list AS (SELECT * FROM users) \
+        SELECT id\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_with_clause() {
+      let query = sql::Select::new()
+        .raw_before(sql::SelectClause::With, "/* the users orders */")
+        .with("orders_list", sql::Select::new().select("*").from("orders"))
+        .as_string();
+      let expected_query = "/* the users orders */ WITH orders_list AS (SELECT * FROM orders)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_with_clause() {
+      let query = sql::Select::new()
+        .with("address_list", sql::Select::new().select("*").from("address"))
+        .raw_after(sql::SelectClause::With, "select name, login")
+        .as_string();
+      let expected_query = "WITH address_list AS (SELECT * FROM address) select name, login";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_select_should_be_after_with_clause() {
+      let select_users = sql::Select::new().select("*").from("users");
+      let select_base = sql::Select::new().with("user_list", select_users).select("id");
+      let query = select_base.as_string();
+      let expected_query = "\
+        WITH user_list AS (SELECT * FROM users) \
+        SELECT id\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_renumber_the_placeholders_of_the_query_argument_past_the_placeholders_of_the_builder() {
+      let select_users = sql::Select::new().select("login").from("users").where_clause("id = $1");
+      let query = sql::Select::new()
+        .with("user_list", select_users)
+        .select("*")
+        .from("orders")
+        .where_clause("owner_login in (select login from user_list)")
+        .and("status = $1")
+        .as_string();
+      let expected_query = "\
+        WITH user_list AS (SELECT login FROM users WHERE id = $2) \
+        SELECT * \
+        FROM orders \
+        WHERE owner_login in (select login from user_list) AND status = $1\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_renumber_each_query_argument_past_the_previous_one() {
+      let (query, params) = sql::Select::new()
+        .with(
+          "a",
+          sql::Select::new()
+            .select("1")
+            .where_bind("x = $1", "a1")
+            .and_bind("y = $1", "a2"),
+        )
+        .with("b", sql::Select::new().select("2").where_bind("x = $1", "b1"))
+        .select("*")
+        .where_bind("z = $1", "outer")
+        .as_query();
+      let expected_query = "\
+        WITH a AS (SELECT 1 WHERE x = $2 AND y = $3), b AS (SELECT 2 WHERE x = $4) \
+        SELECT * \
+        WHERE z = $1\
+      ";
+      let expected_params = vec![
+        sql::Value::from("outer"),
+        sql::Value::from("a1"),
+        sql::Value::from("a2"),
+        sql::Value::from("b1"),
+      ];
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, expected_params);
+    }
+
+    #[test]
+    fn method_as_query_with_should_follow_the_question_mark_placeholders_across_the_with_queries() {
+      let (query, params) = sql::Select::new()
+        .with(
+          "owners",
+          sql::Select::new().select("login").where_bind("active = $1", true),
+        )
+        .select("*")
+        .from("orders")
+        .where_bind("status = $1", "paid")
+        .as_query_with(sql::Placeholder::QuestionMark)
+        .unwrap();
+      let expected_query = "\
+        WITH owners AS (SELECT login WHERE active = ?) \
+        SELECT * \
+        FROM orders \
+        WHERE status = ?\
+      ";
+      let expected_params = vec![sql::Value::from(true), sql::Value::from("paid")];
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, expected_params);
+    }
+
+    #[test]
+    fn method_as_query_named_should_resolve_the_names_across_the_with_queries() {
+      let named = std::collections::HashMap::from([("tenant", sql::Value::from(42))]);
+      let (query, params) = sql::Select::new()
+        .with(
+          "owners",
+          sql::Select::new().select("login").where_clause("tenant_id = :tenant"),
+        )
+        .select("*")
+        .from("orders")
+        .where_clause("tenant_id = :tenant")
+        .as_query_named(&named, sql::Placeholder::Dollar)
+        .unwrap();
+      let expected_query = "\
+        WITH owners AS (SELECT login WHERE tenant_id = $1) \
+        SELECT * \
+        FROM orders \
+        WHERE tenant_id = $1\
+      ";
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, vec![sql::Value::from(42)]);
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_with_should_accept_update_builder_as_query_argument() {
+      let query = sql::Update::new()
+        .with("address", sql::Update::new().set("city = 'foo'"))
+        .as_string();
+      let expected_query = "\
+        WITH address AS (SET city = 'foo')\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_add_the_with_clause() {
+      let update_users = sql::Update::new()
+        .update("users")
+        .where_clause("ative = false")
+        .returning("id");
+      let query = sql::Update::new().with("id_list", update_users).as_string();
+      let expected_query = "WITH id_list AS (UPDATE users WHERE ative = false RETURNING id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_accept_inline_argument() {
+      let query = sql::Update::new()
+        .with(
+          "id_list",
+          sql::Update::new()
+            .update("users")
+            .where_clause("ative = false")
+            .returning("id"),
+        )
+        .as_string();
+      let expected_query = "WITH id_list AS (UPDATE users WHERE ative = false RETURNING id)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_accumulate_values_on_consecutive_calls() {
+      let updated_users = sql::Update::new().update("users");
+      let updated_orders = sql::Update::new().update("orders");
+      let query = sql::Update::new()
+        .with("updated_users", updated_users)
+        .with("updated_orders", updated_orders)
+        .as_string();
+      let expected_query = "\
+        WITH updated_users AS (UPDATE users), \
+             updated_orders AS (UPDATE orders)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_with_should_trim_space_of_the_argument() {
+      let query = sql::Update::new()
+        .with("  updated_users  ", sql::Update::new().update("users"))
+        .as_string();
+      let expected_query = "WITH updated_users AS (UPDATE users)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_with_should_be_after_raw() {
+      let query = sql::Update::new()
+        .raw("/* the with clause */")
+        .with("updated_users", sql::Update::new().update("users"))
+        .as_string();
+      let expected_query = "\
+        /* the with clause */ \
+        WITH updated_users AS (UPDATE users)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_with_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::With, "/* the with clause */")
+        .with("updated_orders", sql::Update::new().update("orders"))
+        .as_string();
+      let expected_query = "\
+        /* the with clause */ \
+        WITH updated_orders AS (UPDATE orders)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_with_clause() {
+      let query = sql::Update::new()
+        .with("updated_address", sql::Update::new().update("address"))
+        .raw_after(sql::UpdateClause::With, "select name, login")
+        .as_string();
+      let expected_query = "\
+        WITH updated_address AS (UPDATE address) \
+        select name, login\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_update_should_be_after_with_clause() {
+      let query = sql::Update::new()
+        .with("updated_address", sql::Update::new().update("address"))
+        .update("orders")
+        .as_string();
+      let expected_query = "\
+        WITH updated_address AS (UPDATE address) \
+        UPDATE orders\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod values_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_with_should_accept_values_builder_as_query_argument() {
+      let query = sql::Select::new()
+        .with("address", sql::Values::new().values("('foo', 'Foo')"))
+        .as_string();
+      let expected_query = "\
+        WITH address AS (VALUES ('foo', 'Foo'))\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod except_clause {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_except_should_add_the_except_clause() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let select_address = sql::Select::new().select("login").from("address");
+      let query = select_users
+        .except(select_address)
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT login FROM users EXCEPT SELECT login FROM address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_except_should_accept_inline_argument() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let query = select_users
+        .except(sql::Select::new().select("login").from("address"))
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT login FROM users EXCEPT SELECT login FROM address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_except_should_accumulate_values_on_consecutive_calls() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let select_address = sql::Select::new().select("login").from("address");
+      let select_orders = sql::Select::new().select("login").from("orders");
+      let query = select_users
+        .except(select_address)
+        .except(select_orders)
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "\
+        SELECT login FROM users \
+        EXCEPT \
+        SELECT login FROM address \
+        EXCEPT \
+        SELECT login FROM orders\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_except_should_be_after_offset_clause() {
+      let select_address = sql::Select::new().select("login").from("address");
+      let query = sql::Select::new().offset("10").except(select_address).as_string();
+      let expected_query = "\
+        (OFFSET 10) \
+        EXCEPT \
+        (SELECT login FROM address)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_offset_clause_of_the_left_except_operand() {
+      let select_address = sql::Select::new().select("login").from("address");
+      let select = sql::Select::new().select("login").offset("10").except(select_address);
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "PARENTHESIZED COMPOUND OPERAND",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert_eq!(select.as_string_for(sql::Dialect::Sqlite), expected_error);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_except_clause() {
+      let query = sql::Select::new()
+        .raw_before(sql::SelectClause::Except, "select name from orders")
+        .except(sql::Select::new().select("name"))
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "select name from orders EXCEPT SELECT name";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_except_clause() {
+      let query = sql::Select::new()
+        .select("name")
+        .except(sql::Select::new().select("name"))
+        .raw_after(sql::SelectClause::Except, "/* the name */")
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT name EXCEPT SELECT name /* the name */";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod intersect_clause {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_intersect_should_add_the_intersect_clause() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let select_address = sql::Select::new().select("login").from("address");
+      let query = select_users
+        .intersect(select_address)
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT login FROM users INTERSECT SELECT login FROM address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_intersect_should_accept_inline_argument() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let query = select_users
+        .intersect(sql::Select::new().select("login").from("address"))
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT login FROM users INTERSECT SELECT login FROM address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_intersect_should_accumulate_values_on_consecutive_calls() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let select_address = sql::Select::new().select("login").from("address");
+      let select_orders = sql::Select::new().select("login").from("orders");
+      let query = select_users
+        .intersect(select_address)
+        .intersect(select_orders)
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "\
+        SELECT login FROM users \
+        INTERSECT \
+        SELECT login FROM address \
+        INTERSECT \
+        SELECT login FROM orders\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_intersect_should_be_after_offset_clause() {
+      let select_address = sql::Select::new().select("login").from("address");
+      let query = sql::Select::new().offset("10").intersect(select_address).as_string();
+      let expected_query = "\
+        (OFFSET 10) \
+        INTERSECT \
+        (SELECT login FROM address)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_offset_clause_of_the_left_intersect_operand() {
+      let select_address = sql::Select::new().select("login").from("address");
+      let select = sql::Select::new()
+        .select("login")
+        .offset("10")
+        .intersect(select_address);
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "PARENTHESIZED COMPOUND OPERAND",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert_eq!(select.as_string_for(sql::Dialect::Sqlite), expected_error);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_intersect_clause() {
+      let query = sql::Select::new()
+        .raw_before(sql::SelectClause::Except, "select name from orders")
+        .intersect(sql::Select::new().select("name"))
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "select name from orders INTERSECT SELECT name";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_intersect_clause() {
+      let query = sql::Select::new()
+        .select("name")
+        .intersect(sql::Select::new().select("name"))
+        .raw_after(sql::SelectClause::Intersect, "/* the name */")
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT name INTERSECT SELECT name /* the name */";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod union_clause {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_union_should_add_the_union_clause() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let select_address = sql::Select::new().select("login").from("address");
+      let query = select_users
+        .union(select_address)
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT login FROM users UNION SELECT login FROM address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_union_should_accept_inline_argument() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let query = select_users
+        .union(sql::Select::new().select("login").from("address"))
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT login FROM users UNION SELECT login FROM address";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_union_should_accumulate_values_on_consecutive_calls() {
+      let select_users = sql::Select::new().select("login").from("users");
+      let select_address = sql::Select::new().select("login").from("address");
+      let select_orders = sql::Select::new().select("login").from("orders");
+      let query = select_users
+        .union(select_address)
+        .union(select_orders)
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "\
+        SELECT login FROM users \
+        UNION \
+        SELECT login FROM address \
+        UNION \
+        SELECT login FROM orders\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_union_should_be_after_offset_clause() {
+      let select_address = sql::Select::new().select("login").from("address");
+      let query = sql::Select::new().offset("10").union(select_address).as_string();
+      let expected_query = "\
+        (OFFSET 10) \
+        UNION \
+        (SELECT login FROM address)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_offset_clause_of_the_left_union_operand() {
+      let select_address = sql::Select::new().select("login").from("address");
+      let select = sql::Select::new().select("login").offset("10").union(select_address);
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "PARENTHESIZED COMPOUND OPERAND",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert_eq!(select.as_string_for(sql::Dialect::Sqlite), expected_error);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_union_clause() {
+      let query = sql::Select::new()
+        .raw_before(sql::SelectClause::Union, "select name from orders")
+        .union(sql::Select::new().select("name"))
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "select name from orders UNION SELECT name";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_union_clause() {
+      let query = sql::Select::new()
+        .select("name")
+        .union(sql::Select::new().select("name"))
+        .raw_after(sql::SelectClause::Union, "/* the name */")
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT name UNION SELECT name /* the name */";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_union_should_renumber_the_placeholders_of_the_select_argument() {
+      let (query, params) = sql::Select::new()
+        .select("login")
+        .from("users")
+        .where_bind("login = $1", "foo")
+        .union(
+          sql::Select::new()
+            .select("login")
+            .from("users_bk")
+            .where_bind("login = $1", "bar")
+            .with("ids", sql::Select::new().select("id").where_bind("id > $1", 10)),
+        )
+        .as_query();
+      let expected_query = "\
+        (SELECT login FROM users WHERE login = $1) \
+        UNION \
+        (WITH ids AS (SELECT id WHERE id > $3) SELECT login FROM users_bk WHERE login = $2)\
+      ";
+      let expected_params = vec![sql::Value::from("foo"), sql::Value::from("bar"), sql::Value::from(10)];
+
+      assert_eq!(query, expected_query);
+      assert_eq!(params, expected_params);
+    }
+
+    #[test]
+    fn method_union_all_should_add_the_union_all_clause() {
+      let select = sql::Select::new()
+        .select("login")
+        .from("users")
+        .union_all(sql::Select::new().select("login").from("address"));
+      let expected_query = "SELECT login FROM users UNION ALL SELECT login FROM address";
+
+      assert_eq!(
+        select.as_string_for(sql::Dialect::Sqlite),
+        Ok(expected_query.to_owned())
+      );
+    }
+
+    #[test]
+    fn method_as_string_for_should_render_the_compound_order_by_and_limit_after_the_operands() {
+      let query = sql::Select::new()
+        .select("login")
+        .from("users")
+        .union(sql::Select::new().select("login").from("address"))
+        .compound_order_by("login")
+        .compound_limit("10")
+        .as_string_for(sql::Dialect::Sqlite)
+        .unwrap();
+      let expected_query = "SELECT login FROM users UNION SELECT login FROM address ORDER BY login LIMIT 10";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_with_clause_of_the_right_union_operand() {
+      let select = sql::Select::new().select("login").from("users").union(
+        sql::Select::new()
+          .with("bk", sql::Select::new().select("login").from("users_bk"))
+          .select("login")
+          .from("bk"),
+      );
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "PARENTHESIZED COMPOUND OPERAND",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert_eq!(select.as_string_for(sql::Dialect::Sqlite), expected_error);
+      assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod insert_or_clause {
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_insert_or_should_add_the_insert_or_clause() {
+      let query = sql::Insert::new().insert_or("REPLACE INTO users").as_string();
+      let expected_query = "INSERT OR REPLACE INTO users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_insert_or_should_override_value_on_consecutive_calls() {
+      let query = sql::Insert::new()
+        .insert_or("REPLACE INTO users")
+        .insert_or("IGNORE INTO orders")
+        .as_string();
+      let expected_query = "INSERT OR IGNORE INTO orders";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_insert_or_should_trim_space_of_the_argument() {
+      let query = sql::Insert::new().insert_or("  ABORT INTO users (name)  ").as_string();
+      let expected_query = "INSERT OR ABORT INTO users (name)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_insert_or_should_be_before_values_clause() {
+      let query = sql::Insert::new()
+        .values("('foo')")
+        .insert_or("IGNORE INTO users (login)")
+        .as_string();
+      let expected_query = "INSERT OR IGNORE INTO users (login) VALUES ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_insert_or_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::InsertOr, "/* insert or replace */")
+        .insert_or("REPLACE INTO users")
+        .as_string();
+      let expected_query = "/* insert or replace */ INSERT OR REPLACE INTO users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_insert_or_clause() {
+      let query = sql::Insert::new()
+        .insert_or("REPLACE INTO users (name)")
+        .raw_after(sql::InsertClause::InsertOr, "values ('foo')")
+        .as_string();
+      let expected_query = "INSERT OR REPLACE INTO users (name) values ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod replace_into_clause {
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_replace_into_should_add_the_replace_into_clause() {
+      let query = sql::Insert::new().replace_into("users").as_string();
+      let expected_query = "REPLACE INTO users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_replace_into_should_override_value_on_consecutive_calls() {
+      let query = sql::Insert::new()
+        .replace_into("users")
+        .replace_into("orders")
+        .as_string();
+      let expected_query = "REPLACE INTO orders";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_replace_into_should_trim_space_of_the_argument() {
+      let query = sql::Insert::new().replace_into("  users (name)  ").as_string();
+      let expected_query = "REPLACE INTO users (name)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_replace_into_should_be_before_select_clause() {
+      let query = sql::Insert::new()
+        .select(sql::Select::new().select("login").from("users_bk"))
+        .replace_into("users (login)")
+        .as_string();
+      let expected_query = "REPLACE INTO users (login) SELECT login FROM users_bk";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_replace_into_clause() {
+      let query = sql::Insert::new()
+        .raw_before(sql::InsertClause::ReplaceInto, "/* replace */")
+        .replace_into("users")
+        .as_string();
+      let expected_query = "/* replace */ REPLACE INTO users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_replace_into_clause() {
+      let query = sql::Insert::new()
+        .replace_into("users (name)")
+        .raw_after(sql::InsertClause::ReplaceInto, "values ('foo')")
+        .as_string();
+      let expected_query = "REPLACE INTO users (name) values ('foo')";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod update_or_clause {
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_update_or_should_add_the_update_or_clause() {
+      let query = sql::Update::new().update_or("REPLACE users").as_string();
+      let expected_query = "UPDATE OR REPLACE users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_update_or_should_override_value_on_consecutive_calls() {
+      let query = sql::Update::new()
+        .update_or("REPLACE users")
+        .update_or("IGNORE orders")
+        .as_string();
+      let expected_query = "UPDATE OR IGNORE orders";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_update_or_should_trim_space_of_the_argument() {
+      let query = sql::Update::new().update_or("  FAIL users  ").as_string();
+      let expected_query = "UPDATE OR FAIL users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn clause_update_or_should_be_before_set_clause() {
+      let query = sql::Update::new()
+        .set("login = 'foo'")
+        .update_or("IGNORE users")
+        .as_string();
+      let expected_query = "UPDATE OR IGNORE users SET login = 'foo'";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_before_should_add_raw_sql_before_update_or_clause() {
+      let query = sql::Update::new()
+        .raw_before(sql::UpdateClause::UpdateOr, "/* update or */")
+        .update_or("ROLLBACK users")
+        .as_string();
+      let expected_query = "/* update or */ UPDATE OR ROLLBACK users";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_raw_after_should_add_raw_sql_after_update_or_clause() {
+      let query = sql::Update::new()
+        .update_or("ABORT users")
+        .raw_after(sql::UpdateClause::UpdateOr, "set login = 'foo'")
+        .as_string();
+      let expected_query = "UPDATE OR ABORT users set login = 'foo'";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod dialect_validation {
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_insert_or_clause_outside_sqlite() {
+      let insert = sql::Insert::new().insert_or("REPLACE INTO users (login)");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "INSERT OR",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(insert.as_string_for(sql::Dialect::Postgres), expected_error);
+      assert!(insert.as_string_for(sql::Dialect::Sqlite).is_ok());
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_more_than_one_insert_clause() {
+      let insert_or = sql::Insert::new()
+        .insert_into("users (login)")
+        .insert_or("REPLACE INTO users (login)");
+      let replace_into = sql::Insert::new()
+        .insert_into("users (login)")
+        .replace_into("users (login)");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "MULTIPLE INSERT CLAUSES",
+        dialect: sql::Dialect::Sqlite,
+      });
+
+      assert_eq!(insert_or.as_string_for(sql::Dialect::Sqlite), expected_error);
+      assert_eq!(replace_into.as_string_for(sql::Dialect::Sqlite), expected_error);
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod query_plan_option {
+  mod explain_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_query_plan_should_add_the_query_plan_option() {
+      let query = sql::Explain::new()
+        .query_plan()
+        .explain(sql::Select::new().select("*").from("orders"))
+        .as_string();
+      let expected_query = "EXPLAIN QUERY PLAN SELECT * FROM orders";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_query_plan_outside_sqlite() {
+      let explain = sql::Explain::new()
+        .query_plan()
+        .explain(sql::Select::new().select("*").from("orders"));
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "QUERY PLAN",
+        dialect: sql::Dialect::Postgres,
+      });
+
+      assert_eq!(explain.as_string_for(sql::Dialect::Postgres), expected_error);
+      assert!(explain.as_string_for(sql::Dialect::Sqlite).is_ok());
+    }
+  }
+}
+
+#[cfg(feature = "sqlite")]
+mod cte_options {
+  mod select_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_column_should_add_the_column_list_after_the_name() {
+      let query = sql::Select::new()
+        .with(
+          sql::Cte::new("totals").column("user_id").column("total"),
+          sql::Select::new().select("user_id, sum(amount)").from("orders"),
+        )
+        .as_string();
+      let expected_query = "WITH totals(user_id, total) AS (SELECT user_id, sum(amount) FROM orders)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_column_should_not_accumulate_arguments_with_the_same_content() {
+      let query = sql::Select::new()
+        .with(
+          sql::Cte::new("totals").column("total").column("total"),
+          sql::Select::new().select("1"),
+        )
+        .as_string();
+      let expected_query = "WITH totals(total) AS (SELECT 1)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_new_should_trim_space_of_the_name() {
+      let query = sql::Select::new()
+        .with(
+          sql::Cte::new("  totals  ").column("  total  "),
+          sql::Select::new().select("1"),
+        )
+        .as_string();
+      let expected_query = "WITH totals(total) AS (SELECT 1)";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_recursive_should_render_the_with_recursive_clause() {
+      let numbers = sql::Select::new().select("1").union(
+        sql::Select::new()
+          .select("n + 1")
+          .from("numbers")
+          .where_clause("n < 10"),
+      );
+      let query = sql::Select::new()
+        .with(sql::Cte::new("numbers").column("n").recursive(), numbers)
+        .select("n")
+        .from("numbers")
+        .as_string();
+      let expected_query = "\
+        WITH RECURSIVE numbers(n) AS ((SELECT 1) UNION (SELECT n + 1 FROM numbers WHERE n < 10)) \
+        SELECT n FROM numbers\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_recursive_should_render_the_keyword_once_for_all_the_expressions() {
+      let query = sql::Select::new()
+        .with("users_list", sql::Select::new().select("id").from("users"))
+        .with(
+          sql::Cte::new("tree").recursive(),
+          sql::Select::new().select("id").from("nodes"),
+        )
+        .as_string();
+      let expected_query = "\
+        WITH RECURSIVE users_list AS (SELECT id FROM users), \
+        tree AS (SELECT id FROM nodes)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+
+    #[test]
+    fn method_as_string_for_should_return_an_error_for_the_with_recursive_clause_in_mssql() {
+      let select = sql::Select::new()
+        .with(sql::Cte::new("tree").recursive(), sql::Select::new().select("1"))
+        .select("*")
+        .from("tree");
+      let expected_error = Err(sql::Error::UnsupportedClause {
+        clause: "WITH RECURSIVE",
+        dialect: sql::Dialect::MsSql,
+      });
+
+      assert!(select.as_string_for(sql::Dialect::Sqlite).is_ok());
+      assert_eq!(select.as_string_for(sql::Dialect::MsSql), expected_error);
+    }
+  }
+
+  mod delete_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_with_should_accept_the_cte_options() {
+      let query = sql::Delete::new()
+        .with(
+          sql::Cte::new("stale").column("id").recursive(),
+          sql::Select::new().select("id").from("sessions"),
+        )
+        .delete_from("sessions")
+        .where_clause("id in (select id from stale)")
+        .as_string();
+      let expected_query = "\
+        WITH RECURSIVE stale(id) AS (SELECT id FROM sessions) \
+        DELETE FROM sessions \
+        WHERE id in (select id from stale)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod insert_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_with_should_accept_the_cte_options() {
+      let query = sql::Insert::new()
+        .with(
+          sql::Cte::new("archived").column("login"),
+          sql::Select::new().select("login").from("users_bk"),
+        )
+        .insert_into("users (login)")
+        .select(sql::Select::new().select("login").from("archived"))
+        .as_string();
+      let expected_query = "\
+        WITH archived(login) AS (SELECT login FROM users_bk) \
+        INSERT INTO users (login) \
+        SELECT login FROM archived\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+
+  mod update_builder {
+    use pretty_assertions::assert_eq;
+    use sql_query_builder as sql;
+
+    #[test]
+    fn method_with_should_accept_the_cte_options() {
+      let query = sql::Update::new()
+        .with(
+          sql::Cte::new("totals").column("user_id").column("total"),
+          sql::Select::new().select("user_id, sum(amount)").from("orders"),
+        )
+        .update("users")
+        .set("total = (select total from totals where user_id = users.id)")
+        .as_string();
+      let expected_query = "\
+        WITH totals(user_id, total) AS (SELECT user_id, sum(amount) FROM orders) \
+        UPDATE users \
+        SET total = (select total from totals where user_id = users.id)\
+      ";
+
+      assert_eq!(query, expected_query);
+    }
+  }
+}
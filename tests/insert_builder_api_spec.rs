@@ -290,6 +290,311 @@ mod on_conflict_clause {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_on_conflict_column_should_add_the_conflict_target_in_parentheses() {
+    let query = sql::Insert::new().on_conflict_column("login").do_nothing().as_string();
+    let expected_query = "ON CONFLICT (login) DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_column_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Insert::new()
+      .on_conflict_column("tenant_id")
+      .on_conflict_column("login")
+      .as_string();
+    let expected_query = "ON CONFLICT (tenant_id, login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_column_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Insert::new()
+      .on_conflict_column("login")
+      .on_conflict_column("login")
+      .as_string();
+    let expected_query = "ON CONFLICT (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_column_should_trim_space_of_the_argument() {
+    let query = sql::Insert::new().on_conflict_column("  login  ").as_string();
+    let expected_query = "ON CONFLICT (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_on_constraint_should_add_the_constraint_as_the_conflict_target() {
+    let query = sql::Insert::new()
+      .on_conflict_on_constraint("users_login_key")
+      .do_nothing()
+      .as_string();
+    let expected_query = "ON CONFLICT ON CONSTRAINT users_login_key DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_on_constraint_should_override_the_conflict_columns() {
+    let query = sql::Insert::new()
+      .on_conflict_column("login")
+      .on_conflict_on_constraint("users_login_key")
+      .as_string();
+    let expected_query = "ON CONFLICT ON CONSTRAINT users_login_key";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_column_should_override_the_conflict_constraint() {
+    let query = sql::Insert::new()
+      .on_conflict_on_constraint("users_login_key")
+      .on_conflict_column("login")
+      .as_string();
+    let expected_query = "ON CONFLICT (login)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_nothing_should_add_the_action_without_a_conflict_target() {
+    let query = sql::Insert::new().do_nothing().as_string();
+    let expected_query = "ON CONFLICT DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_update_set_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Insert::new()
+      .on_conflict_column("login")
+      .do_update_set("name = excluded.name")
+      .do_update_set("updated_at = now()")
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO UPDATE SET name = excluded.name, updated_at = now()";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_update_set_should_trim_space_of_the_argument() {
+    let query = sql::Insert::new().do_update_set("  name = excluded.name  ").as_string();
+    let expected_query = "ON CONFLICT DO UPDATE SET name = excluded.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_update_set_should_override_the_do_nothing_action() {
+    let query = sql::Insert::new()
+      .on_conflict_column("login")
+      .do_nothing()
+      .do_update_set("name = excluded.name")
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO UPDATE SET name = excluded.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_nothing_should_override_the_do_update_action() {
+    let query = sql::Insert::new()
+      .on_conflict_column("login")
+      .do_update_set("name = excluded.name")
+      .do_update_where("users.active = true")
+      .do_nothing()
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_update_excluded_should_update_the_non_key_columns_of_the_insert_into_clause() {
+    let query = sql::Insert::new()
+      .insert_into("users (login, name, email)")
+      .on_conflict_column("login")
+      .do_update_excluded()
+      .as_string();
+    let expected_query = "\
+      INSERT INTO users (login, name, email) \
+      ON CONFLICT (login) DO UPDATE SET name = excluded.name, email = excluded.email\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_update_excluded_should_use_the_insert_into_clause_defined_after_it() {
+    let query = sql::Insert::new()
+      .on_conflict_column("id")
+      .do_update_excluded()
+      .insert_into("users (id, login)")
+      .as_string();
+    let expected_query = "INSERT INTO users (id, login) ON CONFLICT (id) DO UPDATE SET login = excluded.login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_update_excluded_should_render_before_the_do_update_set_assignments() {
+    let query = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .on_conflict_column("login")
+      .do_update_set("updated_at = now()")
+      .do_update_excluded()
+      .as_string();
+    let expected_query = "\
+      INSERT INTO users (login, name) \
+      ON CONFLICT (login) DO UPDATE SET name = excluded.name, updated_at = now()\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_update_excluded_should_match_the_quoted_columns_with_the_key_columns() {
+    let query = sql::Insert::new()
+      .insert_into(r#"users ("login", `name`, [email])"#)
+      .on_conflict_column("login")
+      .on_conflict_column("email")
+      .do_update_excluded()
+      .as_string();
+    let expected_query = r#"INSERT INTO users ("login", `name`, [email]) ON CONFLICT (login, email) DO UPDATE SET `name` = excluded.`name`"#;
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_update_excluded_should_skip_the_columns_assigned_by_the_do_update_set_method() {
+    let query = sql::Insert::new()
+      .insert_into("counters (a, b, c)")
+      .on_conflict_column("a")
+      .do_update_excluded()
+      .do_update_set("b = counters.b + 1")
+      .as_string();
+    let expected_query =
+      "INSERT INTO counters (a, b, c) ON CONFLICT (a) DO UPDATE SET c = excluded.c, b = counters.b + 1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_conflict_target_without_action() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login)")
+      .values("('foo')")
+      .on_conflict_column("login");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "ON CONFLICT WITHOUT ACTION",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(insert.as_string_for(sql::Dialect::Postgres), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_do_update_action_without_assignments() {
+    let all_key_columns = sql::Insert::new()
+      .insert_into("users (login)")
+      .on_conflict_column("login")
+      .do_update_excluded();
+    let without_columns = sql::Insert::new()
+      .insert_into("users")
+      .on_conflict_column("login")
+      .do_update_excluded();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "DO UPDATE WITHOUT ASSIGNMENTS",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(all_key_columns.as_string_for(sql::Dialect::Postgres), expected_error);
+    assert_eq!(without_columns.as_string_for(sql::Dialect::Postgres), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_do_update_action_without_conflict_target_in_postgres() {
+    let insert = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .do_update_set("name = excluded.name");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "DO UPDATE WITHOUT CONFLICT TARGET",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(insert.as_string_for(sql::Dialect::Postgres), expected_error);
+    assert!(insert.as_string_for(sql::Dialect::Sqlite).is_ok());
+  }
+
+  #[test]
+  fn method_do_update_where_should_combine_the_conditions_with_and_operator() {
+    let query = sql::Insert::new()
+      .on_conflict_column("login")
+      .do_update_set("name = excluded.name")
+      .do_update_where("users.active = true")
+      .do_update_where("users.locked = false")
+      .as_string();
+    let expected_query = "\
+      ON CONFLICT (login) DO UPDATE SET name = excluded.name \
+      WHERE users.active = true AND users.locked = false\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_should_override_the_structured_conflict_clause() {
+    let query = sql::Insert::new()
+      .on_conflict_column("login")
+      .do_update_set("name = excluded.name")
+      .on_conflict("do nothing")
+      .as_string();
+    let expected_query = "ON CONFLICT do nothing";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_conflict_column_should_override_the_raw_conflict_clause() {
+    let query = sql::Insert::new()
+      .on_conflict("do nothing")
+      .on_conflict_column("login")
+      .do_update_set("name = excluded.name")
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO UPDATE SET name = excluded.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_on_conflict_with_structured_methods_should_be_after_values_clause() {
+    let query = sql::Insert::new()
+      .do_nothing()
+      .values("('foo', 'Foo')")
+      .on_conflict_column("login")
+      .as_string();
+    let expected_query = "VALUES ('foo', 'Foo') ON CONFLICT (login) DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_the_structured_conflict_clause() {
+    let query = sql::Insert::new()
+      .on_conflict_column("login")
+      .do_nothing()
+      .raw_after(sql::InsertClause::OnConflict, "/* raw after */")
+      .as_string();
+    let expected_query = "ON CONFLICT (login) DO NOTHING /* raw after */";
+
+    assert_eq!(query, expected_query);
+  }
 }
 
 mod select_clause {
@@ -365,6 +670,27 @@ mod select_clause {
 
     assert_eq!(query, expected_query);
   }
+
+  #[test]
+  fn method_select_should_renumber_the_placeholders_of_the_select_past_the_insert_placeholders() {
+    let query = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .select(
+        sql::Select::new()
+          .select("login, name")
+          .from("users_bk")
+          .where_clause("login = $1"),
+      )
+      .on_conflict("(login) do update set name = $1")
+      .as_string();
+    let expected_query = "\
+      INSERT INTO users (login, name) \
+      SELECT login, name FROM users_bk WHERE login = $2 \
+      ON CONFLICT (login) do update set name = $1\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
 }
 
 mod values_clause {
@@ -442,3 +768,27 @@ mod values_clause {
     assert_eq!(query, expected_query);
   }
 }
+
+mod values_bind_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_values_bind_should_renumber_the_placeholders_of_each_row() {
+    let (query, params) = sql::Insert::new()
+      .insert_into("users (login, name)")
+      .values_bind("($1, $2)", ["foo".into(), "Foo".into()])
+      .values_bind("($1, $2)", ["bar".into(), sql::Value::Null])
+      .as_query();
+    let expected_query = "INSERT INTO users (login, name) VALUES ($1, $2), ($3, $4)";
+    let expected_params = vec![
+      sql::Value::from("foo"),
+      sql::Value::from("Foo"),
+      sql::Value::from("bar"),
+      sql::Value::Null,
+    ];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+}
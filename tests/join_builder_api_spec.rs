@@ -0,0 +1,152 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::Join::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn join_builder_should_be_displayable() {
+    let join = sql::Join::new().inner_join("orders o").on("o.user_id = u.id");
+
+    assert_eq!(format!("{join}"), "INNER JOIN orders o ON o.user_id = u.id");
+  }
+
+  #[test]
+  fn join_builder_should_be_debuggable() {
+    let join = sql::Join::new().inner_join("orders").using("user_id");
+
+    assert_eq!(format!("{join:?}"), "INNER JOIN orders USING (user_id)");
+  }
+
+  #[test]
+  fn join_builder_should_be_cloneable() {
+    let join_orders = sql::Join::new().left_join("orders o").on("o.user_id = u.id");
+    let join_paid_orders = join_orders.clone().on("o.status = 'paid'");
+
+    assert_eq!(join_orders.as_string(), "LEFT JOIN orders o ON o.user_id = u.id");
+    assert_eq!(
+      join_paid_orders.as_string(),
+      "LEFT JOIN orders o ON o.user_id = u.id AND o.status = 'paid'"
+    );
+  }
+}
+
+mod join_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn methods_of_the_join_types_should_add_the_join_clause() {
+    assert_eq!(sql::Join::new().full_join("a").as_string(), "FULL JOIN a");
+    assert_eq!(sql::Join::new().inner_join("a").as_string(), "INNER JOIN a");
+    assert_eq!(sql::Join::new().left_join("a").as_string(), "LEFT JOIN a");
+    assert_eq!(sql::Join::new().right_join("a").as_string(), "RIGHT JOIN a");
+  }
+
+  #[test]
+  fn method_on_should_not_be_rendered_without_a_join_type() {
+    let query = sql::Join::new().on("o.user_id = u.id").using("user_id").as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_of_the_join_types_should_override_the_current_value() {
+    let query = sql::Join::new().inner_join("orders").left_join("address").as_string();
+    let expected_query = "LEFT JOIN address";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_of_the_join_types_should_trim_space_of_the_argument() {
+    let query = sql::Join::new().inner_join("  orders  ").as_string();
+    let expected_query = "INNER JOIN orders";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod on_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_on_should_combine_the_conditions_with_and_operator() {
+    let query = sql::Join::new()
+      .inner_join("orders o")
+      .on("o.user_id = u.id")
+      .on("o.total > 0")
+      .as_string();
+    let expected_query = "INNER JOIN orders o ON o.user_id = u.id AND o.total > 0";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Join::new()
+      .inner_join("orders o")
+      .on("o.user_id = u.id")
+      .on("o.user_id = u.id")
+      .as_string();
+    let expected_query = "INNER JOIN orders o ON o.user_id = u.id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_trim_space_of_the_argument() {
+    let query = sql::Join::new()
+      .inner_join("orders o")
+      .on("  o.user_id = u.id  ")
+      .as_string();
+    let expected_query = "INNER JOIN orders o ON o.user_id = u.id";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod using_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_using_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Join::new()
+      .inner_join("orders")
+      .using("user_id")
+      .using("tenant_id")
+      .as_string();
+    let expected_query = "INNER JOIN orders USING (user_id, tenant_id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_trim_space_of_the_argument() {
+    let query = sql::Join::new().inner_join("orders").using("  user_id  ").as_string();
+    let expected_query = "INNER JOIN orders USING (user_id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_take_precedence_over_the_on_conditions() {
+    let query = sql::Join::new()
+      .inner_join("orders")
+      .on("orders.user_id = users.user_id")
+      .using("user_id")
+      .as_string();
+    let expected_query = "INNER JOIN orders USING (user_id)";
+
+    assert_eq!(query, expected_query);
+  }
+}
@@ -0,0 +1,772 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_as_string_should_convert_the_current_state_into_string() {
+    let query = sql::Merge::new().merge_into("customers").as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::Merge::new().merge_into("customers").debug().as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::Merge::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::Merge::new().merge_into("customers").print().as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::Merge::new().raw("merge into customers").as_string();
+    let expected_query = "merge into customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Merge::new()
+      .raw("merge into customers c")
+      .raw("using customers_bk b")
+      .as_string();
+    let expected_query = "merge into customers c using customers_bk b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::Merge::new()
+      .using("customers_bk b")
+      .raw("merge into customers c")
+      .as_string();
+    let expected_query = "merge into customers c USING customers_bk b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().raw("  merge into customers  ").as_string();
+    let expected_query = "merge into customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Merge::new()
+      .raw("merge into customers")
+      .raw("merge into customers")
+      .as_string();
+    let expected_query = "merge into customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new()
+      .raw_after(sql::MergeClause::MergeInto, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::MergeInto, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_merge_outside_postgres_and_mssql() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using("customers_bk b")
+      .on("c.id = b.id")
+      .delete();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "MERGE",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(merge.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(merge.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert!(merge.as_string_for(sql::Dialect::MsSql).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_do_nothing_outside_postgres() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using("customers_bk b")
+      .on("c.id = b.id")
+      .do_nothing();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "DO NOTHING",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(merge.as_string_for(sql::Dialect::MsSql), expected_error);
+    assert!(merge.as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_end_the_statement_with_a_semicolon_in_mssql() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using("customers_bk b")
+      .on("c.id = b.id")
+      .delete();
+
+    assert_eq!(
+      merge.as_string_for(sql::Dialect::MsSql),
+      Ok("MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN DELETE;".to_owned())
+    );
+    assert_eq!(
+      merge.as_string_for(sql::Dialect::Postgres),
+      Ok("MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN DELETE".to_owned())
+    );
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_when_clause_without_action() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using("customers_bk b")
+      .on("c.id = b.id")
+      .when_matched();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "WHEN WITHOUT ACTION",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(merge.as_string_for(sql::Dialect::Postgres), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_multiple_values_of_the_insert_action() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using("customers_bk b")
+      .on("c.id = b.id")
+      .values("(b.id)")
+      .values("(b.id + 1)");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "MULTIPLE VALUES",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(merge.as_string_for(sql::Dialect::Postgres), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_validate_the_source_query() {
+    let merge = sql::Merge::new()
+      .merge_into("customers c")
+      .using_query("b", sql::Select::new().select("*").from("customers_bk").limit("10"))
+      .on("c.id = b.id")
+      .delete();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "LIMIT",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(merge.as_string_for(sql::Dialect::MsSql), expected_error);
+  }
+}
+
+mod merge_into_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_merge_into_should_add_a_merge_into_clause() {
+    let query = sql::Merge::new().merge_into("customers").as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_merge_into_should_override_value_on_consecutive_calls() {
+    let query = sql::Merge::new()
+      .merge_into("customers")
+      .merge_into("orders")
+      .as_string();
+    let expected_query = "MERGE INTO orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_merge_into_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().merge_into("  customers  ").as_string();
+    let expected_query = "MERGE INTO customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_merge_into_should_accept_a_quoted_identifier() {
+    let query = sql::Merge::new()
+      .merge_into(sql::quote_ident("Customers", sql::Dialect::Postgres))
+      .as_string();
+    let expected_query = "MERGE INTO \"Customers\"";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_merge_into_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::MergeInto, "/* sync */")
+      .merge_into("customers")
+      .as_string();
+    let expected_query = "/* sync */ MERGE INTO customers";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_merge_into_clause() {
+    let query = sql::Merge::new()
+      .merge_into("customers")
+      .raw_after(sql::MergeClause::MergeInto, "as c")
+      .as_string();
+    let expected_query = "MERGE INTO customers as c";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod using_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_using_should_add_a_using_clause() {
+    let query = sql::Merge::new().using("customers_bk b").as_string();
+    let expected_query = "USING customers_bk b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_override_value_on_consecutive_calls() {
+    let query = sql::Merge::new()
+      .using("customers_bk b")
+      .using("customers_tmp b")
+      .as_string();
+    let expected_query = "USING customers_tmp b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().using("  customers_bk b  ").as_string();
+    let expected_query = "USING customers_bk b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_query_should_add_the_select_as_the_source() {
+    let query = sql::Merge::new()
+      .using_query(
+        "b",
+        sql::Select::new()
+          .select("id, name")
+          .from("customers_bk")
+          .where_clause("active = true"),
+      )
+      .as_string();
+    let expected_query = "USING (SELECT id, name FROM customers_bk WHERE active = true) AS b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_query_should_add_the_values_as_the_source() {
+    let query = sql::Merge::new()
+      .using_query("b (id, name)", sql::Values::new().values("(1, 'Foo')"))
+      .as_string();
+    let expected_query = "USING (VALUES (1, 'Foo')) AS b (id, name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_query_should_override_the_using_table() {
+    let query = sql::Merge::new()
+      .using("customers_bk b")
+      .using_query("b", sql::Values::new().values("(1, 'Foo')"))
+      .as_string();
+    let expected_query = "USING (VALUES (1, 'Foo')) AS b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_using_should_override_the_using_query() {
+    let query = sql::Merge::new()
+      .using_query("b", sql::Values::new().values("(1, 'Foo')"))
+      .using("customers_bk b")
+      .as_string();
+    let expected_query = "USING customers_bk b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_using_should_be_after_merge_into_clause() {
+    let query = sql::Merge::new()
+      .using("customers_bk b")
+      .merge_into("customers c")
+      .as_string();
+    let expected_query = "MERGE INTO customers c USING customers_bk b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_using_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::Using, "merge into customers c")
+      .using("customers_bk b")
+      .as_string();
+    let expected_query = "merge into customers c USING customers_bk b";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_using_clause() {
+    let query = sql::Merge::new()
+      .using("customers_bk b")
+      .raw_after(sql::MergeClause::Using, "on c.id = b.id")
+      .as_string();
+    let expected_query = "USING customers_bk b on c.id = b.id";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod on_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_on_should_add_a_on_clause() {
+    let query = sql::Merge::new().on("c.id = b.id").as_string();
+    let expected_query = "ON c.id = b.id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Merge::new()
+      .on("c.id = b.id")
+      .on("c.tenant_id = b.tenant_id")
+      .as_string();
+    let expected_query = "ON c.id = b.id AND c.tenant_id = b.tenant_id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Merge::new().on("c.id = b.id").on("c.id = b.id").as_string();
+    let expected_query = "ON c.id = b.id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_on_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().on("  c.id = b.id  ").as_string();
+    let expected_query = "ON c.id = b.id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_on_should_be_after_using_clause() {
+    let query = sql::Merge::new().on("c.id = b.id").using("customers_bk b").as_string();
+    let expected_query = "USING customers_bk b ON c.id = b.id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_on_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::On, "using customers_bk b")
+      .on("c.id = b.id")
+      .as_string();
+    let expected_query = "using customers_bk b ON c.id = b.id";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_on_clause() {
+    let query = sql::Merge::new()
+      .on("c.id = b.id")
+      .raw_after(sql::MergeClause::On, "when matched then delete")
+      .as_string();
+    let expected_query = "ON c.id = b.id when matched then delete";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod when_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_set_should_add_a_when_matched_update_action() {
+    let query = sql::Merge::new().set("name = b.name").as_string();
+    let expected_query = "WHEN MATCHED THEN UPDATE SET name = b.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Merge::new()
+      .set("name = b.name")
+      .set("email = b.email")
+      .as_string();
+    let expected_query = "WHEN MATCHED THEN UPDATE SET name = b.name, email = b.email";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Merge::new().set("name = b.name").set("name = b.name").as_string();
+    let expected_query = "WHEN MATCHED THEN UPDATE SET name = b.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_should_trim_space_of_the_argument() {
+    let query = sql::Merge::new().set("  name = b.name  ").as_string();
+    let expected_query = "WHEN MATCHED THEN UPDATE SET name = b.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_delete_should_add_a_when_matched_delete_action() {
+    let query = sql::Merge::new().delete().as_string();
+    let expected_query = "WHEN MATCHED THEN DELETE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_do_nothing_should_add_a_when_not_matched_do_nothing_action() {
+    let query = sql::Merge::new().do_nothing().as_string();
+    let expected_query = "WHEN NOT MATCHED THEN DO NOTHING";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_should_add_a_when_not_matched_insert_action() {
+    let query = sql::Merge::new().values("(b.id, b.name)").as_string();
+    let expected_query = "WHEN NOT MATCHED THEN INSERT VALUES (b.id, b.name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_should_add_the_columns_of_the_insert_action() {
+    let query = sql::Merge::new()
+      .values("(b.id, b.name)")
+      .insert("(id, name)")
+      .as_string();
+    let expected_query = "WHEN NOT MATCHED THEN INSERT (id, name) VALUES (b.id, b.name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_insert_should_override_value_on_consecutive_calls() {
+    let query = sql::Merge::new()
+      .insert("(id)")
+      .insert("  (id, name)  ")
+      .values("(b.id, b.name)")
+      .as_string();
+    let expected_query = "WHEN NOT MATCHED THEN INSERT (id, name) VALUES (b.id, b.name)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_when_matched_and_should_add_the_condition_of_the_when_clause() {
+    let query = sql::Merge::new()
+      .when_matched_and("  b.deleted = true  ")
+      .delete()
+      .as_string();
+    let expected_query = "WHEN MATCHED AND b.deleted = true THEN DELETE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_when_not_matched_and_should_add_the_condition_of_the_when_clause() {
+    let query = sql::Merge::new()
+      .when_not_matched_and("b.active = true")
+      .values("(b.id)")
+      .as_string();
+    let expected_query = "WHEN NOT MATCHED AND b.active = true THEN INSERT VALUES (b.id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_when_should_render_the_when_clauses_in_the_call_order() {
+    let query = sql::Merge::new()
+      .when_matched_and("b.deleted = true")
+      .delete()
+      .when_matched()
+      .set("name = b.name")
+      .when_not_matched()
+      .insert("(id, name)")
+      .values("(b.id, b.name)")
+      .as_string();
+    let expected_query = "\
+      WHEN MATCHED AND b.deleted = true THEN DELETE \
+      WHEN MATCHED THEN UPDATE SET name = b.name \
+      WHEN NOT MATCHED THEN INSERT (id, name) VALUES (b.id, b.name)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_delete_should_override_the_action_of_the_last_when_clause() {
+    let query = sql::Merge::new()
+      .when_matched()
+      .set("name = b.name")
+      .delete()
+      .as_string();
+    let expected_query = "WHEN MATCHED THEN DELETE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_values_after_set_should_start_a_when_not_matched_clause() {
+    let query = sql::Merge::new()
+      .set("name = b.name")
+      .values("(b.id, b.name)")
+      .as_string();
+    let expected_query = "\
+      WHEN MATCHED THEN UPDATE SET name = b.name \
+      WHEN NOT MATCHED THEN INSERT VALUES (b.id, b.name)\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_after_values_should_start_a_when_matched_clause() {
+    let query = sql::Merge::new()
+      .insert("(id, name)")
+      .values("(b.id, b.name)")
+      .set("name = b.name")
+      .as_string();
+    let expected_query = "\
+      WHEN NOT MATCHED THEN INSERT (id, name) VALUES (b.id, b.name) \
+      WHEN MATCHED THEN UPDATE SET name = b.name\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_set_after_when_not_matched_should_start_a_when_matched_clause() {
+    let query = sql::Merge::new().when_not_matched().set("name = b.name").as_string();
+    let expected_query = "WHEN NOT MATCHED WHEN MATCHED THEN UPDATE SET name = b.name";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_delete_after_values_should_start_a_when_matched_clause() {
+    let query = sql::Merge::new()
+      .when_not_matched_and("b.active = true")
+      .values("(b.id)")
+      .delete()
+      .as_string();
+    let expected_query = "\
+      WHEN NOT MATCHED AND b.active = true THEN INSERT VALUES (b.id) \
+      WHEN MATCHED THEN DELETE\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_when_should_be_after_on_clause() {
+    let query = sql::Merge::new().delete().on("c.id = b.id").as_string();
+    let expected_query = "ON c.id = b.id WHEN MATCHED THEN DELETE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_when_clause() {
+    let query = sql::Merge::new()
+      .raw_before(sql::MergeClause::When, "on c.id = b.id")
+      .delete()
+      .as_string();
+    let expected_query = "on c.id = b.id WHEN MATCHED THEN DELETE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_when_clause() {
+    let query = sql::Merge::new()
+      .delete()
+      .raw_after(sql::MergeClause::When, "when not matched by source then delete")
+      .as_string();
+    let expected_query = "WHEN MATCHED THEN DELETE when not matched by source then delete";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod bind_values {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_set_bind_should_add_the_set_clause_and_the_bound_value() {
+    let (query, params) = sql::Merge::new().set_bind("name = $1", "Foo").as_query();
+    let expected_query = "WHEN MATCHED THEN UPDATE SET name = $1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from("Foo")]);
+  }
+
+  #[test]
+  fn method_values_bind_should_renumber_the_placeholders_after_the_values_already_bound() {
+    let (query, params) = sql::Merge::new()
+      .set_bind("name = $1", "Foo")
+      .when_not_matched()
+      .values_bind("(b.id, $1, $2)", [sql::Value::from("Foo"), sql::Value::from(true)])
+      .as_query();
+    let expected_query = "\
+      WHEN MATCHED THEN UPDATE SET name = $1 \
+      WHEN NOT MATCHED THEN INSERT VALUES (b.id, $2, $3)\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(
+      params,
+      vec![sql::Value::from("Foo"), sql::Value::from("Foo"), sql::Value::from(true)]
+    );
+  }
+
+  #[test]
+  fn methods_set_bind_and_values_bind_should_keep_the_actions_and_the_values_in_the_call_order() {
+    let (query, params) = sql::Merge::new()
+      .set_bind("synced_by = $1", "foo")
+      .values_bind("(b.id, $1)", [sql::Value::from("bar")])
+      .set_bind("synced_at = $1", "2024-01-01")
+      .as_query();
+    let expected_query = "\
+      WHEN MATCHED THEN UPDATE SET synced_by = $1 \
+      WHEN NOT MATCHED THEN INSERT VALUES (b.id, $2) \
+      WHEN MATCHED THEN UPDATE SET synced_at = $3\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(
+      params,
+      vec![
+        sql::Value::from("foo"),
+        sql::Value::from("bar"),
+        sql::Value::from("2024-01-01")
+      ]
+    );
+  }
+
+  #[test]
+  fn method_as_query_should_renumber_the_placeholders_of_the_source_after_the_merge_values() {
+    let source = sql::Select::new()
+      .select("*")
+      .from("customers_bk")
+      .where_bind("tenant_id = $1", 42);
+    let (query, params) = sql::Merge::new()
+      .merge_into("customers c")
+      .using_query("b", source)
+      .on("c.id = b.id")
+      .set_bind("synced_by = $1", "foo")
+      .as_query();
+    let expected_query = "\
+      MERGE INTO customers c \
+      USING (SELECT * FROM customers_bk WHERE tenant_id = $2) AS b \
+      ON c.id = b.id \
+      WHEN MATCHED THEN UPDATE SET synced_by = $1\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from(42)]);
+  }
+
+  #[test]
+  fn method_as_query_with_should_reorder_the_values_to_follow_the_question_mark_placeholders() {
+    let source = sql::Select::new()
+      .select("*")
+      .from("customers_bk")
+      .where_bind("tenant_id = $1", 42);
+    let (query, params) = sql::Merge::new()
+      .using_query("b", source)
+      .set_bind("synced_by = $1", "foo")
+      .as_query_with(sql::Placeholder::QuestionMark)
+      .unwrap();
+    let expected_query = "\
+      USING (SELECT * FROM customers_bk WHERE tenant_id = ?) AS b \
+      WHEN MATCHED THEN UPDATE SET synced_by = ?\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from(42), sql::Value::from("foo")]);
+  }
+}
@@ -0,0 +1,146 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn merge_builder_should_be_displayable() {
+  let merge = sql::Merge::new()
+    .merge_into("customers c")
+    .using("customers_bk b")
+    .on("c.id = b.id")
+    .set("name = b.name");
+
+  println!("{}", merge);
+
+  let query = merge.as_string();
+  let expected_query =
+    "MERGE INTO customers c USING customers_bk b ON c.id = b.id WHEN MATCHED THEN UPDATE SET name = b.name";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn merge_builder_should_be_debuggable() {
+  let merge = sql::Merge::new()
+    .merge_into("customers c")
+    .using("customers_bk b")
+    .on("c.id = b.id")
+    .set("name = b.name")
+    .when_not_matched()
+    .insert("(id, name)")
+    .values("(b.id, b.name)");
+
+  println!("{:?}", merge);
+
+  let expected_query = "\
+    MERGE INTO customers c \
+    USING customers_bk b \
+    ON c.id = b.id \
+    WHEN MATCHED THEN UPDATE SET name = b.name \
+    WHEN NOT MATCHED THEN INSERT (id, name) VALUES (b.id, b.name)\
+  ";
+  let query = merge.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn merge_builder_should_be_cloneable() {
+  let merge_update = sql::Merge::new()
+    .raw("/* test raw */")
+    .raw_before(sql::MergeClause::MergeInto, "/* test raw_before */")
+    .raw_after(sql::MergeClause::On, "/* test raw_after */")
+    .merge_into("customers c")
+    .using("customers_bk b")
+    .on("c.id = b.id")
+    .set("name = b.name");
+
+  let merge_upsert = merge_update.clone().when_not_matched().values("(b.id, b.name)");
+
+  let query_update = merge_update.as_string();
+  let query_upsert = merge_upsert.as_string();
+
+  let expected_query_update = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    MERGE INTO customers c \
+    USING customers_bk b \
+    ON c.id = b.id \
+    /* test raw_after */ \
+    WHEN MATCHED THEN UPDATE SET name = b.name\
+  ";
+  let expected_query_upsert = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    MERGE INTO customers c \
+    USING customers_bk b \
+    ON c.id = b.id \
+    /* test raw_after */ \
+    WHEN MATCHED THEN UPDATE SET name = b.name \
+    WHEN NOT MATCHED THEN INSERT VALUES (b.id, b.name)\
+  ";
+
+  assert_eq!(query_update, expected_query_update);
+  assert_eq!(query_upsert, expected_query_upsert);
+}
+
+#[test]
+fn merge_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut merge = sql::Merge::new()
+    .merge_into("customers c")
+    .using("customers_bk b")
+    .on("c.id = b.id")
+    .set("name = b.name");
+
+  if true {
+    merge = merge.set("email = b.email");
+  }
+
+  let query = merge.as_string();
+  let expected_query = "\
+    MERGE INTO customers c \
+    USING customers_bk b \
+    ON c.id = b.id \
+    WHEN MATCHED THEN UPDATE SET name = b.name, email = b.email\
+  ";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn merge_builder_should_be_composable() {
+  fn target_and_source(merge: sql::Merge) -> sql::Merge {
+    merge
+      .merge_into("customers c")
+      .using("customers_bk b")
+      .on("c.id = b.id")
+  }
+
+  fn update_matched(merge: sql::Merge) -> sql::Merge {
+    merge.when_matched().set("name = b.name")
+  }
+
+  fn insert_not_matched(merge: sql::Merge) -> sql::Merge {
+    merge.when_not_matched().insert("(id, name)").values("(b.id, b.name)")
+  }
+
+  fn as_string(merge: sql::Merge) -> String {
+    merge.as_string()
+  }
+
+  let query = Some(sql::Merge::new())
+    .map(target_and_source)
+    .map(update_matched)
+    .map(insert_not_matched)
+    .map(as_string)
+    .unwrap();
+
+  let expected_query = "\
+    MERGE INTO customers c \
+    USING customers_bk b \
+    ON c.id = b.id \
+    WHEN MATCHED THEN UPDATE SET name = b.name \
+    WHEN NOT MATCHED THEN INSERT (id, name) VALUES (b.id, b.name)\
+  ";
+
+  assert_eq!(query, expected_query);
+}
@@ -0,0 +1,155 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_parentheses() {
+    let query = sql::Over::new().as_string();
+    let expected_query = "()";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn over_builder_should_be_displayable_inside_column_expressions() {
+    let over = sql::Over::new().partition_by("department_id").order_by("salary desc");
+    let query = sql::Select::new()
+      .select(&format!("name, row_number() OVER {over}"))
+      .from("employees")
+      .as_string();
+    let expected_query =
+      "SELECT name, row_number() OVER (PARTITION BY department_id ORDER BY salary desc) FROM employees";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn over_builder_should_be_debuggable() {
+    let over = sql::Over::new().order_by("id");
+
+    assert_eq!(format!("{over:?}"), "(ORDER BY id)");
+  }
+
+  #[test]
+  fn over_builder_should_be_cloneable() {
+    let by_department = sql::Over::new().partition_by("department_id");
+    let by_department_and_salary = by_department.clone().order_by("salary");
+
+    assert_eq!(by_department.as_string(), "(PARTITION BY department_id)");
+    assert_eq!(
+      by_department_and_salary.as_string(),
+      "(PARTITION BY department_id ORDER BY salary)"
+    );
+  }
+}
+
+mod partition_by_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_partition_by_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Over::new()
+      .partition_by("tenant_id")
+      .partition_by("department_id")
+      .as_string();
+    let expected_query = "(PARTITION BY tenant_id, department_id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_partition_by_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Over::new()
+      .partition_by("tenant_id")
+      .partition_by("tenant_id")
+      .as_string();
+    let expected_query = "(PARTITION BY tenant_id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_partition_by_should_trim_space_of_the_argument() {
+    let query = sql::Over::new().partition_by("  tenant_id  ").as_string();
+    let expected_query = "(PARTITION BY tenant_id)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod order_by_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_order_by_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Over::new().order_by("score desc").order_by("id").as_string();
+    let expected_query = "(ORDER BY score desc, id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_order_by_should_trim_space_of_the_argument() {
+    let query = sql::Over::new().order_by("  id  ").as_string();
+    let expected_query = "(ORDER BY id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_order_by_should_be_after_partition_by_clause() {
+    let query = sql::Over::new().order_by("id").partition_by("tenant_id").as_string();
+    let expected_query = "(PARTITION BY tenant_id ORDER BY id)";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod frame_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_frame_should_add_the_frame_clause() {
+    let query = sql::Over::new()
+      .frame("ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING")
+      .as_string();
+    let expected_query = "(ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_frame_should_override_the_current_value() {
+    let query = sql::Over::new()
+      .frame("ROWS UNBOUNDED PRECEDING")
+      .frame("RANGE UNBOUNDED PRECEDING")
+      .as_string();
+    let expected_query = "(RANGE UNBOUNDED PRECEDING)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_frame_should_trim_space_of_the_argument() {
+    let query = sql::Over::new().frame("  ROWS UNBOUNDED PRECEDING  ").as_string();
+    let expected_query = "(ROWS UNBOUNDED PRECEDING)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_frame_should_be_after_order_by_clause() {
+    let query = sql::Over::new()
+      .frame("ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW")
+      .order_by("created_at")
+      .partition_by("account_id")
+      .as_string();
+    let expected_query =
+      "(PARTITION BY account_id ORDER BY created_at ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)";
+
+    assert_eq!(query, expected_query);
+  }
+}
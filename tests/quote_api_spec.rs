@@ -0,0 +1,90 @@
+use sql_query_builder as sql;
+
+mod quote_ident {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn function_quote_ident_should_use_double_quotes_for_postgres_and_sqlite() {
+    assert_eq!(sql::quote_ident("users", sql::Dialect::Postgres), "\"users\"");
+    assert_eq!(sql::quote_ident("users", sql::Dialect::Sqlite), "\"users\"");
+  }
+
+  #[test]
+  fn function_quote_ident_should_use_backticks_for_mysql() {
+    assert_eq!(sql::quote_ident("users", sql::Dialect::MySql), "`users`");
+  }
+
+  #[test]
+  fn function_quote_ident_should_use_brackets_for_mssql() {
+    assert_eq!(sql::quote_ident("users", sql::Dialect::MsSql), "[users]");
+  }
+
+  #[test]
+  fn function_quote_ident_should_escape_the_quotes_inside_the_name() {
+    assert_eq!(sql::quote_ident("my\"table", sql::Dialect::Postgres), "\"my\"\"table\"");
+    assert_eq!(sql::quote_ident("my`table", sql::Dialect::MySql), "`my``table`");
+    assert_eq!(sql::quote_ident("my]table", sql::Dialect::MsSql), "[my]]table]");
+    assert_eq!(sql::quote_ident("my[table", sql::Dialect::MsSql), "[my[table]");
+  }
+}
+
+mod quote_literal {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn function_quote_literal_should_escape_single_quotes() {
+    assert_eq!(sql::quote_literal("it's", sql::Dialect::Postgres), "'it''s'");
+    assert_eq!(sql::quote_literal("it's", sql::Dialect::Sqlite), "'it''s'");
+    assert_eq!(sql::quote_literal("it's", sql::Dialect::MySql), "'it''s'");
+    assert_eq!(sql::quote_literal("it's", sql::Dialect::MsSql), "'it''s'");
+  }
+
+  #[test]
+  fn function_quote_literal_should_escape_backslashes_only_for_mysql() {
+    assert_eq!(sql::quote_literal("a\\b", sql::Dialect::Postgres), "'a\\b'");
+    assert_eq!(sql::quote_literal("a\\b", sql::Dialect::MySql), "'a\\\\b'");
+  }
+}
+
+mod quote_qualified {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn function_quote_qualified_should_quote_each_part_of_the_name() {
+    assert_eq!(
+      sql::quote_qualified(&["public", "users"], sql::Dialect::Postgres),
+      "\"public\".\"users\""
+    );
+    assert_eq!(
+      sql::quote_qualified(&["dbo", "users"], sql::Dialect::MsSql),
+      "[dbo].[users]"
+    );
+    assert_eq!(sql::quote_qualified(&["users"], sql::Dialect::MySql), "`users`");
+  }
+}
+
+mod builders_integration {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn quoted_names_should_be_accepted_by_the_table_clauses() {
+    let table = sql::quote_qualified(&["sales", "orders"], sql::Dialect::Postgres);
+    let column = sql::quote_ident("Total", sql::Dialect::Postgres);
+
+    let select = sql::Select::new().select(&column).from(&table).as_string();
+    let insert = sql::Insert::new().insert_into(table.clone()).as_string();
+    let update = sql::Update::new()
+      .update(sql::quote_ident("orders", sql::Dialect::Postgres))
+      .as_string();
+    let delete = sql::Delete::new().delete_from(&table).as_string();
+
+    assert_eq!(select, "SELECT \"Total\" FROM \"sales\".\"orders\"");
+    assert_eq!(insert, "INSERT INTO \"sales\".\"orders\"");
+    assert_eq!(update, "UPDATE \"orders\"");
+    assert_eq!(delete, "DELETE FROM \"sales\".\"orders\"");
+  }
+}
@@ -0,0 +1,270 @@
+#![cfg(feature = "postgresql")]
+
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_as_string_should_convert_the_current_state_into_string() {
+    let query = sql::RefreshMaterializedView::new()
+      .refresh_materialized_view("sales_summary")
+      .as_string();
+    let expected_query = "REFRESH MATERIALIZED VIEW sales_summary";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::RefreshMaterializedView::new()
+      .refresh_materialized_view("sales_summary")
+      .with_data()
+      .debug()
+      .as_string();
+    let expected_query = "REFRESH MATERIALIZED VIEW sales_summary WITH DATA";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::RefreshMaterializedView::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::RefreshMaterializedView::new()
+      .refresh_materialized_view("sales_summary")
+      .with_data()
+      .print()
+      .as_string();
+    let expected_query = "REFRESH MATERIALIZED VIEW sales_summary WITH DATA";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::RefreshMaterializedView::new()
+      .raw("refresh materialized view sales_summary")
+      .as_string();
+    let expected_query = "refresh materialized view sales_summary";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::RefreshMaterializedView::new()
+      .raw("refresh materialized view")
+      .raw("sales_summary")
+      .as_string();
+    let expected_query = "refresh materialized view sales_summary";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::RefreshMaterializedView::new()
+      .with_data()
+      .raw("refresh materialized view sales_summary")
+      .as_string();
+    let expected_query = "refresh materialized view sales_summary WITH DATA";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_trim_space_of_the_argument() {
+    let query = sql::RefreshMaterializedView::new()
+      .raw("  refresh materialized view sales_summary  ")
+      .as_string();
+    let expected_query = "refresh materialized view sales_summary";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::RefreshMaterializedView::new()
+      .raw_after(
+        sql::RefreshMaterializedViewClause::RefreshMaterializedView,
+        "  /* raw one */  ",
+      )
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::RefreshMaterializedView::new()
+      .raw_before(sql::RefreshMaterializedViewClause::WithData, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_outside_postgres() {
+    let refresh = sql::RefreshMaterializedView::new().refresh_materialized_view("sales_summary");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "REFRESH MATERIALIZED VIEW",
+      dialect: sql::Dialect::MySql,
+    });
+
+    assert_eq!(refresh.as_string_for(sql::Dialect::MySql), expected_error);
+    assert!(refresh.as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+}
+
+mod refresh_materialized_view_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_refresh_materialized_view_should_override_the_current_value() {
+    let query = sql::RefreshMaterializedView::new()
+      .refresh_materialized_view("sales_summary")
+      .refresh_materialized_view("orders_summary")
+      .as_string();
+    let expected_query = "REFRESH MATERIALIZED VIEW orders_summary";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_refresh_materialized_view_should_trim_space_of_the_argument() {
+    let query = sql::RefreshMaterializedView::new()
+      .refresh_materialized_view("  sales_summary  ")
+      .as_string();
+    let expected_query = "REFRESH MATERIALIZED VIEW sales_summary";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_concurrently_should_add_the_concurrently_option_before_the_view_name() {
+    let query = sql::RefreshMaterializedView::new()
+      .concurrently()
+      .refresh_materialized_view("sales_summary")
+      .as_string();
+    let expected_query = "REFRESH MATERIALIZED VIEW CONCURRENTLY sales_summary";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_refresh_materialized_view_clause() {
+    let query = sql::RefreshMaterializedView::new()
+      .raw_before(
+        sql::RefreshMaterializedViewClause::RefreshMaterializedView,
+        "/* nightly refresh */",
+      )
+      .refresh_materialized_view("sales_summary")
+      .as_string();
+    let expected_query = "/* nightly refresh */ REFRESH MATERIALIZED VIEW sales_summary";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_refresh_materialized_view_clause() {
+    let query = sql::RefreshMaterializedView::new()
+      .refresh_materialized_view("sales_summary")
+      .raw_after(
+        sql::RefreshMaterializedViewClause::RefreshMaterializedView,
+        "/* nightly refresh */",
+      )
+      .as_string();
+    let expected_query = "REFRESH MATERIALIZED VIEW sales_summary /* nightly refresh */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod with_data_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_with_data_should_add_the_with_data_clause() {
+    let query = sql::RefreshMaterializedView::new().with_data().as_string();
+    let expected_query = "WITH DATA";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_no_data_should_add_the_with_no_data_clause() {
+    let query = sql::RefreshMaterializedView::new().with_no_data().as_string();
+    let expected_query = "WITH NO DATA";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_with_no_data_should_override_the_with_data_clause() {
+    let query = sql::RefreshMaterializedView::new()
+      .with_data()
+      .with_no_data()
+      .as_string();
+    let expected_query = "WITH NO DATA";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_with_no_data_clause_with_concurrently() {
+    let refresh = sql::RefreshMaterializedView::new()
+      .refresh_materialized_view("sales_summary")
+      .concurrently()
+      .with_no_data();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "CONCURRENTLY WITH NO DATA",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(refresh.as_string_for(sql::Dialect::Postgres), expected_error);
+    assert!(refresh.with_data().as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+
+  #[test]
+  fn clause_with_data_should_be_after_refresh_materialized_view_clause() {
+    let query = sql::RefreshMaterializedView::new()
+      .with_no_data()
+      .refresh_materialized_view("sales_summary")
+      .as_string();
+    let expected_query = "REFRESH MATERIALIZED VIEW sales_summary WITH NO DATA";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_with_data_clause() {
+    let query = sql::RefreshMaterializedView::new()
+      .raw_before(sql::RefreshMaterializedViewClause::WithData, "/* populate */")
+      .with_data()
+      .as_string();
+    let expected_query = "/* populate */ WITH DATA";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_with_data_clause() {
+    let query = sql::RefreshMaterializedView::new()
+      .with_data()
+      .raw_after(sql::RefreshMaterializedViewClause::WithData, "/* populate */")
+      .as_string();
+    let expected_query = "WITH DATA /* populate */";
+
+    assert_eq!(query, expected_query);
+  }
+}
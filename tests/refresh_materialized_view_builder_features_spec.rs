@@ -0,0 +1,104 @@
+#![cfg(feature = "postgresql")]
+
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn refresh_materialized_view_builder_should_be_displayable() {
+  let refresh = sql::RefreshMaterializedView::new().refresh_materialized_view("sales_summary");
+
+  println!("{}", refresh);
+
+  let query = refresh.as_string();
+  let expected_query = "REFRESH MATERIALIZED VIEW sales_summary";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn refresh_materialized_view_builder_should_be_debuggable() {
+  let refresh = sql::RefreshMaterializedView::new()
+    .refresh_materialized_view("sales_summary")
+    .concurrently()
+    .with_data();
+
+  println!("{:?}", refresh);
+
+  let expected_query = "REFRESH MATERIALIZED VIEW CONCURRENTLY sales_summary WITH DATA";
+  let query = refresh.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn refresh_materialized_view_builder_should_be_cloneable() {
+  let refresh = sql::RefreshMaterializedView::new()
+    .raw("/* test raw */")
+    .raw_before(
+      sql::RefreshMaterializedViewClause::RefreshMaterializedView,
+      "/* test raw_before */",
+    )
+    .raw_after(sql::RefreshMaterializedViewClause::WithData, "/* test raw_after */")
+    .refresh_materialized_view("sales_summary");
+
+  let refresh_no_data = refresh.clone().with_no_data();
+
+  let query_refresh = refresh.as_string();
+  let query_refresh_no_data = refresh_no_data.as_string();
+
+  let expected_query_refresh = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    REFRESH MATERIALIZED VIEW sales_summary \
+    /* test raw_after */\
+  ";
+  let expected_query_refresh_no_data = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    REFRESH MATERIALIZED VIEW sales_summary \
+    WITH NO DATA \
+    /* test raw_after */\
+  ";
+
+  assert_eq!(query_refresh, expected_query_refresh);
+  assert_eq!(query_refresh_no_data, expected_query_refresh_no_data);
+}
+
+#[test]
+fn refresh_materialized_view_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut refresh = sql::RefreshMaterializedView::new().refresh_materialized_view("sales_summary");
+
+  if true {
+    refresh = refresh.concurrently();
+  }
+
+  let query = refresh.as_string();
+  let expected_query = "REFRESH MATERIALIZED VIEW CONCURRENTLY sales_summary";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn refresh_materialized_view_builder_should_be_composable() {
+  fn without_locks(refresh: sql::RefreshMaterializedView) -> sql::RefreshMaterializedView {
+    refresh.concurrently()
+  }
+
+  fn populated(refresh: sql::RefreshMaterializedView) -> sql::RefreshMaterializedView {
+    refresh.with_data()
+  }
+
+  fn as_string(refresh: sql::RefreshMaterializedView) -> String {
+    refresh.as_string()
+  }
+
+  let query = Some(sql::RefreshMaterializedView::new().refresh_materialized_view("sales_summary"))
+    .map(without_locks)
+    .map(populated)
+    .map(as_string)
+    .unwrap();
+
+  let expected_query = "REFRESH MATERIALIZED VIEW CONCURRENTLY sales_summary WITH DATA";
+
+  assert_eq!(query, expected_query);
+}
@@ -254,3 +254,321 @@ mod right_join_clause {
     assert_eq!(query, expected_query);
   }
 }
+
+mod full_join_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_full_join_should_add_the_full_join_clause() {
+    let query = sql::Select::new()
+      .full_join("address ON users.login = address.login")
+      .as_string();
+    let expected_query = "FULL JOIN address ON users.login = address.login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_full_join_by_should_trim_space_of_the_argument() {
+    let query = sql::Select::new().full_join("  orders  ").as_string();
+    let expected_query = "FULL JOIN orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_full_join_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Select::new().full_join("address").full_join("address").as_string();
+    let expected_query = "FULL JOIN address";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_full_join_clause_in_mysql() {
+    let select = sql::Select::new()
+      .from("users")
+      .full_join("address ON users.login = address.login");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "FULL JOIN",
+      dialect: sql::Dialect::MySql,
+    });
+
+    assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert_eq!(select.as_string_for(sql::Dialect::MySql), expected_error);
+  }
+}
+
+mod natural_join_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_natural_join_should_add_the_natural_join_clause() {
+    let query = sql::Select::new().from("users").natural_join("profiles").as_string();
+    let expected_query = "FROM users NATURAL JOIN profiles";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_natural_join_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Select::new()
+      .natural_join("profiles")
+      .natural_join("settings")
+      .as_string();
+    let expected_query = "NATURAL JOIN profiles NATURAL JOIN settings";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_natural_join_by_should_trim_space_of_the_argument() {
+    let query = sql::Select::new().natural_join("  profiles  ").as_string();
+    let expected_query = "NATURAL JOIN profiles";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_natural_join_clause_in_mssql() {
+    let select = sql::Select::new().from("users").natural_join("profiles");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "NATURAL JOIN",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert!(select.as_string_for(sql::Dialect::Sqlite).is_ok());
+    assert_eq!(select.as_string_for(sql::Dialect::MsSql), expected_error);
+  }
+}
+
+mod lateral_join_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_cross_join_lateral_should_add_the_subquery_with_the_alias() {
+    let query = sql::Select::new()
+      .from("users u")
+      .cross_join_lateral(
+        "o",
+        sql::Select::new()
+          .select("*")
+          .from("orders")
+          .where_clause("user_id = u.id"),
+      )
+      .as_string();
+    let expected_query = "FROM users u CROSS JOIN LATERAL (SELECT * FROM orders WHERE user_id = u.id) o";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_left_join_lateral_should_add_the_subquery_with_the_on_true_condition() {
+    let query = sql::Select::new()
+      .from("users u")
+      .left_join_lateral(
+        "o",
+        sql::Select::new()
+          .select("*")
+          .from("orders")
+          .where_clause("user_id = u.id"),
+      )
+      .as_string();
+    let expected_query = "FROM users u LEFT JOIN LATERAL (SELECT * FROM orders WHERE user_id = u.id) o ON true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_left_join_lateral_should_trim_space_of_the_alias() {
+    let query = sql::Select::new()
+      .left_join_lateral("  o  ", sql::Select::new().select("1"))
+      .as_string();
+    let expected_query = "LEFT JOIN LATERAL (SELECT 1) o ON true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn lateral_joins_should_be_rendered_in_the_call_order_with_the_other_joins() {
+    let query = sql::Select::new()
+      .from("users u")
+      .inner_join("profiles p ON p.user_id = u.id")
+      .cross_join_lateral("o", sql::Select::new().select("count(*)").from("orders"))
+      .left_join("address a ON a.user_id = u.id")
+      .as_string();
+    let expected_query = "\
+      FROM users u \
+      INNER JOIN profiles p ON p.user_id = u.id \
+      CROSS JOIN LATERAL (SELECT count(*) FROM orders) o \
+      LEFT JOIN address a ON a.user_id = u.id\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_query_should_shift_the_placeholders_of_the_lateral_subquery() {
+    let (query, params) = sql::Select::new()
+      .from("users u")
+      .cross_join_lateral(
+        "o",
+        sql::Select::new()
+          .select("*")
+          .from("orders")
+          .where_bind("total > $1", 100),
+      )
+      .where_bind("u.login = $1", "foo")
+      .as_query();
+    let expected_query = "FROM users u CROSS JOIN LATERAL (SELECT * FROM orders WHERE total > $2) o WHERE u.login = $1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from("foo"), sql::Value::from(100)]);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_lateral_joins_outside_postgres_and_mysql() {
+    let select = sql::Select::new()
+      .from("users u")
+      .left_join_lateral("o", sql::Select::new().select("*").from("orders"));
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "LATERAL",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert!(select.as_string_for(sql::Dialect::MySql).is_ok());
+    assert_eq!(select.as_string_for(sql::Dialect::Sqlite), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_validate_the_lateral_subquery() {
+    let select = sql::Select::new()
+      .from("users u")
+      .cross_join_lateral("o", sql::Select::new().select("*").from("orders").for_no_key_update());
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "FOR NO KEY UPDATE",
+      dialect: sql::Dialect::MySql,
+    });
+
+    assert_eq!(select.as_string_for(sql::Dialect::MySql), expected_error);
+  }
+}
+
+mod join_method {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_join_should_add_the_join_with_the_on_conditions_combined_with_and() {
+    let query = sql::Select::new()
+      .from("users u")
+      .join(
+        sql::Join::new()
+          .left_join("address a")
+          .on("a.user_id = u.id")
+          .on("a.main = true"),
+      )
+      .as_string();
+    let expected_query = "FROM users u LEFT JOIN address a ON a.user_id = u.id AND a.main = true";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_join_should_add_the_join_with_the_using_column_list() {
+    let query = sql::Select::new()
+      .from("users")
+      .join(
+        sql::Join::new()
+          .inner_join("orders")
+          .using("user_id")
+          .using("tenant_id"),
+      )
+      .as_string();
+    let expected_query = "FROM users INNER JOIN orders USING (user_id, tenant_id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_join_should_not_accumulate_arguments_with_the_same_content() {
+    let join = sql::Join::new().inner_join("orders").using("user_id");
+    let query = sql::Select::new().join(join.clone()).join(join).as_string();
+    let expected_query = "INNER JOIN orders USING (user_id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_join_should_be_rendered_in_the_call_order_with_the_other_joins() {
+    let query = sql::Select::new()
+      .join(sql::Join::new().full_join("orders o").on("o.user_id = u.id"))
+      .cross_join("settings")
+      .as_string();
+    let expected_query = "FULL JOIN orders o ON o.user_id = u.id CROSS JOIN settings";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_using_column_list_in_mssql() {
+    let select = sql::Select::new()
+      .from("users")
+      .join(sql::Join::new().inner_join("orders").using("user_id"));
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "USING",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert!(select.as_string_for(sql::Dialect::MySql).is_ok());
+    assert_eq!(select.as_string_for(sql::Dialect::MsSql), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_using_column_list_with_the_on_conditions() {
+    let select = sql::Select::new().from("users").join(
+      sql::Join::new()
+        .inner_join("orders")
+        .on("orders.user_id = users.user_id")
+        .using("user_id"),
+    );
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "USING WITH ON",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(select.as_string_for(sql::Dialect::Postgres), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_full_join_of_the_join_builder_in_mysql() {
+    let select = sql::Select::new()
+      .from("users u")
+      .join(sql::Join::new().full_join("orders o").on("o.user_id = u.id"));
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "FULL JOIN",
+      dialect: sql::Dialect::MySql,
+    });
+
+    assert_eq!(select.as_string_for(sql::Dialect::MySql), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_join_builder_without_a_join_type() {
+    let select = sql::Select::new()
+      .from("users u")
+      .join(sql::Join::new().on("o.user_id = u.id"))
+      .where_clause("u.active = true");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "JOIN WITHOUT TYPE",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(select.as_string(), "FROM users u WHERE u.active = true");
+    assert_eq!(select.as_string_for(sql::Dialect::Postgres), expected_error);
+  }
+}
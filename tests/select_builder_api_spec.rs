@@ -172,6 +172,59 @@ mod and_clause {
   }
 }
 
+mod distinct_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_distinct_should_add_the_distinct_option_to_the_select_clause() {
+    let query = sql::Select::new().select("login").distinct().as_string();
+    let expected_query = "SELECT DISTINCT login";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_distinct_should_render_before_all_columns_regardless_of_the_call_order() {
+    let query = sql::Select::new()
+      .select("country")
+      .distinct()
+      .select("city")
+      .as_string();
+    let expected_query = "SELECT DISTINCT country, city";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_distinct_should_render_when_called_before_the_select_method() {
+    let query = sql::Select::new().distinct().select("login").from("users").as_string();
+    let expected_query = "SELECT DISTINCT login FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_distinct_should_not_render_without_the_select_clause() {
+    let query = sql::Select::new().distinct().from("users").as_string();
+    let expected_query = "FROM users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_select_clause_should_be_added_after_the_distinct_columns() {
+    let query = sql::Select::new()
+      .distinct()
+      .select("login")
+      .raw_after(sql::SelectClause::Select, "from users")
+      .as_string();
+    let expected_query = "SELECT DISTINCT login from users";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
 mod from_clause {
   use super::*;
   use pretty_assertions::assert_eq;
@@ -453,10 +506,230 @@ mod limit_clause {
   }
 }
 
+mod lock_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_for_update_should_add_the_for_update_clause() {
+    let query = sql::Select::new().for_update().as_string();
+    let expected_query = "FOR UPDATE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_for_no_key_update_should_add_the_for_no_key_update_clause() {
+    let query = sql::Select::new().for_no_key_update().as_string();
+    let expected_query = "FOR NO KEY UPDATE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_for_share_should_add_the_for_share_clause() {
+    let query = sql::Select::new().for_share().as_string();
+    let expected_query = "FOR SHARE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_for_share_should_override_the_current_lock_strength() {
+    let query = sql::Select::new().for_update().for_share().as_string();
+    let expected_query = "FOR SHARE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_lock_of_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Select::new()
+      .for_update()
+      .lock_of("orders")
+      .lock_of("users")
+      .as_string();
+    let expected_query = "FOR UPDATE OF orders, users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_lock_of_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Select::new()
+      .for_update()
+      .lock_of("orders")
+      .lock_of("orders")
+      .as_string();
+    let expected_query = "FOR UPDATE OF orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_lock_of_should_trim_space_of_the_argument() {
+    let query = sql::Select::new().for_update().lock_of("  orders  ").as_string();
+    let expected_query = "FOR UPDATE OF orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_nowait_should_add_the_nowait_option() {
+    let query = sql::Select::new().for_update().nowait().as_string();
+    let expected_query = "FOR UPDATE NOWAIT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_skip_locked_should_add_the_skip_locked_option() {
+    let query = sql::Select::new().for_update().skip_locked().as_string();
+    let expected_query = "FOR UPDATE SKIP LOCKED";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_skip_locked_should_override_the_nowait_option() {
+    let query = sql::Select::new().for_update().nowait().skip_locked().as_string();
+    let expected_query = "FOR UPDATE SKIP LOCKED";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_nowait_should_override_the_skip_locked_option() {
+    let query = sql::Select::new().for_update().skip_locked().nowait().as_string();
+    let expected_query = "FOR UPDATE NOWAIT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn lock_options_should_not_be_rendered_without_a_lock_strength() {
+    let query = sql::Select::new().lock_of("orders").skip_locked().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_lock_options_without_a_lock_strength() {
+    let lock_of = sql::Select::new().select("*").from("jobs").lock_of("jobs");
+    let nowait = sql::Select::new().select("*").from("jobs").nowait();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "LOCK OPTION WITHOUT STRENGTH",
+      dialect: sql::Dialect::Postgres,
+    });
+
+    assert_eq!(lock_of.as_string_for(sql::Dialect::Postgres), expected_error);
+    assert_eq!(nowait.as_string_for(sql::Dialect::Postgres), expected_error);
+    assert!(nowait.for_update().as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+
+  #[test]
+  fn lock_options_should_be_rendered_in_the_sql_order() {
+    let query = sql::Select::new()
+      .skip_locked()
+      .lock_of("jobs")
+      .for_update()
+      .as_string();
+    let expected_query = "FOR UPDATE OF jobs SKIP LOCKED";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_lock_should_be_after_offset_clause() {
+    let query = sql::Select::new()
+      .for_update()
+      .select("id")
+      .from("jobs")
+      .where_clause("status = 'pending'")
+      .order_by("created_at")
+      .limit("10")
+      .offset("20")
+      .as_string();
+    let expected_query = "\
+      SELECT id \
+      FROM jobs \
+      WHERE status = 'pending' \
+      ORDER BY created_at \
+      LIMIT 10 \
+      OFFSET 20 \
+      FOR UPDATE\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_lock_clause_outside_postgres_and_mysql() {
+    let select = sql::Select::new().select("*").from("jobs").for_update();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "FOR UPDATE",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert!(select.as_string_for(sql::Dialect::MySql).is_ok());
+    assert_eq!(select.as_string_for(sql::Dialect::Sqlite), expected_error);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_for_no_key_update_clause_outside_postgres() {
+    let select = sql::Select::new().select("*").from("jobs").for_no_key_update();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "FOR NO KEY UPDATE",
+      dialect: sql::Dialect::MySql,
+    });
+
+    assert!(select.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert_eq!(select.as_string_for(sql::Dialect::MySql), expected_error);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_lock_clause() {
+    let query = sql::Select::new()
+      .raw_before(sql::SelectClause::Lock, "limit 1")
+      .for_update()
+      .as_string();
+    let expected_query = "limit 1 FOR UPDATE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_lock_clause() {
+    let query = sql::Select::new()
+      .for_update()
+      .raw_after(sql::SelectClause::Lock, "/* the job */")
+      .as_string();
+    let expected_query = "FOR UPDATE /* the job */";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
 mod offset_clause {
   use super::*;
   use pretty_assertions::assert_eq;
 
+  #[test]
+  fn method_as_string_for_should_render_the_rows_keyword_of_the_offset_clause_in_mssql() {
+    let select = sql::Select::new().select("*").from("users").order_by("id").offset("10");
+
+    assert_eq!(
+      select.as_string_for(sql::Dialect::MsSql),
+      Ok("SELECT * FROM users ORDER BY id OFFSET 10 ROWS".to_owned())
+    );
+    assert_eq!(
+      select.as_string_for(sql::Dialect::Postgres),
+      Ok("SELECT * FROM users ORDER BY id OFFSET 10".to_owned())
+    );
+  }
+
   #[test]
   fn method_offset_should_add_the_offset_clause() {
     let query = sql::Select::new().offset("100").as_string();
@@ -728,3 +1001,399 @@ mod where_clause {
     assert_eq!(query, expected_query);
   }
 }
+
+mod where_bind_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_where_bind_should_add_the_where_clause_and_the_bound_value() {
+    let (query, params) = sql::Select::new().where_bind("login = $1", "foo").as_query();
+    let expected_query = "WHERE login = $1";
+    let expected_params = vec![sql::Value::from("foo")];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+
+  #[test]
+  fn method_where_bind_should_renumber_the_placeholder_after_the_values_already_bound() {
+    let (query, params) = sql::Select::new()
+      .where_bind("login = $1", "foo")
+      .where_bind("age > $1", 18)
+      .and_bind("active = $1", true)
+      .as_query();
+    let expected_query = "WHERE login = $1 AND age > $2 AND active = $3";
+    let expected_params = vec![sql::Value::from("foo"), sql::Value::from(18), sql::Value::from(true)];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+
+  #[test]
+  fn method_where_bind_should_not_renumber_placeholders_inside_quoted_literals_and_comments() {
+    let query = sql::Select::new()
+      .where_bind("id = $1", 1)
+      .where_bind("note <> '$1' /* $1 */ and login = $1", "foo")
+      .as_string();
+    let expected_query = "WHERE id = $1 AND note <> '$1' /* $1 */ and login = $2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_where_bind_should_not_renumber_placeholders_inside_quoted_identifiers() {
+    let query = sql::Select::new()
+      .select("`$1 total`, [$1 amount]], ?]")
+      .where_bind("id = $1", 1)
+      .where_bind("login = $1", "foo")
+      .as_string();
+    let expected_query = "SELECT `$1 total`, [$1 amount]], ?] WHERE id = $1 AND login = $2";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_query_for_should_keep_the_placeholders_inside_quoted_identifiers() {
+    let (query, params) = sql::Select::new()
+      .select(&sql::quote_ident("$1 total", sql::Dialect::MySql))
+      .from("orders")
+      .where_bind("id = $1", 1)
+      .as_query_for(sql::Dialect::MySql)
+      .unwrap();
+    let expected_query = "SELECT `$1 total` FROM orders WHERE id = ?";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from(1)]);
+  }
+
+  #[test]
+  fn method_where_bind_should_trim_space_of_the_argument() {
+    let query = sql::Select::new().where_bind("  id = $1  ", 1).as_string();
+    let expected_query = "WHERE id = $1";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_having_bind_should_share_the_placeholder_numbering_with_the_where_clause() {
+    let (query, params) = sql::Select::new()
+      .having_bind("count(*) > $1", 10)
+      .where_bind("status = $1", "paid")
+      .as_query();
+    let expected_query = "WHERE status = $2 HAVING count(*) > $1";
+    let expected_params = vec![sql::Value::from(10), sql::Value::from("paid")];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+
+  #[test]
+  fn method_as_query_should_return_an_empty_list_when_there_is_no_bound_values() {
+    let (query, params) = sql::Select::new().select("*").where_clause("id = 1").as_query();
+    let expected_query = "SELECT * WHERE id = 1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![]);
+  }
+}
+
+mod placeholder_style {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_as_string_with_should_render_the_placeholders_in_the_placeholder_style() {
+    let select = sql::Select::new().where_clause("login = $1").and("id = $2");
+
+    assert_eq!(
+      select.as_string_with(sql::Placeholder::Dollar),
+      "WHERE login = $1 AND id = $2"
+    );
+    assert_eq!(
+      select.as_string_with(sql::Placeholder::QuestionMark),
+      "WHERE login = ? AND id = ?"
+    );
+    assert_eq!(
+      select.as_string_with(sql::Placeholder::Colon),
+      "WHERE login = :p1 AND id = :p2"
+    );
+    assert_eq!(
+      select.as_string_with(sql::Placeholder::At),
+      "WHERE login = @p1 AND id = @p2"
+    );
+  }
+
+  #[test]
+  fn method_as_string_with_should_not_change_quoted_literals_and_comments() {
+    let query = sql::Select::new()
+      .select("'$1' as a, \"$2\" as b, $$ $3 $$ as c")
+      .where_clause("id = $1 -- the $2 id")
+      .raw_after(sql::SelectClause::Where, "/* $3 */")
+      .as_string_with(sql::Placeholder::At);
+    let expected_query = "SELECT '$1' as a, \"$2\" as b, $$ $3 $$ as c WHERE id = @p1 -- the $2 id /* $3 */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_query_with_should_reorder_the_values_to_follow_the_question_mark_placeholders() {
+    let (query, params) = sql::Select::new()
+      .having_bind("count(*) > $1", 10)
+      .where_bind("status = $1", "paid")
+      .and_bind("owner_login = $1", "foo")
+      .as_query_with(sql::Placeholder::QuestionMark)
+      .unwrap();
+    let expected_query = "WHERE status = ? AND owner_login = ? HAVING count(*) > ?";
+    let expected_params = vec![sql::Value::from("paid"), sql::Value::from("foo"), sql::Value::from(10)];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+
+  #[test]
+  fn method_as_query_with_should_return_an_error_for_a_placeholder_without_bound_value() {
+    let select = sql::Select::new()
+      .select("*")
+      .from("orders")
+      .where_clause("owner_login = $2 or approver_login = $2")
+      .and_bind("status = $1", "paid");
+    let expected_error = Err(sql::Error::UnboundPlaceholder { position: 2 });
+
+    assert_eq!(select.as_query_with(sql::Placeholder::QuestionMark), expected_error);
+    assert_eq!(select.as_query_with(sql::Placeholder::At), expected_error);
+  }
+
+  #[test]
+  fn method_as_query_with_should_keep_the_values_order_in_the_numbered_placeholder_styles() {
+    let (query, params) = sql::Select::new()
+      .having_bind("count(*) > $1", 10)
+      .where_bind("status = $1", "paid")
+      .as_query_with(sql::Placeholder::At)
+      .unwrap();
+    let expected_query = "WHERE status = @p2 HAVING count(*) > @p1";
+    let expected_params = vec![sql::Value::from(10), sql::Value::from("paid")];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+}
+
+mod named_placeholders {
+  use super::*;
+  use pretty_assertions::assert_eq;
+  use std::collections::HashMap;
+
+  #[test]
+  fn method_as_query_named_should_replace_the_names_by_positional_placeholders_in_order() {
+    let named = HashMap::from([("login", sql::Value::from("foo")), ("tenant", sql::Value::from(42))]);
+    let (query, params) = sql::Select::new()
+      .where_clause("tenant_id = :tenant")
+      .and("login = :login")
+      .as_query_named(&named, sql::Placeholder::Dollar)
+      .unwrap();
+    let expected_query = "WHERE tenant_id = $1 AND login = $2";
+    let expected_params = vec![sql::Value::from(42), sql::Value::from("foo")];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+
+  #[test]
+  fn method_as_query_named_should_reuse_the_position_of_a_repeated_name() {
+    let named = HashMap::from([("login", sql::Value::from("foo"))]);
+    let (query, params) = sql::Select::new()
+      .where_clause("owner_login = :login")
+      .and("approver_login <> :login")
+      .as_query_named(&named, sql::Placeholder::Dollar)
+      .unwrap();
+    let expected_query = "WHERE owner_login = $1 AND approver_login <> $1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from("foo")]);
+  }
+
+  #[test]
+  fn method_as_query_named_should_number_the_names_after_the_bound_values() {
+    let named = HashMap::from([("tenant", sql::Value::from(42))]);
+    let (query, params) = sql::Select::new()
+      .where_clause("tenant_id = :tenant")
+      .where_bind("login = $1", "foo")
+      .as_query_named(&named, sql::Placeholder::Dollar)
+      .unwrap();
+    let expected_query = "WHERE tenant_id = $2 AND login = $1";
+    let expected_params = vec![sql::Value::from("foo"), sql::Value::from(42)];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+
+  #[test]
+  fn method_as_query_named_should_not_change_casts_and_literals() {
+    let named = HashMap::from([("login", sql::Value::from("foo"))]);
+    let (query, params) = sql::Select::new()
+      .select("created_at::date, ':login' as label")
+      .where_clause("login = :login")
+      .as_query_named(&named, sql::Placeholder::Dollar)
+      .unwrap();
+    let expected_query = "SELECT created_at::date, ':login' as label WHERE login = $1";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from("foo")]);
+  }
+
+  #[test]
+  fn method_as_query_named_should_not_change_the_names_inside_quoted_identifiers() {
+    let named = HashMap::from([("login", sql::Value::from("foo"))]);
+    let (query, params) = sql::Select::new()
+      .select("`:tenant`, [:tenant], \":tenant\"")
+      .where_clause("login = :login")
+      .as_query_named(&named, sql::Placeholder::QuestionMark)
+      .unwrap();
+    let expected_query = "SELECT `:tenant`, [:tenant], \":tenant\" WHERE login = ?";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from("foo")]);
+  }
+
+  #[test]
+  fn method_as_query_named_should_replace_the_names_inside_array_subscripts() {
+    let named = HashMap::from([("index", sql::Value::from(1))]);
+    let (query, _) = sql::Select::new()
+      .select("tags[:index]")
+      .as_query_named(&named, sql::Placeholder::Dollar)
+      .unwrap();
+
+    assert_eq!(query, "SELECT tags[$1]");
+  }
+
+  #[test]
+  fn method_as_query_named_should_return_an_error_for_a_name_not_found_in_the_map() {
+    let named = HashMap::from([("login", sql::Value::from("foo"))]);
+    let result = sql::Select::new()
+      .where_clause("login = :login")
+      .and("tenant_id = :tenant")
+      .as_query_named(&named, sql::Placeholder::Dollar);
+    let expected_error = Err(sql::Error::UnknownPlaceholder {
+      name: "tenant".to_owned(),
+    });
+
+    assert_eq!(result, expected_error);
+  }
+
+  #[test]
+  fn method_as_query_named_should_return_an_error_for_a_positional_placeholder_without_bound_value() {
+    let named = HashMap::from([("login", sql::Value::from("foo"))]);
+    let result = sql::Select::new()
+      .where_clause("id = $1")
+      .and("login = :login")
+      .as_query_named(&named, sql::Placeholder::Dollar);
+
+    assert_eq!(result, Err(sql::Error::UnboundPlaceholder { position: 1 }));
+  }
+
+  #[test]
+  fn method_as_query_named_should_repeat_the_values_of_a_repeated_name_using_question_mark_placeholders() {
+    let named = HashMap::from([("login", sql::Value::from("foo")), ("tenant", sql::Value::from(42))]);
+    let (query, params) = sql::Select::new()
+      .where_clause("owner_login = :login")
+      .and("tenant_id = :tenant")
+      .and("approver_login <> :login")
+      .as_query_named(&named, sql::Placeholder::QuestionMark)
+      .unwrap();
+    let expected_query = "WHERE owner_login = ? AND tenant_id = ? AND approver_login <> ?";
+    let expected_params = vec![sql::Value::from("foo"), sql::Value::from(42), sql::Value::from("foo")];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+}
+
+mod window_clause {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_window_should_add_the_window_clause() {
+    let query = sql::Select::new()
+      .window("w", sql::Over::new().partition_by("department_id"))
+      .as_string();
+    let expected_query = "WINDOW w AS (PARTITION BY department_id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_window_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Select::new()
+      .window("w1", sql::Over::new().partition_by("department_id"))
+      .window("w2", sql::Over::new().order_by("salary desc"))
+      .as_string();
+    let expected_query = "WINDOW w1 AS (PARTITION BY department_id), w2 AS (ORDER BY salary desc)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_window_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Select::new()
+      .window("w", sql::Over::new().order_by("id"))
+      .window("w", sql::Over::new().order_by("id"))
+      .as_string();
+    let expected_query = "WINDOW w AS (ORDER BY id)";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_window_should_trim_space_of_the_name_argument() {
+    let query = sql::Select::new().window("  w  ", sql::Over::new()).as_string();
+    let expected_query = "WINDOW w AS ()";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn clause_window_should_be_after_having_clause_and_before_order_by_clause() {
+    let query = sql::Select::new()
+      .order_by("department_id")
+      .window("w", sql::Over::new().partition_by("department_id"))
+      .select("department_id, count(*) OVER w")
+      .from("employees")
+      .group_by("department_id")
+      .having("count(*) > 1")
+      .as_string();
+    let expected_query = "\
+      SELECT department_id, count(*) OVER w \
+      FROM employees \
+      GROUP BY department_id \
+      HAVING count(*) > 1 \
+      WINDOW w AS (PARTITION BY department_id) \
+      ORDER BY department_id\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_window_clause() {
+    let query = sql::Select::new()
+      .raw_before(sql::SelectClause::Window, "having count(*) > 1")
+      .window("w", sql::Over::new())
+      .as_string();
+    let expected_query = "having count(*) > 1 WINDOW w AS ()";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_window_clause() {
+    let query = sql::Select::new()
+      .window("w", sql::Over::new())
+      .raw_after(sql::SelectClause::Window, "order by id")
+      .as_string();
+    let expected_query = "WINDOW w AS () order by id";
+
+    assert_eq!(query, expected_query);
+  }
+}
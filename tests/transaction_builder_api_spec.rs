@@ -0,0 +1,432 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::Transaction::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::Transaction::new()
+      .statement(sql::Delete::new().delete_from("sessions"))
+      .debug()
+      .as_string();
+    let expected_query = "BEGIN; DELETE FROM sessions; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::Transaction::new()
+      .statement(sql::Delete::new().delete_from("sessions"))
+      .print()
+      .as_string();
+    let expected_query = "BEGIN; DELETE FROM sessions; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::Transaction::new()
+      .raw("begin; delete from sessions; commit;")
+      .as_string();
+    let expected_query = "begin; delete from sessions; commit;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Transaction::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::Transaction::new()
+      .statement(sql::Delete::new().delete_from("sessions"))
+      .raw("/* the cleanup script */")
+      .as_string();
+    let expected_query = "/* the cleanup script */ BEGIN; DELETE FROM sessions; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::Transaction::new()
+      .raw_after(sql::TransactionClause::Begin, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::Transaction::new()
+      .raw_before(sql::TransactionClause::Begin, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_transaction_in_mssql() {
+    let transaction = sql::Transaction::new().statement(sql::Delete::new().delete_from("sessions"));
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "BEGIN",
+      dialect: sql::Dialect::MsSql,
+    });
+
+    assert_eq!(transaction.as_string_for(sql::Dialect::MsSql), expected_error);
+    assert!(transaction.as_string_for(sql::Dialect::MySql).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_isolation_level_outside_postgres() {
+    let transaction = sql::Transaction::new()
+      .isolation_level(sql::IsolationLevel::ReadCommitted)
+      .statement(sql::Delete::new().delete_from("sessions"));
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "SET TRANSACTION",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(transaction.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(transaction.as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_validate_the_statements_of_the_transaction() {
+    let transaction = sql::Transaction::new().statement(
+      sql::CreateIndex::new()
+        .create_index("users_login_idx")
+        .on("users")
+        .using("btree")
+        .column("login"),
+    );
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "USING",
+      dialect: sql::Dialect::MySql,
+    });
+
+    assert_eq!(transaction.as_string_for(sql::Dialect::MySql), expected_error);
+  }
+}
+
+mod statement_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+  use std::collections::HashMap;
+
+  #[test]
+  fn method_statement_should_wrap_the_statement_in_begin_and_commit() {
+    let query = sql::Transaction::new()
+      .statement(sql::Select::new().select("1"))
+      .as_string();
+    let expected_query = "BEGIN; SELECT 1; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_statement_should_render_the_statements_in_the_call_order() {
+    let query = sql::Transaction::new()
+      .statement(sql::Insert::new().insert_into("users (login)").values("('foo')"))
+      .statement(sql::Update::new().update("users").set("name = 'Foo'"))
+      .statement(sql::Delete::new().delete_from("sessions"))
+      .statement(sql::Select::new().select("*").from("users"))
+      .as_string();
+    let expected_query = "\
+      BEGIN; \
+      INSERT INTO users (login) VALUES ('foo'); \
+      UPDATE users SET name = 'Foo'; \
+      DELETE FROM sessions; \
+      SELECT * FROM users; \
+      COMMIT;\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_statement_should_accept_the_values_and_the_ddl_builders() {
+    let query = sql::Transaction::new()
+      .statement(sql::CreateTable::new().create_table("users").column("login text"))
+      .statement(sql::Values::new().values("(1)"))
+      .statement(sql::Truncate::new().truncate("users"))
+      .statement(sql::DropTable::new().drop_table("users"))
+      .as_string();
+    let expected_query = "\
+      BEGIN; \
+      CREATE TABLE users (login text); \
+      VALUES (1); \
+      TRUNCATE TABLE users; \
+      DROP TABLE users; \
+      COMMIT;\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_statement_should_renumber_the_placeholders_after_the_previous_statements() {
+    let query = sql::Transaction::new()
+      .statement(
+        sql::Delete::new()
+          .delete_from("sessions")
+          .where_bind("login = $1", "foo"),
+      )
+      .statement(sql::Delete::new().delete_from("users").where_bind("login = $1", "foo"))
+      .as_string();
+    let expected_query = "BEGIN; DELETE FROM sessions WHERE login = $1; DELETE FROM users WHERE login = $2; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_query_should_return_the_bound_values_of_the_statement() {
+    let (query, params) = sql::Transaction::new()
+      .statement(sql::Insert::new().insert_into("audit (event)").values("('deactivate')"))
+      .savepoint("after_insert")
+      .statement(
+        sql::Update::new()
+          .update("users")
+          .set_bind("active = $1", false)
+          .where_bind("login = $1", "foo"),
+      )
+      .as_query()
+      .unwrap();
+    let expected_query = "\
+      BEGIN; \
+      INSERT INTO audit (event) VALUES ('deactivate'); \
+      SAVEPOINT after_insert; \
+      UPDATE users SET active = $1 WHERE login = $2; \
+      COMMIT;\
+    ";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from(false), sql::Value::from("foo")]);
+  }
+
+  #[test]
+  fn method_as_query_should_return_an_error_for_the_placeholders_of_more_than_one_statement() {
+    let bound = sql::Transaction::new()
+      .statement(
+        sql::Insert::new()
+          .insert_into("users (login)")
+          .values_bind("($1)", [sql::Value::from("foo")]),
+      )
+      .statement(
+        sql::Delete::new()
+          .delete_from("sessions")
+          .where_bind("login = $1", "foo"),
+      );
+    let named = sql::Transaction::new()
+      .statement(
+        sql::Delete::new()
+          .delete_from("sessions")
+          .where_clause("login = :login"),
+      )
+      .statement(sql::Delete::new().delete_from("users").where_clause("login = :login"));
+    let values = HashMap::from([("login", sql::Value::from("foo"))]);
+    let expected_error = Err(sql::Error::MultipleStatementsWithParams);
+
+    assert_eq!(bound.as_query(), expected_error);
+    assert_eq!(bound.as_query_with(sql::Placeholder::QuestionMark), expected_error);
+    assert_eq!(bound.as_query_for(sql::Dialect::Postgres), expected_error);
+    assert_eq!(named.as_query_named(&values, sql::Placeholder::Dollar), expected_error);
+  }
+
+  #[test]
+  fn method_as_query_for_should_render_the_placeholders_of_the_dialect() {
+    let (query, params) = sql::Transaction::new()
+      .statement(
+        sql::Delete::new()
+          .delete_from("sessions")
+          .where_clause("expired = true"),
+      )
+      .statement(sql::Delete::new().delete_from("users").where_bind("id = $1", 42))
+      .as_query_for(sql::Dialect::Sqlite)
+      .unwrap();
+    let expected_query = "BEGIN; DELETE FROM sessions WHERE expired = true; DELETE FROM users WHERE id = ?; COMMIT;";
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, vec![sql::Value::from(42)]);
+  }
+
+  #[test]
+  fn method_as_string_with_should_render_the_placeholders_in_the_placeholder_style() {
+    let query = sql::Transaction::new()
+      .statement(sql::Delete::new().delete_from("users").where_clause("id = $1"))
+      .as_string_with(sql::Placeholder::At);
+    let expected_query = "BEGIN; DELETE FROM users WHERE id = @p1; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_begin_clause() {
+    let query = sql::Transaction::new()
+      .statement(sql::Delete::new().delete_from("users"))
+      .raw_after(sql::TransactionClause::Begin, "lock table users in exclusive mode;")
+      .as_string();
+    let expected_query = "BEGIN; lock table users in exclusive mode; DELETE FROM users; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_begin_clause() {
+    let query = sql::Transaction::new()
+      .raw_before(sql::TransactionClause::Begin, "/* cleanup */")
+      .statement(sql::Delete::new().delete_from("users"))
+      .as_string();
+    let expected_query = "/* cleanup */ BEGIN; DELETE FROM users; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_commit_clause() {
+    let query = sql::Transaction::new()
+      .statement(sql::Delete::new().delete_from("users"))
+      .raw_before(sql::TransactionClause::Commit, "analyze users;")
+      .as_string();
+    let expected_query = "BEGIN; DELETE FROM users; analyze users; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_commit_clause() {
+    let query = sql::Transaction::new()
+      .statement(sql::Delete::new().delete_from("users"))
+      .raw_after(sql::TransactionClause::Commit, "vacuum users;")
+      .as_string();
+    let expected_query = "BEGIN; DELETE FROM users; COMMIT; vacuum users;";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod savepoint_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_savepoint_should_add_the_savepoint_statement() {
+    let query = sql::Transaction::new().savepoint("before_cleanup").as_string();
+    let expected_query = "BEGIN; SAVEPOINT before_cleanup; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_savepoint_should_trim_space_of_the_argument() {
+    let query = sql::Transaction::new().savepoint("  before_cleanup  ").as_string();
+    let expected_query = "BEGIN; SAVEPOINT before_cleanup; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_rollback_to_should_add_the_rollback_to_savepoint_statement() {
+    let query = sql::Transaction::new().rollback_to("before_cleanup").as_string();
+    let expected_query = "BEGIN; ROLLBACK TO SAVEPOINT before_cleanup; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn methods_savepoint_and_rollback_to_should_be_rendered_in_the_call_order_with_the_statements() {
+    let query = sql::Transaction::new()
+      .statement(sql::Insert::new().insert_into("users (login)").values("('foo')"))
+      .savepoint("before_orders")
+      .statement(sql::Delete::new().delete_from("orders"))
+      .rollback_to("before_orders")
+      .savepoint("before_orders")
+      .as_string();
+    let expected_query = "\
+      BEGIN; \
+      INSERT INTO users (login) VALUES ('foo'); \
+      SAVEPOINT before_orders; \
+      DELETE FROM orders; \
+      ROLLBACK TO SAVEPOINT before_orders; \
+      SAVEPOINT before_orders; \
+      COMMIT;\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+}
+
+mod isolation_level_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_isolation_level_should_add_the_set_transaction_statement_after_begin() {
+    let query = sql::Transaction::new()
+      .statement(sql::Delete::new().delete_from("sessions"))
+      .isolation_level(sql::IsolationLevel::RepeatableRead)
+      .as_string();
+    let expected_query = "BEGIN; SET TRANSACTION ISOLATION LEVEL REPEATABLE READ; DELETE FROM sessions; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_isolation_level_should_override_value_on_consecutive_calls() {
+    let query = sql::Transaction::new()
+      .isolation_level(sql::IsolationLevel::ReadUncommitted)
+      .isolation_level(sql::IsolationLevel::ReadCommitted)
+      .statement(sql::Delete::new().delete_from("sessions"))
+      .as_string();
+    let expected_query = "BEGIN; SET TRANSACTION ISOLATION LEVEL READ COMMITTED; DELETE FROM sessions; COMMIT;";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_isolation_level_should_not_be_rendered_without_statements() {
+    let query = sql::Transaction::new()
+      .isolation_level(sql::IsolationLevel::Serializable)
+      .as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_set_transaction_clause() {
+    let query = sql::Transaction::new()
+      .isolation_level(sql::IsolationLevel::Serializable)
+      .raw_after(sql::TransactionClause::SetTransaction, "set transaction read only;")
+      .statement(sql::Select::new().select("*").from("users"))
+      .as_string();
+    let expected_query = "\
+      BEGIN; \
+      SET TRANSACTION ISOLATION LEVEL SERIALIZABLE; \
+      set transaction read only; \
+      SELECT * FROM users; \
+      COMMIT;\
+    ";
+
+    assert_eq!(query, expected_query);
+  }
+}
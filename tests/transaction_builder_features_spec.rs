@@ -0,0 +1,112 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn transaction_builder_should_be_displayable() {
+  let transaction = sql::Transaction::new().statement(sql::Delete::new().delete_from("sessions"));
+
+  println!("{}", transaction);
+
+  let query = transaction.as_string();
+  let expected_query = "BEGIN; DELETE FROM sessions; COMMIT;";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn transaction_builder_should_be_debuggable() {
+  let transaction = sql::Transaction::new()
+    .statement(sql::Insert::new().insert_into("users (login)").values("('foo')"))
+    .statement(sql::Delete::new().delete_from("sessions"));
+
+  println!("{:?}", transaction);
+
+  let expected_query = "BEGIN; INSERT INTO users (login) VALUES ('foo'); DELETE FROM sessions; COMMIT;";
+  let query = transaction.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn transaction_builder_should_be_cloneable() {
+  let signup = sql::Transaction::new()
+    .raw("/* test raw */")
+    .raw_before(sql::TransactionClause::Begin, "/* test raw_before */")
+    .raw_after(sql::TransactionClause::Commit, "/* test raw_after */")
+    .statement(sql::Insert::new().insert_into("users (login)").values("('foo')"));
+
+  let signup_with_profile = signup
+    .clone()
+    .statement(sql::Insert::new().insert_into("profiles (login)").values("('foo')"));
+
+  let query_signup = signup.as_string();
+  let query_signup_with_profile = signup_with_profile.as_string();
+
+  let expected_query_signup = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    BEGIN; \
+    INSERT INTO users (login) VALUES ('foo'); \
+    COMMIT; \
+    /* test raw_after */\
+  ";
+  let expected_query_signup_with_profile = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    BEGIN; \
+    INSERT INTO users (login) VALUES ('foo'); \
+    INSERT INTO profiles (login) VALUES ('foo'); \
+    COMMIT; \
+    /* test raw_after */\
+  ";
+
+  assert_eq!(query_signup, expected_query_signup);
+  assert_eq!(query_signup_with_profile, expected_query_signup_with_profile);
+}
+
+#[test]
+fn transaction_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut transaction = sql::Transaction::new().statement(sql::Delete::new().delete_from("sessions"));
+
+  if true {
+    transaction = transaction.statement(sql::Delete::new().delete_from("tokens"));
+  }
+
+  let query = transaction.as_string();
+  let expected_query = "BEGIN; DELETE FROM sessions; DELETE FROM tokens; COMMIT;";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn transaction_builder_should_be_composable() {
+  fn teardown(transaction: sql::Transaction, table_names: &[&'static str]) -> sql::Transaction {
+    table_names.iter().fold(transaction, |transaction, table_name| {
+      transaction.statement(sql::Delete::new().delete_from(*table_name))
+    })
+  }
+
+  fn fixtures(transaction: sql::Transaction) -> sql::Transaction {
+    transaction.statement(sql::Insert::new().insert_into("users (login)").values("('foo')"))
+  }
+
+  fn as_string(transaction: sql::Transaction) -> String {
+    transaction.as_string()
+  }
+
+  let query = Some(sql::Transaction::new())
+    .map(|transaction| teardown(transaction, &["orders", "users"]))
+    .map(fixtures)
+    .map(as_string)
+    .unwrap();
+
+  let expected_query = "\
+    BEGIN; \
+    DELETE FROM orders; \
+    DELETE FROM users; \
+    INSERT INTO users (login) VALUES ('foo'); \
+    COMMIT;\
+  ";
+
+  assert_eq!(query, expected_query);
+}
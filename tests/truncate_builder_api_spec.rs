@@ -0,0 +1,204 @@
+mod builder_methods {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_new_should_initialize_as_empty_string() {
+    let query = sql::Truncate::new().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_debug_should_print_at_console_in_a_human_readable_format() {
+    let query = sql::Truncate::new().truncate("users").debug().as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_print_should_print_in_one_line_the_current_state_of_builder() {
+    let query = sql::Truncate::new().truncate("users").print().as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_add_raw_sql() {
+    let query = sql::Truncate::new().raw("truncate users").as_string();
+    let expected_query = "truncate users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Truncate::new()
+      .raw("/* raw one */")
+      .raw("/* raw two */")
+      .as_string();
+    let expected_query = "/* raw one */ /* raw two */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_should_be_the_first_to_be_concatenated() {
+    let query = sql::Truncate::new().truncate("users").raw("/* teardown */").as_string();
+    let expected_query = "/* teardown */ TRUNCATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_trim_space_of_the_argument() {
+    let query = sql::Truncate::new()
+      .raw_after(sql::TruncateClause::Truncate, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_trim_space_of_the_argument() {
+    let query = sql::Truncate::new()
+      .raw_before(sql::TruncateClause::Truncate, "  /* raw one */  ")
+      .as_string();
+    let expected_query = "/* raw one */";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_truncate_clause_in_sqlite() {
+    let truncate = sql::Truncate::new().truncate("users");
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "TRUNCATE",
+      dialect: sql::Dialect::Sqlite,
+    });
+
+    assert_eq!(truncate.as_string_for(sql::Dialect::Sqlite), expected_error);
+    assert!(truncate.as_string_for(sql::Dialect::MsSql).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_the_cascade_option_outside_postgres() {
+    let truncate = sql::Truncate::new().truncate("users").cascade();
+    let expected_error = Err(sql::Error::UnsupportedClause {
+      clause: "CASCADE",
+      dialect: sql::Dialect::MySql,
+    });
+
+    assert_eq!(truncate.as_string_for(sql::Dialect::MySql), expected_error);
+    assert!(truncate.as_string_for(sql::Dialect::Postgres).is_ok());
+  }
+
+  #[test]
+  fn method_as_string_for_should_return_an_error_for_multiple_tables_outside_postgres() {
+    let truncate = sql::Truncate::new().truncate("users").truncate("orders");
+
+    assert!(truncate.as_string_for(sql::Dialect::Postgres).is_ok());
+    assert_eq!(
+      truncate.as_string_for(sql::Dialect::MySql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "MULTIPLE TABLES",
+        dialect: sql::Dialect::MySql,
+      })
+    );
+    assert_eq!(
+      truncate.as_string_for(sql::Dialect::MsSql),
+      Err(sql::Error::UnsupportedClause {
+        clause: "MULTIPLE TABLES",
+        dialect: sql::Dialect::MsSql,
+      })
+    );
+  }
+}
+
+mod truncate_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_truncate_should_add_the_truncate_clause() {
+    let query = sql::Truncate::new().truncate("users").as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_truncate_should_accumulate_values_on_consecutive_calls() {
+    let query = sql::Truncate::new().truncate("users").truncate("orders").as_string();
+    let expected_query = "TRUNCATE TABLE users, orders";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_truncate_should_not_accumulate_arguments_with_the_same_content() {
+    let query = sql::Truncate::new().truncate("users").truncate("users").as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_truncate_should_trim_space_of_the_argument() {
+    let query = sql::Truncate::new().truncate("  users  ").as_string();
+    let expected_query = "TRUNCATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_override_the_restrict_option() {
+    let query = sql::Truncate::new().truncate("users").restrict().cascade().as_string();
+    let expected_query = "TRUNCATE TABLE users CASCADE";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_restrict_should_override_the_cascade_option() {
+    let query = sql::Truncate::new().truncate("users").cascade().restrict().as_string();
+    let expected_query = "TRUNCATE TABLE users RESTRICT";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_cascade_should_not_be_rendered_without_the_truncate_clause() {
+    let query = sql::Truncate::new().cascade().as_string();
+    let expected_query = "";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_before_should_add_raw_sql_before_truncate_clause() {
+    let query = sql::Truncate::new()
+      .raw_before(sql::TruncateClause::Truncate, "/* teardown */")
+      .truncate("users")
+      .as_string();
+    let expected_query = "/* teardown */ TRUNCATE TABLE users";
+
+    assert_eq!(query, expected_query);
+  }
+
+  #[test]
+  fn method_raw_after_should_add_raw_sql_after_truncate_clause() {
+    let query = sql::Truncate::new()
+      .truncate("users")
+      .cascade()
+      .raw_after(sql::TruncateClause::Truncate, "/* teardown */")
+      .as_string();
+    let expected_query = "TRUNCATE TABLE users CASCADE /* teardown */";
+
+    assert_eq!(query, expected_query);
+  }
+}
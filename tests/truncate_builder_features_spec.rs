@@ -0,0 +1,84 @@
+use pretty_assertions::assert_eq;
+use sql_query_builder as sql;
+
+#[test]
+fn truncate_builder_should_be_displayable() {
+  let truncate = sql::Truncate::new().truncate("users");
+
+  println!("{}", truncate);
+
+  let query = truncate.as_string();
+  let expected_query = "TRUNCATE TABLE users";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn truncate_builder_should_be_debuggable() {
+  let truncate = sql::Truncate::new().truncate("users").cascade();
+
+  println!("{:?}", truncate);
+
+  let expected_query = "TRUNCATE TABLE users CASCADE";
+  let query = truncate.as_string();
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn truncate_builder_should_be_cloneable() {
+  let truncate_users = sql::Truncate::new()
+    .raw("/* test raw */")
+    .raw_before(sql::TruncateClause::Truncate, "/* test raw_before */")
+    .truncate("users")
+    .raw_after(sql::TruncateClause::Truncate, "/* test raw_after */");
+
+  let truncate_all = truncate_users.clone().truncate("orders");
+
+  let query_users = truncate_users.as_string();
+  let query_all = truncate_all.as_string();
+
+  let expected_query_users = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    TRUNCATE TABLE users \
+    /* test raw_after */\
+  ";
+  let expected_query_all = "\
+    /* test raw */ \
+    /* test raw_before */ \
+    TRUNCATE TABLE users, orders \
+    /* test raw_after */\
+  ";
+
+  assert_eq!(query_users, expected_query_users);
+  assert_eq!(query_all, expected_query_all);
+}
+
+#[test]
+fn truncate_builder_should_be_able_to_conditionally_add_clauses() {
+  let mut truncate = sql::Truncate::new().truncate("users");
+
+  if true {
+    truncate = truncate.cascade();
+  }
+
+  let query = truncate.as_string();
+  let expected_query = "TRUNCATE TABLE users CASCADE";
+
+  assert_eq!(query, expected_query);
+}
+
+#[test]
+fn truncate_builder_should_be_composable() {
+  fn teardown(table_names: &[&str]) -> sql::Truncate {
+    table_names.iter().fold(sql::Truncate::new(), |truncate, table_name| {
+      truncate.truncate(table_name)
+    })
+  }
+
+  let query = teardown(&["users", "orders"]).cascade().as_string();
+  let expected_query = "TRUNCATE TABLE users, orders CASCADE";
+
+  assert_eq!(query, expected_query);
+}
@@ -309,3 +309,53 @@ mod where_clause {
     assert_eq!(query, expected_query);
   }
 }
+
+mod bind_values {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_set_bind_should_add_the_set_clause_and_the_bound_value() {
+    let (query, params) = sql::Update::new().set_bind("name = $1", "Foo").as_query();
+    let expected_query = "SET name = $1";
+    let expected_params = vec![sql::Value::from("Foo")];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+
+  #[test]
+  fn method_where_bind_should_renumber_the_placeholder_after_the_values_already_bound() {
+    let (query, params) = sql::Update::new()
+      .update("users")
+      .set_bind("name = $1", "Foo")
+      .where_bind("login = $1", "foo")
+      .and_bind("active = $1", true)
+      .as_query();
+    let expected_query = "UPDATE users SET name = $1 WHERE login = $2 AND active = $3";
+    let expected_params = vec![sql::Value::from("Foo"), sql::Value::from("foo"), sql::Value::from(true)];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+}
+
+mod placeholder_style {
+  use super::*;
+  use pretty_assertions::assert_eq;
+
+  #[test]
+  fn method_as_query_with_should_reorder_the_values_to_follow_the_question_mark_placeholders() {
+    let (query, params) = sql::Update::new()
+      .update("users")
+      .where_bind("login = $1", "foo")
+      .set_bind("name = $1", "Foo")
+      .as_query_with(sql::Placeholder::QuestionMark)
+      .unwrap();
+    let expected_query = "UPDATE users SET name = ? WHERE login = ?";
+    let expected_params = vec![sql::Value::from("Foo"), sql::Value::from("foo")];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+}
@@ -176,3 +176,26 @@ mod values_clause {
     assert_eq!(query, expected_query);
   }
 }
+
+mod values_bind_clause {
+  use pretty_assertions::assert_eq;
+  use sql_query_builder as sql;
+
+  #[test]
+  fn method_values_bind_should_renumber_the_placeholders_of_each_row() {
+    let (query, params) = sql::Values::new()
+      .values_bind("($1, $2)", [1.into(), "one".into()])
+      .values_bind("($1, $2)", [2.into(), sql::Value::from(None::<&str>)])
+      .as_query();
+    let expected_query = "VALUES ($1, $2), ($3, $4)";
+    let expected_params = vec![
+      sql::Value::from(1),
+      sql::Value::from("one"),
+      sql::Value::from(2),
+      sql::Value::Null,
+    ];
+
+    assert_eq!(query, expected_query);
+    assert_eq!(params, expected_params);
+  }
+}